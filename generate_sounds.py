@@ -138,4 +138,62 @@ e4_sad = generate_square_wave(311.13, 0.3, True) # Eb
 c4_sad = generate_square_wave(261.63, 0.8, True)
 save_wav("assets/sounds/gameover.wav", g4_sad + e4_sad + c4_sad)
 
+# 11. Hull Breach: Metal crunch over a low groan
+crunch = generate_noise(0.4, True)
+groan = generate_square_wave(55, 0.6, True)
+save_wav("assets/sounds/hull_breach.wav", mix_sounds(crunch, groan))
+
+# 12. Fire: Crackling noise loop (no decay so it loops cleanly)
+crackle = [s * (1.0 if random.random() > 0.97 else 0.3) for s in generate_noise(2.0, False)]
+save_wav("assets/sounds/fire.wav", crackle)
+
+# 13. Formation Warning: Two-tone alarm, played twice
+hi = generate_square_wave(880, 0.12, False)
+lo = generate_square_wave(660, 0.12, False)
+save_wav("assets/sounds/formation_warning.wav", hi + lo + hi + lo)
+
+# 14. Carrier Drop: Falling whoosh that lands on a short thud
+whoosh = [s * 0.5 for s in generate_laser(600, 150, 0.3)]
+thud = mix_sounds(generate_square_wave(70, 0.2, True), generate_noise(0.1, True))
+save_wav("assets/sounds/carrier_drop.wav", whoosh + thud)
+
+# 15. Music: two 8 second layers at 120 BPM over Am - F - C - G, the same length so they stay in step
+MUSIC_BEAT = 0.5
+MUSIC_CHORDS = [(110.00, 130.81, 164.81), (87.31, 110.00, 130.81), (130.81, 164.81, 196.00), (98.00, 123.47, 146.83)]
+
+def generate_pad(freqs, duration):
+    # Soft sine chord that swells in and out so chord changes don't click
+    samples = []
+    num_samples = int(duration * SAMPLE_RATE)
+    for i in range(num_samples):
+        t = float(i) / SAMPLE_RATE
+        swell = math.sin(math.pi * i / num_samples)
+        val = sum(math.sin(2 * math.pi * f * 2 * t) for f in freqs) / len(freqs)
+        samples.append(val * swell * 0.15)
+    return samples
+
+base = []
+for chord in MUSIC_CHORDS:
+    bass = []
+    for _ in range(4):
+        bass += generate_sine(chord[0], MUSIC_BEAT, True)
+    base += mix_sounds([s * 0.6 for s in bass], generate_pad(chord, MUSIC_BEAT * 4))
+save_wav("assets/sounds/music_base.wav", base)
+
+tension = []
+for chord in MUSIC_CHORDS:
+    for step in range(8):
+        note = chord[step % 3] * 2
+        pulse = [s * 0.4 for s in generate_square_wave(note, MUSIC_BEAT / 2, True)]
+        if step % 2 == 1:
+            tick = [s * 0.3 for s in generate_noise(0.03, True)]
+            pulse = mix_sounds(pulse, tick)
+        tension += pulse
+save_wav("assets/sounds/music_tension.wav", tension)
+
+# 16. Enemy Retreat: Rising sweep that fades out as the enemy pulls away
+sweep = generate_laser(250, 900, 0.4)
+retreat = [s * 0.5 * (1.0 - i / len(sweep)) for i, s in enumerate(sweep)]
+save_wav("assets/sounds/enemy_retreat.wav", retreat)
+
 print("All sounds generated in assets/sounds/")
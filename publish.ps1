@@ -162,6 +162,20 @@ if ($buildWebGL) {
         Write-Warning "Could not download mq_js_bundle.js - will use CDN reference"
     }
 
+    # Download the JS plugins quad-storage needs for localStorage saves
+    $JsPlugins = @{
+        "sapp_jsutils.js" = "https://not-fl3.github.io/miniquad-samples/sapp_jsutils.js"
+        "quad-storage.js" = "https://optozorax.github.io/quad-storage/quad-storage.js"
+    }
+    foreach ($plugin in $JsPlugins.GetEnumerator()) {
+        Write-Host "Downloading $($plugin.Key)..." -ForegroundColor Gray
+        try {
+            Invoke-WebRequest -Uri $plugin.Value -OutFile (Join-Path $WebGLPackageDir $plugin.Key)
+        } catch {
+            Write-Warning "Could not download $($plugin.Key) - web saves will not work"
+        }
+    }
+
     # Create WebGL zip (for itch.io uploads)
     $WebGLZipPath = Join-Path $DistDir "${ProjectName}_webgl.zip"
     Compress-Archive -Path "$WebGLPackageDir\*" -DestinationPath $WebGLZipPath -CompressionLevel Optimal
@@ -206,6 +220,15 @@ if ($DryRun) {
             Copy-Item $jsBundle $DeployDir -Force
             Write-Host "  Copied: mq_js_bundle.js" -ForegroundColor Gray
         }
+
+        # Copy JS plugins
+        foreach ($plugin in @("sapp_jsutils.js", "quad-storage.js")) {
+            $pluginFile = Join-Path $WebGLSourceDir $plugin
+            if (Test-Path $pluginFile) {
+                Copy-Item $pluginFile $DeployDir -Force
+                Write-Host "  Copied: $plugin" -ForegroundColor Gray
+            }
+        }
         
         # Copy assets folder
         $assetsDir = Join-Path $WebGLSourceDir "assets"
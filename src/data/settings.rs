@@ -1,11 +1,265 @@
 // settings.rs - Game settings with save/load to config.json
 
+use macroquad::input::KeyCode;
 use serde::{Deserialize, Serialize};
 use std::fs::File;
 use std::io::{BufReader, BufWriter};
 
 const CONFIG_PATH: &str = "config.json";
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Difficulty {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+}
+
+impl Difficulty {
+    pub const ALL: [Difficulty; 3] = [Difficulty::Easy, Difficulty::Normal, Difficulty::Hard];
+
+    /// Returns (spawn_interval_scale, hp_scale)
+    pub fn difficulty_multiplier(&self) -> (f32, f32) {
+        match self {
+            Difficulty::Easy => (1.5, 0.7),
+            Difficulty::Normal => (1.0, 1.0),
+            Difficulty::Hard => (0.7, 1.4),
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Difficulty::Easy => "EASY",
+            Difficulty::Normal => "NORMAL",
+            Difficulty::Hard => "HARD",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            Difficulty::Easy => "Slower waves, weaker enemies",
+            Difficulty::Normal => "The intended experience",
+            Difficulty::Hard => "Faster waves, tougher enemies",
+        }
+    }
+}
+
+/// Color-blind accessibility mode. Any mode other than None adds shape
+/// icons, hatch patterns and size differences so nothing relies on color alone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ColorblindMode {
+    #[default]
+    None,
+    Deuteranopia,
+    Protanopia,
+    Tritanopia,
+}
+
+impl ColorblindMode {
+    pub const ALL: [ColorblindMode; 4] = [
+        ColorblindMode::None,
+        ColorblindMode::Deuteranopia,
+        ColorblindMode::Protanopia,
+        ColorblindMode::Tritanopia,
+    ];
+
+    pub fn is_enabled(&self) -> bool {
+        *self != ColorblindMode::None
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ColorblindMode::None => "OFF",
+            ColorblindMode::Deuteranopia => "Deuteranopia",
+            ColorblindMode::Protanopia => "Protanopia",
+            ColorblindMode::Tritanopia => "Tritanopia",
+        }
+    }
+
+    /// Next mode in the list (wraps around); `forward = false` goes backwards
+    pub fn cycle(&self, forward: bool) -> Self {
+        let count = Self::ALL.len();
+        let index = Self::ALL.iter().position(|m| m == self).unwrap_or(0);
+        let next = if forward { (index + 1) % count } else { (index + count - 1) % count };
+        Self::ALL[next]
+    }
+}
+
+/// Rebindable gameplay actions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BindAction {
+    MoveUp,
+    MoveDown,
+    MoveLeft,
+    MoveRight,
+    Sprint,
+    Interact,
+    ToggleView,
+    Pause,
+    Craft,
+    Door,
+}
+
+impl BindAction {
+    pub const ALL: [BindAction; 10] = [
+        BindAction::MoveUp,
+        BindAction::MoveDown,
+        BindAction::MoveLeft,
+        BindAction::MoveRight,
+        BindAction::Sprint,
+        BindAction::Interact,
+        BindAction::ToggleView,
+        BindAction::Pause,
+        BindAction::Craft,
+        BindAction::Door,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            BindAction::MoveUp => "Move Up",
+            BindAction::MoveDown => "Move Down",
+            BindAction::MoveLeft => "Move Left",
+            BindAction::MoveRight => "Move Right",
+            BindAction::Sprint => "Sprint",
+            BindAction::Interact => "Interact / Gather",
+            BindAction::ToggleView => "Toggle View",
+            BindAction::Pause => "Pause",
+            BindAction::Craft => "Craft (Storage)",
+            BindAction::Door => "Open / Close Door",
+        }
+    }
+}
+
+/// Keyboard bindings for gameplay actions (arrow keys always move as well)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KeyBindings {
+    #[serde(with = "key_code_serde")]
+    pub move_up: KeyCode,
+    #[serde(with = "key_code_serde")]
+    pub move_down: KeyCode,
+    #[serde(with = "key_code_serde")]
+    pub move_left: KeyCode,
+    #[serde(with = "key_code_serde")]
+    pub move_right: KeyCode,
+    #[serde(with = "key_code_serde", default = "default_sprint_key")]
+    pub sprint: KeyCode,
+    #[serde(with = "key_code_serde")]
+    pub interact: KeyCode,
+    #[serde(with = "key_code_serde")]
+    pub toggle_view: KeyCode,
+    #[serde(with = "key_code_serde")]
+    pub pause: KeyCode,
+    #[serde(with = "key_code_serde", default = "default_craft_key")]
+    pub craft: KeyCode,
+    #[serde(with = "key_code_serde", default = "default_door_key")]
+    pub door: KeyCode,
+}
+
+fn default_sprint_key() -> KeyCode {
+    KeyCode::LeftShift
+}
+
+fn default_craft_key() -> KeyCode {
+    KeyCode::C
+}
+
+fn default_door_key() -> KeyCode {
+    KeyCode::F
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        Self {
+            move_up: KeyCode::W,
+            move_down: KeyCode::S,
+            move_left: KeyCode::A,
+            move_right: KeyCode::D,
+            sprint: default_sprint_key(),
+            interact: KeyCode::E,
+            toggle_view: KeyCode::Tab,
+            pause: KeyCode::P,
+            craft: default_craft_key(),
+            door: default_door_key(),
+        }
+    }
+}
+
+impl KeyBindings {
+    pub fn get(&self, action: BindAction) -> KeyCode {
+        match action {
+            BindAction::MoveUp => self.move_up,
+            BindAction::MoveDown => self.move_down,
+            BindAction::MoveLeft => self.move_left,
+            BindAction::MoveRight => self.move_right,
+            BindAction::Sprint => self.sprint,
+            BindAction::Interact => self.interact,
+            BindAction::ToggleView => self.toggle_view,
+            BindAction::Pause => self.pause,
+            BindAction::Craft => self.craft,
+            BindAction::Door => self.door,
+        }
+    }
+
+    pub fn set(&mut self, action: BindAction, key: KeyCode) {
+        match action {
+            BindAction::MoveUp => self.move_up = key,
+            BindAction::MoveDown => self.move_down = key,
+            BindAction::MoveLeft => self.move_left = key,
+            BindAction::MoveRight => self.move_right = key,
+            BindAction::Sprint => self.sprint = key,
+            BindAction::Interact => self.interact = key,
+            BindAction::ToggleView => self.toggle_view = key,
+            BindAction::Pause => self.pause = key,
+            BindAction::Craft => self.craft = key,
+            BindAction::Door => self.door = key,
+        }
+    }
+}
+
+/// Display/config name for a key, or None if it can't be bound
+pub fn key_name(key: KeyCode) -> Option<&'static str> {
+    key_code_serde::KEY_NAMES.iter().find(|(k, _)| *k == key).map(|(_, name)| *name)
+}
+
+/// KeyCode has no serde support, so bindings are stored by name
+mod key_code_serde {
+    use macroquad::input::KeyCode;
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub const KEY_NAMES: &[(KeyCode, &str)] = &[
+        (KeyCode::A, "A"), (KeyCode::B, "B"), (KeyCode::C, "C"), (KeyCode::D, "D"),
+        (KeyCode::E, "E"), (KeyCode::F, "F"), (KeyCode::G, "G"), (KeyCode::H, "H"),
+        (KeyCode::I, "I"), (KeyCode::J, "J"), (KeyCode::K, "K"), (KeyCode::L, "L"),
+        (KeyCode::M, "M"), (KeyCode::N, "N"), (KeyCode::O, "O"), (KeyCode::P, "P"),
+        (KeyCode::Q, "Q"), (KeyCode::R, "R"), (KeyCode::S, "S"), (KeyCode::T, "T"),
+        (KeyCode::U, "U"), (KeyCode::V, "V"), (KeyCode::W, "W"), (KeyCode::X, "X"),
+        (KeyCode::Y, "Y"), (KeyCode::Z, "Z"),
+        (KeyCode::Key0, "0"), (KeyCode::Key1, "1"), (KeyCode::Key2, "2"), (KeyCode::Key3, "3"),
+        (KeyCode::Key4, "4"), (KeyCode::Key5, "5"), (KeyCode::Key6, "6"), (KeyCode::Key7, "7"),
+        (KeyCode::Key8, "8"), (KeyCode::Key9, "9"),
+        (KeyCode::Space, "Space"), (KeyCode::Tab, "Tab"), (KeyCode::Enter, "Enter"),
+        (KeyCode::Backspace, "Backspace"),
+        (KeyCode::Up, "Up"), (KeyCode::Down, "Down"), (KeyCode::Left, "Left"), (KeyCode::Right, "Right"),
+        (KeyCode::LeftShift, "LeftShift"), (KeyCode::RightShift, "RightShift"),
+        (KeyCode::LeftControl, "LeftControl"), (KeyCode::RightControl, "RightControl"),
+        (KeyCode::LeftAlt, "LeftAlt"), (KeyCode::RightAlt, "RightAlt"),
+        (KeyCode::F1, "F1"), (KeyCode::F2, "F2"), (KeyCode::F3, "F3"), (KeyCode::F4, "F4"),
+    ];
+
+    pub fn serialize<S: Serializer>(key: &KeyCode, serializer: S) -> Result<S::Ok, S::Error> {
+        let name = super::key_name(*key).unwrap_or("Unknown");
+        serializer.serialize_str(name)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<KeyCode, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        KEY_NAMES.iter()
+            .find(|(_, n)| *n == name)
+            .map(|(k, _)| *k)
+            .ok_or_else(|| D::Error::custom(format!("unknown key '{}'", name)))
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Settings {
     pub master_volume: f32,    // 0.0 - 1.0
@@ -14,6 +268,32 @@ pub struct Settings {
     pub fullscreen: bool,
     pub show_fps: bool,
     pub screen_shake: bool,
+    #[serde(default = "default_true")]
+    pub show_minimap: bool,
+    #[serde(default = "default_true")]
+    pub gamepad_enabled: bool,
+    #[serde(default)]
+    pub difficulty: Difficulty,
+    #[serde(default)]
+    pub key_bindings: KeyBindings,
+    #[serde(default)]
+    pub skip_tutorial: bool,
+    #[serde(default)]
+    pub colorblind_mode: ColorblindMode,
+    #[serde(default = "default_zoom")]
+    pub zoom: f32, // Exterior view zoom, ZOOM_MIN..=ZOOM_MAX
+    #[serde(default)]
+    pub ironman_mode: bool, // Runs started while on can't be saved; saves of a lost run are deleted
+    #[serde(default)]
+    pub touch_mode: bool, // Always show the on-screen D-pad and interact button
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_zoom() -> f32 {
+    1.0
 }
 
 impl Default for Settings {
@@ -25,6 +305,15 @@ impl Default for Settings {
             fullscreen: false,
             show_fps: false,
             screen_shake: true,
+            show_minimap: true,
+            gamepad_enabled: true,
+            difficulty: Difficulty::Normal,
+            key_bindings: KeyBindings::default(),
+            skip_tutorial: false,
+            colorblind_mode: ColorblindMode::None,
+            zoom: 1.0,
+            ironman_mode: false,
+            touch_mode: false,
         }
     }
 }
@@ -0,0 +1,38 @@
+use serde::{Serialize, Deserialize};
+use crate::ship::ship::ModuleType;
+
+/// Crafted consumables carried in the player's inventory
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Item {
+    RepairKit(ModuleType), // Free repair of one point in a matching module room
+}
+
+impl Item {
+    pub fn name(&self) -> String {
+        match self {
+            Item::RepairKit(module_type) => format!("{:?} Repair Kit", module_type),
+        }
+    }
+}
+
+/// Scrap -> item recipe, crafted in the Storage room
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Recipe {
+    pub scrap_cost: i32,
+    pub output: Item,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecipeBook {
+    pub recipes: Vec<Recipe>,
+}
+
+impl RecipeBook {
+    pub fn load() -> Self {
+        serde_json::from_str(include_str!("../../assets/recipes.json"))
+            .unwrap_or_else(|e| {
+                eprintln!("Warning: Failed to load recipes.json: {}. Using no recipes.", e);
+                Self { recipes: Vec::new() }
+            })
+    }
+}
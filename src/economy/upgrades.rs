@@ -9,6 +9,8 @@ pub struct UpgradeTemplate {
     pub cost_multiplier: f32,
     pub max_level: u32,
     pub category: String,
+    #[serde(default)]
+    pub requires_schematic: bool, // Hidden until its schematic turns up in a scrap pile
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
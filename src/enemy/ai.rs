@@ -1,33 +1,75 @@
 use macroquad::prelude::*;
 use crate::state::{GameState, EngineState};
-use crate::enemy::entities::{Enemy, EnemyType};
+use crate::enemy::entities::{Enemy, EnemyType, ParticleKind, SpawnerRoom};
 use crate::simulation::constants::*;
 use crate::ship::ship::{ModuleType, ModuleState, Ship};
 use crate::simulation::events::{EventBus, GameEvent};
 
 use crate::enemy::wave::WaveState;
+use crate::enemy::pathfinding::find_path_around_modules;
+use crate::simulation::pool::ParticlePool;
+use crate::simulation::rng;
+
+/// Run-wide enemy multipliers, from the run's difficulty and veteran level
+#[derive(Debug, Clone, Copy)]
+pub struct EnemyScaling {
+    pub hp: f32,
+    pub spawn_interval: f32,
+}
+
+/// Geometric drone group patterns
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormationType {
+    Line, // Evenly spaced along one edge
+    V,    // Fanned toward the ship center
+    Ring, // Surrounding the screen
+}
+
+impl FormationType {
+    fn random() -> Self {
+        match rng::spawn_range(0, 3) {
+            0 => FormationType::Line,
+            1 => FormationType::V,
+            _ => FormationType::Ring,
+        }
+    }
+}
 
 pub fn update_wave_logic(
     total_power: i32,
     engine_state: &EngineState,
     enemies: &mut Vec<Enemy>,
+    spawners: &mut Vec<SpawnerRoom>,
     upgrades: &crate::economy::upgrades::GameUpgrades,
     wave_state: &mut WaveState,
+    scaling: EnemyScaling,
     frame_count: u64,
     dt: f32,
     events: &mut EventBus
 ) {
     let power_level = total_power;
+
+    // Spawners keep building through the escape sequence
+    update_spawners(spawners, enemies, frame_count, dt, scaling.hp);
     
     // Boss mode: Stop normal spawn when engine is charging or power >= 16
     if *engine_state == EngineState::Charging {
-        // In boss mode, only spawn boss if not already present
+        // In boss mode, only spawn boss if not already present.
+        // The first boss of a charge is announced a few seconds ahead.
         let has_boss = enemies.iter().any(|e| e.enemy_type == EnemyType::Boss);
         if !has_boss {
-            spawn_boss(enemies, events, frame_count);
+            let first_warning = !wave_state.boss_warned;
+            if wave_state.tick_boss_warning(dt) {
+                events.push_game(GameEvent::BossWarning);
+            }
+            if !first_warning && wave_state.boss_warning_timer <= 0.0 {
+                spawn_boss(enemies, events, frame_count, scaling.hp);
+            }
         }
         return;
     }
+    wave_state.boss_warning_timer = 0.0;
+    wave_state.boss_warned = false;
 
     // Normal wave logic based on power level per GDD
     wave_state.update(dt);
@@ -37,148 +79,371 @@ pub fn update_wave_logic(
         return;
     }
 
+    let (drone_interval, guard_interval) = spawn_intervals(power_level, upgrades, scaling.spawn_interval);
+    let wave_hp = scaling.hp * wave_state.wave_bonus_mult;
+
+    if wave_state.spawn_timer >= drone_interval {
+        // A formation counts as a single spawn for the cooldown
+        if power_level >= WAVE_T2_POWER && rng::spawn_range(0.0, 1.0) < FORMATION_SPAWN_CHANCE {
+            let formation_type = FormationType::random();
+            spawn_formation(enemies, formation_type, frame_count, wave_hp);
+            events.push_game(GameEvent::FormationSpawned { formation_type });
+        } else {
+            spawn_drone(enemies, frame_count, wave_hp);
+        }
+        wave_state.reset_spawn_timer();
+    }
+
+    if power_level >= WAVE_T2_POWER && !wave_state.carrier_spawned {
+        spawn_enemy(enemies, EnemyType::Carrier, frame_count, scaling.hp);
+        wave_state.carrier_spawned = true;
+    }
+
+    if power_level >= WAVE_T3_POWER && !wave_state.spawner_spawned {
+        spawners.push(SpawnerRoom::new(random_border_position(SPAWNER_EDGE_INSET)));
+        wave_state.spawner_spawned = true;
+    }
+
+    if power_level >= 6 && wave_state.guard_timer >= guard_interval {
+        spawn_guard(enemies, frame_count, wave_hp);
+        wave_state.reset_guard_timer();
+    }
+}
+
+/// Enemy scaling tier (0-3) for the given power level
+pub fn wave_tier(power_level: i32) -> u8 {
+    if power_level >= WAVE_T3_POWER {
+        3
+    } else if power_level >= WAVE_T2_POWER {
+        2
+    } else if power_level >= WAVE_T1_POWER {
+        1
+    } else {
+        0
+    }
+}
+
+/// Drone and guard spawn intervals for the given power level, scaled by the run's
+/// `EnemyScaling::spawn_interval`. Guard interval is `f32::MAX` below WAVE_T2_POWER (no guards).
+pub fn spawn_intervals(power_level: i32, upgrades: &crate::economy::upgrades::GameUpgrades, run_scale: f32) -> (f32, f32) {
     let targeting_tier = upgrades.get_level("targeting_tier");
     let diff_mult = 1.0 + (targeting_tier as f32 * 0.5);
+    let interval_scale = run_scale / diff_mult;
 
-    let (drone_interval, guard_interval) = if power_level >= WAVE_T3_POWER {
-        (SPAWN_INTERVAL_DRONE_T3 / diff_mult, SPAWN_INTERVAL_GUARD_T3 / diff_mult)
+    if power_level >= WAVE_T3_POWER {
+        (SPAWN_INTERVAL_DRONE_T3 * interval_scale, SPAWN_INTERVAL_GUARD_T3 * interval_scale)
     } else if power_level >= WAVE_T2_POWER {
-        (SPAWN_INTERVAL_DRONE_T2 / diff_mult, SPAWN_INTERVAL_GUARD_T2 / diff_mult)
+        (SPAWN_INTERVAL_DRONE_T2 * interval_scale, SPAWN_INTERVAL_GUARD_T2 * interval_scale)
     } else if power_level >= WAVE_T1_POWER {
-        (SPAWN_INTERVAL_DRONE_T1 / diff_mult, f32::MAX)
+        (SPAWN_INTERVAL_DRONE_T1 * interval_scale, f32::MAX)
     } else {
-        (SPAWN_INTERVAL_DRONE_T0 / diff_mult, f32::MAX)
-    };
-
-    if wave_state.spawn_timer >= drone_interval {
-        spawn_drone(enemies, frame_count);
-        wave_state.reset_spawn_timer();
+        (SPAWN_INTERVAL_DRONE_T0 * interval_scale, f32::MAX)
     }
+}
 
-    if power_level >= 6 && wave_state.guard_timer >= guard_interval {
-        spawn_guard(enemies, frame_count);
-        wave_state.reset_guard_timer();
+fn spawn_drone(enemies: &mut Vec<Enemy>, frame_count: u64, hp_scale: f32) {
+    spawn_enemy(enemies, EnemyType::Nanodrone, frame_count, hp_scale);
+    if let Some(drone) = enemies.last_mut() {
+        roll_elite(drone);
     }
 }
 
-fn spawn_drone(enemies: &mut Vec<Enemy>, frame_count: u64) {
+/// Spawn a single enemy of any type at a random screen edge
+pub fn spawn_enemy(enemies: &mut Vec<Enemy>, enemy_type: EnemyType, frame_count: u64, hp_scale: f32) {
     let pos = random_spawn_position();
     let id = generate_enemy_id(enemies.len(), frame_count);
-    enemies.push(Enemy::new(id, EnemyType::Nanodrone, pos));
+    enemies.push(Enemy::new(id, enemy_type, pos, hp_scale));
 }
 
-fn spawn_guard(enemies: &mut Vec<Enemy>, frame_count: u64) {
-    let pos = random_spawn_position();
-    let id = generate_enemy_id(enemies.len(), frame_count);
-    enemies.push(Enemy::new(id, EnemyType::Nanoguard, pos));
+pub fn spawn_formation(enemies: &mut Vec<Enemy>, formation_type: FormationType, frame_count: u64, hp_scale: f32) {
+    let center = vec2(SCREEN_WIDTH / 2.0, SCREEN_HEIGHT / 2.0);
+
+    let positions: Vec<Vec2> = match formation_type {
+        FormationType::Line => {
+            let n = FORMATION_LINE_COUNT;
+            let step = |len: f32, i: usize| len * (i + 1) as f32 / (n + 1) as f32;
+            match rng::spawn_range(0, 4) {
+                0 => (0..n).map(|i| vec2(step(SCREEN_WIDTH, i), -50.0)).collect(), // Top
+                1 => (0..n).map(|i| vec2(SCREEN_WIDTH + 50.0, step(SCREEN_HEIGHT, i))).collect(), // Right
+                2 => (0..n).map(|i| vec2(step(SCREEN_WIDTH, i), SCREEN_HEIGHT + 50.0)).collect(), // Bottom
+                _ => (0..n).map(|i| vec2(-50.0, step(SCREEN_HEIGHT, i))).collect(), // Left
+            }
+        }
+        FormationType::V => {
+            // Tip points at the ship, wings trail behind it
+            let tip = random_spawn_position();
+            let forward = (center - tip).normalize_or_zero();
+            let side = vec2(-forward.y, forward.x);
+            let back = tip - forward * FORMATION_SPACING;
+            vec![tip, back + side * FORMATION_SPACING, back - side * FORMATION_SPACING]
+        }
+        FormationType::Ring => {
+            let n = FORMATION_RING_COUNT;
+            let offset = rng::spawn_range(0.0, std::f32::consts::TAU);
+            (0..n).map(|i| {
+                let angle = offset + i as f32 * std::f32::consts::TAU / n as f32;
+                center + vec2(angle.cos() * (SCREEN_WIDTH / 2.0 + 50.0), angle.sin() * (SCREEN_HEIGHT / 2.0 + 50.0))
+            }).collect()
+        }
+    };
+
+    for pos in positions {
+        let id = generate_enemy_id(enemies.len(), frame_count);
+        enemies.push(Enemy::new(id, EnemyType::Nanodrone, pos, hp_scale));
+    }
 }
 
-pub fn spawn_boss(enemies: &mut Vec<Enemy>, events: &mut EventBus, frame_count: u64) {
+fn spawn_guard(enemies: &mut Vec<Enemy>, frame_count: u64, hp_scale: f32) {
+    spawn_enemy(enemies, EnemyType::Nanoguard, frame_count, hp_scale);
+    if let Some(guard) = enemies.last_mut() {
+        roll_elite(guard);
+    }
+}
+
+fn roll_elite(enemy: &mut Enemy) {
+    if rng::spawn_range(0.0, 1.0) < ELITE_SPAWN_CHANCE {
+        enemy.make_elite();
+    }
+}
+
+pub fn spawn_boss(enemies: &mut Vec<Enemy>, events: &mut EventBus, frame_count: u64, hp_scale: f32) {
     // Spawn boss at top center
     let pos = vec2(SCREEN_WIDTH / 2.0, -100.0);
     let id = generate_enemy_id(enemies.len(), frame_count);
-    enemies.push(Enemy::new(id, EnemyType::Boss, pos));
+    enemies.push(Enemy::new(id, EnemyType::Boss, pos, hp_scale));
     events.push_game(GameEvent::EngineActivated); // Reuse for boss spawn notification
 }
 
 fn random_spawn_position() -> Vec2 {
-    let side = rand::gen_range(0, 4);
+    let side = rng::spawn_range(0, 4);
     match side {
-        0 => vec2(rand::gen_range(0.0, SCREEN_WIDTH), -50.0), // Top
-        1 => vec2(SCREEN_WIDTH + 50.0, rand::gen_range(0.0, SCREEN_HEIGHT)), // Right
-        2 => vec2(rand::gen_range(0.0, SCREEN_WIDTH), SCREEN_HEIGHT + 50.0), // Bottom
-        _ => vec2(-50.0, rand::gen_range(0.0, SCREEN_HEIGHT)), // Left
+        0 => vec2(rng::spawn_range(0.0, SCREEN_WIDTH), -50.0), // Top
+        1 => vec2(SCREEN_WIDTH + 50.0, rng::spawn_range(0.0, SCREEN_HEIGHT)), // Right
+        2 => vec2(rng::spawn_range(0.0, SCREEN_WIDTH), SCREEN_HEIGHT + 50.0), // Bottom
+        _ => vec2(-50.0, rng::spawn_range(0.0, SCREEN_HEIGHT)), // Left
+    }
+}
+
+/// A random point `inset` pixels inside one of the screen borders
+fn random_border_position(inset: f32) -> Vec2 {
+    match rng::spawn_range(0, 4) {
+        0 => vec2(rng::spawn_range(inset, SCREEN_WIDTH - inset), inset), // Top
+        1 => vec2(SCREEN_WIDTH - inset, rng::spawn_range(inset, SCREEN_HEIGHT - inset)), // Right
+        2 => vec2(rng::spawn_range(inset, SCREEN_WIDTH - inset), SCREEN_HEIGHT - inset), // Bottom
+        _ => vec2(inset, rng::spawn_range(inset, SCREEN_HEIGHT - inset)), // Left
     }
 }
 
-fn generate_enemy_id(enemy_count: usize, frame_count: u64) -> u64 {
+/// Each spawner releases a Siege Construct every SPAWNER_INTERVAL seconds
+fn update_spawners(spawners: &mut [SpawnerRoom], enemies: &mut Vec<Enemy>, frame_count: u64, dt: f32, hp_scale: f32) {
+    for spawner in spawners.iter_mut() {
+        spawner.spawn_timer -= dt;
+        if spawner.spawn_timer <= 0.0 {
+            spawner.spawn_timer = SPAWNER_INTERVAL;
+            let id = generate_enemy_id(enemies.len(), frame_count);
+            enemies.push(Enemy::new(id, EnemyType::SiegeConstruct, spawner.position, hp_scale));
+        }
+    }
+}
+
+pub fn generate_enemy_id(enemy_count: usize, frame_count: u64) -> u64 {
     enemy_count as u64 + frame_count
 }
 
 // Note: spawn_scrap_piles was moved to GameState::spawn_scrap_piles() for better room-aware placement
 
-pub fn update_enemies(state: &mut GameState, dt: f32) {
+pub fn update_enemies(state: &mut GameState, dt: f32, events: &mut EventBus) {
     // Calculate core position from grid
     let core_pos = get_core_screen_position(state);
+    let mut carrier_drops = Vec::new();
+    let mut paths_recomputed = 0;
     
     for enemy in &mut state.enemies {
-        match enemy.enemy_type {
-            EnemyType::Nanodrone => {
-                // Rusher: Move directly to core
-                let dir = (core_pos - enemy.position).normalize_or_zero();
-                enemy.position += dir * enemy.speed * dt;
-                enemy.target_module = state.ship.find_core();
-                
-                // Debug if stuck
-                // if state.frame_count % 60 == 0 {
-                //      println!("Drone {} at {}, speed {}, dt {}, dir {}, core {}", 
-                //      enemy.id, enemy.position, enemy.speed, dt, dir, core_pos);
-                // }
-            }
-            EnemyType::Nanoguard => {
-                // Tank: Try to find nearest weapon/shield first, then core
-                if let Some(target) = find_priority_target(&state.ship) {
-                    let target_pos = grid_to_screen(target.0, target.1);
-                    let dir = (target_pos - enemy.position).normalize_or_zero();
-                    enemy.position += dir * enemy.speed * dt;
-                    enemy.target_module = Some(target);
-                } else {
-                    // No priority target, go for core
+        enemy.update_animation(dt);
+        enemy.update_status(dt);
+        let prev_position = enemy.position;
+
+        // Drones and guards near death break off and run for the nearest edge
+        let can_retreat = matches!(enemy.enemy_type, EnemyType::Nanodrone | EnemyType::Nanoguard);
+        if can_retreat && enemy.health / enemy.max_health < RETREAT_HEALTH_THRESHOLD {
+            enemy.is_retreating = true;
+        }
+
+        if enemy.is_retreating {
+            move_retreating(enemy, dt, events);
+        } else {
+            match enemy.enemy_type {
+                EnemyType::Nanodrone => {
+                    // Rusher: Move directly to core
                     let dir = (core_pos - enemy.position).normalize_or_zero();
-                    enemy.position += dir * enemy.speed * dt;
+                    enemy.position += dir * enemy.effective_speed() * dt;
                     enemy.target_module = state.ship.find_core();
+                
+                    // Debug if stuck
+                    // if state.frame_count % 60 == 0 {
+                    //      println!("Drone {} at {}, speed {}, dt {}, dir {}, core {}", 
+                    //      enemy.id, enemy.position, enemy.speed, dt, dir, core_pos);
+                    // }
                 }
-            }
-            EnemyType::Leech => {
-                // Leech: Find utility module or core, attach when close, drain power
-                if enemy.attached_to.is_some() {
-                    // Already attached - stay in place (damage handled in combat.rs)
-                } else {
-                    // Try to find a utility module first
-                    let target = find_utility_module(&state.ship).or(state.ship.find_core());
-                    if let Some(t) = target {
-                        let target_pos = grid_to_screen(t.0, t.1);
-                        let dist = enemy.position.distance(target_pos);
-                        if dist < ENEMY_ATTACK_RANGE {
-                            // Attach to the module
-                            enemy.attached_to = Some(t);
-                            enemy.target_module = Some(t);
-                        } else {
-                            let dir = (target_pos - enemy.position).normalize_or_zero();
-                            enemy.position += dir * enemy.speed * dt;
-                            enemy.target_module = Some(t);
-                        }
+                EnemyType::Nanoguard => {
+                    // Tank: Try to find nearest weapon/shield first, then core
+                    if let Some(target) = find_priority_target(&state.ship) {
+                        let target_pos = grid_to_screen(target.0, target.1);
+                        let dir = (target_pos - enemy.position).normalize_or_zero();
+                        enemy.position += dir * enemy.effective_speed() * dt;
+                        enemy.target_module = Some(target);
+                    } else {
+                        // No priority target, go for core
+                        let dir = (core_pos - enemy.position).normalize_or_zero();
+                        enemy.position += dir * enemy.effective_speed() * dt;
+                        enemy.target_module = state.ship.find_core();
                     }
                 }
-            }
-            EnemyType::SiegeConstruct => {
-                // Siege: Very slow, high damage, targets hull/core directly
-                // Moves to center of screen (where ship is) and attacks
-                let dir = (core_pos - enemy.position).normalize_or_zero();
-                enemy.position += dir * enemy.speed * dt;
-                enemy.target_module = state.ship.find_core();
-            }
-            EnemyType::Boss => {
-                // Boss: Slow approach, cycles through special abilities
-                let center = vec2(SCREEN_WIDTH / 2.0, SCREEN_HEIGHT / 2.0);
-                let dist_to_center = enemy.position.distance(center);
-                
-                // Boss moves to Core/Center to attack
-                // Removing 150.0 distance stop so it actually attacks
-                let dir = (center - enemy.position).normalize_or_zero();
-                enemy.position += dir * enemy.speed * dt;
-                
-                // Update ability timer
-                enemy.ability_timer += dt;
-                
-                // Boss targets weapons preferentially, then core
-                if let Some(target) = find_priority_target(&state.ship) {
-                    enemy.target_module = Some(target);
-                } else {
-                    enemy.target_module = state.ship.find_core();
+                EnemyType::Leech => move_leech(enemy, &state.ship, dt),
+                EnemyType::SiegeConstruct => {
+                    move_siege_construct(enemy, &state.ship, core_pos, dt, &mut paths_recomputed);
+                }
+                EnemyType::Carrier => {
+                    if let Some(pos) = move_carrier(enemy, &state.ship, dt) {
+                        carrier_drops.push(pos);
+                    }
                 }
+                EnemyType::Boss => move_boss(enemy, &state.ship, dt),
             }
         }
+
+        let movement = enemy.position - prev_position;
+        if movement != Vec2::ZERO {
+            spawn_exhaust(enemy, movement.normalize(), dt, &mut state.particle_pool);
+        }
+    }
+
+    let drone_hp = state.enemy_scaling().hp * state.wave_state.wave_bonus_mult;
+    for pos in carrier_drops {
+        for _ in 0..CARRIER_DROP_SIZE {
+            let offset = vec2(rng::gen_range(-20.0, 20.0), rng::gen_range(-20.0, 20.0));
+            let id = generate_enemy_id(state.enemies.len(), state.frame_count);
+            state.enemies.push(Enemy::new(id, EnemyType::Nanodrone, pos + offset, drone_hp));
+        }
+        events.push_game(GameEvent::CarrierDeployed { x: pos.x, y: pos.y });
+    }
+}
+
+/// Drones and guards that broke off head for the nearest edge and leave without a reward
+fn move_retreating(enemy: &mut Enemy, dt: f32, events: &mut EventBus) {
+    let center = vec2(SCREEN_WIDTH / 2.0, SCREEN_HEIGHT / 2.0);
+    let dir = (enemy.position - center).normalize_or_zero();
+    enemy.position += dir * enemy.effective_speed() * dt;
+    enemy.target_module = None;
+
+    let escaped = enemy.position.x < -RETREAT_EXIT_MARGIN
+        || enemy.position.y < -RETREAT_EXIT_MARGIN
+        || enemy.position.x > SCREEN_WIDTH + RETREAT_EXIT_MARGIN
+        || enemy.position.y > SCREEN_HEIGHT + RETREAT_EXIT_MARGIN;
+    if escaped {
+        enemy.health = 0.0; // Removed with the dead, but no kill reward
+        events.push_game(GameEvent::EnemyRetreated { scrap_dropped: 0 });
+    }
+}
+
+/// Leech: Find utility module or core, attach when close, drain power
+fn move_leech(enemy: &mut Enemy, ship: &Ship, dt: f32) {
+    if enemy.attached_to.is_some() {
+        // Already attached - stay in place (damage handled in combat.rs)
+        return;
+    }
+    // Try to find a utility module first
+    let target = find_utility_module(ship).or(ship.find_core());
+    if let Some(t) = target {
+        let target_pos = grid_to_screen(t.0, t.1);
+        let dist = enemy.position.distance(target_pos);
+        if dist < ENEMY_ATTACK_RANGE {
+            // Attach to the module
+            enemy.attached_to = Some(t);
+            enemy.target_module = Some(t);
+        } else {
+            let dir = (target_pos - enemy.position).normalize_or_zero();
+            enemy.position += dir * enemy.effective_speed() * dt;
+            enemy.target_module = Some(t);
+        }
+    }
+}
+
+/// Siege: Very slow, high damage, targets hull/core directly
+/// Steers around working modules instead of ramming through them
+/// Routes are cached; only stale ones are recomputed, a few per frame
+fn move_siege_construct(enemy: &mut Enemy, ship: &Ship, core_pos: Vec2, dt: f32, paths_recomputed: &mut usize) {
+    if enemy.position.distance(enemy.path_start) > PATH_RECOMPUTE_DISTANCE {
+        enemy.pathfinding_dirty = true;
+    }
+    if enemy.pathfinding_dirty && *paths_recomputed < PATH_RECOMPUTE_FRAME_BUDGET {
+        enemy.pathfinding_waypoints = find_path_around_modules(ship, enemy.position, core_pos);
+        enemy.path_start = enemy.position;
+        enemy.pathfinding_dirty = false;
+        *paths_recomputed += 1;
+    }
+    while enemy.pathfinding_waypoints.len() > 1
+        && enemy.position.distance(enemy.pathfinding_waypoints[0]) < SIEGE_WAYPOINT_RADIUS
+    {
+        enemy.pathfinding_waypoints.remove(0);
+    }
+    let waypoint = enemy.pathfinding_waypoints.first().copied().unwrap_or(core_pos);
+    let dir = (waypoint - enemy.position).normalize_or_zero();
+    enemy.position += dir * enemy.effective_speed() * dt;
+    enemy.target_module = ship.find_core();
+}
+
+/// Carrier: Crawls toward the ship, releasing drones until empty.
+/// Returns where to drop a batch of drones this frame, if it's time.
+fn move_carrier(enemy: &mut Enemy, ship: &Ship, dt: f32) -> Option<Vec2> {
+    let center = vec2(SCREEN_WIDTH / 2.0, SCREEN_HEIGHT / 2.0);
+    let dir = (center - enemy.position).normalize_or_zero();
+    enemy.position += dir * enemy.effective_speed() * dt;
+    enemy.target_module = ship.find_core();
+
+    if enemy.drops_remaining == 0 {
+        return None;
+    }
+    enemy.ability_timer += dt;
+    if enemy.ability_timer < CARRIER_DROP_INTERVAL {
+        return None;
+    }
+    enemy.ability_timer = 0.0;
+    enemy.drops_remaining -= 1;
+    Some(enemy.position)
+}
+
+/// Boss: Slow approach, cycles through special abilities
+fn move_boss(enemy: &mut Enemy, ship: &Ship, dt: f32) {
+    // Boss moves to Core/Center to attack
+    let center = vec2(SCREEN_WIDTH / 2.0, SCREEN_HEIGHT / 2.0);
+    let dir = (center - enemy.position).normalize_or_zero();
+    enemy.position += dir * enemy.effective_speed() * dt;
+
+    // Update ability timer
+    enemy.ability_timer += dt;
+
+    // Boss targets weapons preferentially, then core
+    enemy.target_module = find_priority_target(ship).or(ship.find_core());
+}
+
+/// Emit exhaust particles behind a moving enemy at a fixed rate (timer-based, so
+/// trail density doesn't depend on frame rate)
+fn spawn_exhaust(enemy: &mut Enemy, dir: Vec2, dt: f32, particles: &mut ParticlePool) {
+    let (color, lifetime, spread) = match enemy.enemy_type {
+        EnemyType::Nanodrone => (GREEN, ENEMY_TRAIL_LIFETIME, 4.0),
+        EnemyType::Nanoguard => (YELLOW, ENEMY_TRAIL_LIFETIME, 6.0),
+        EnemyType::Boss => (RED, ENEMY_TRAIL_LIFETIME_BOSS, 20.0),
+        _ => return,
+    };
+
+    enemy.trail_timer += dt;
+    while enemy.trail_timer >= ENEMY_TRAIL_INTERVAL {
+        enemy.trail_timer -= ENEMY_TRAIL_INTERVAL;
+        let offset = vec2(rand::gen_range(-spread, spread), rand::gen_range(-spread, spread));
+        let jitter = vec2(rand::gen_range(-0.3, 0.3), rand::gen_range(-0.3, 0.3));
+        let velocity = (-dir + jitter) * ENEMY_TRAIL_SPEED;
+        particles.spawn(enemy.position + offset, velocity, lifetime, color, ParticleKind::Trail);
     }
 }
 
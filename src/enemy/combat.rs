@@ -1,15 +1,19 @@
 use macroquad::prelude::*;
-use crate::state::GameState;
-use crate::enemy::entities::{Enemy, Projectile, EnemyType};
-use crate::ship::ship::ModuleType;
+use crate::state::{GameState, RunStats};
+use crate::enemy::entities::{Enemy, InteriorEnemy, Projectile, ProjectileType, EnemyType, ParticleKind, SpawnerRoom, StatusKind};
+use crate::ship::ship::{Module, ModuleState, ModuleType};
 use crate::simulation::constants::*;
 use crate::simulation::events::{EventBus, GameEvent};
 use crate::ship::layout::Layout;
 use crate::ship::interior::{RoomType, Room};
+use crate::simulation::effects::{DamageNumber, spawn_explosion_particles};
+use crate::enemy::ai::generate_enemy_id;
+use crate::simulation::rng;
 
 pub fn update_combat(state: &mut GameState, dt: f32, events: &mut EventBus) {
     // 1. Modules Fire (Towers)
     fire_towers(state, dt, events);
+    fire_drone_bays(state, dt, events);
     
     // 2. Projectiles Move & Collide
     update_projectiles(state, dt, events);
@@ -54,14 +58,19 @@ fn fire_towers(state: &mut GameState, dt: f32, events: &mut EventBus) {
         let base_range = stats.range;
         
         // Scale with repair percentage
-        let effective_fire_rate = base_fire_rate * repair_pct;
-        let effective_damage = base_damage * repair_pct;
+        let mut effective_fire_rate = base_fire_rate * repair_pct;
+        let synergy_bonus = state.synergies.get(&(gx, gy)).copied().unwrap_or(0.0);
+        let effective_damage = base_damage * repair_pct * (1.0 + synergy_bonus);
         let effective_range = base_range * (0.5 + 0.5 * repair_pct); // 50% base range + 50% from repairs
         
         // Access Module to update cooldown
         // Note: Using disjoint borrow of state should work (interior is borrowed, ship is separate)
         if let Some(cell) = state.ship.grid.get_mut(gx).and_then(|row| row.get_mut(gy)) {
              if let Some(module) = cell {
+                 if module.is_overcharged(state.time_survived) {
+                     effective_fire_rate *= OVERCHARGE_FIRE_RATE_MULTIPLIER;
+                 }
+
                  // Decrease cooldown
                  module.cooldown -= dt;
                  
@@ -72,12 +81,22 @@ fn fire_towers(state: &mut GameState, dt: f32, events: &mut EventBus) {
                     //    room.repaired_count(), room.repair_points.len(), repair_pct, base_fire_rate, effective_fire_rate, effective_range);
                  }
                  
-                 // Ready to fire?
-                 if module.cooldown <= 0.0 {
+                 // Ready to fire? (power surges knock weapons offline)
+                 if module.cooldown <= 0.0 && module.state != ModuleState::Offline {
                      let tower_pos = Layout::grid_to_screen_center(gx, gy);
                      
-                     if let Some(target) = find_nearest_enemy(&state.enemies, tower_pos, effective_range) {
-                         new_projectiles.push(Projectile::new(tower_pos, target, 400.0, effective_damage));
+                     // Spawners are only shot at when no enemy is in range
+                     let target = find_nearest_enemy(&state.enemies, tower_pos, effective_range)
+                         .or_else(|| find_nearest_spawner(&state.spawners, tower_pos, effective_range));
+                     if let Some(target) = target {
+                         let aim = target - tower_pos;
+                         module.target_angle = aim.y.atan2(aim.x);
+                         if module.level >= BEAM_UPGRADE_LEVEL {
+                             let damage = effective_damage * BEAM_DAMAGE_MULTIPLIER;
+                             new_projectiles.push(Projectile::beam(tower_pos, target, effective_range, damage));
+                         } else {
+                             new_projectiles.push(Projectile::new(tower_pos, target, 400.0, effective_damage));
+                         }
                          events.push_game(GameEvent::WeaponFired { x: tower_pos.x, y: tower_pos.y });
                          
                          // Reset cooldown
@@ -95,6 +114,92 @@ fn fire_towers(state: &mut GameState, dt: f32, events: &mut EventBus) {
     state.projectiles.append(&mut new_projectiles);
 }
 
+/// Drone bays launch one drone per repaired point; the drones circle the core and
+/// pick off the enemy farthest from it, catching stragglers the turrets can't reach
+fn fire_drone_bays(state: &mut GameState, dt: f32, events: &mut EventBus) {
+    let mut new_projectiles = Vec::new();
+    let core = match state.ship.find_core() {
+        Some((cx, cy)) => Layout::grid_to_screen_center(cx, cy),
+        None => return,
+    };
+    let stats = state.module_registry.get(ModuleType::DroneBay);
+    let (fire_rate, damage, range) = (stats.fire_rate, stats.damage, stats.range);
+
+    for room in &state.interior.rooms {
+        if room.room_type != RoomType::Module(ModuleType::DroneBay) {
+            continue;
+        }
+        let Some((gx, gy)) = room.module_index else { continue };
+        let Some(module) = state.ship.grid.get_mut(gx).and_then(|col| col.get_mut(gy)).and_then(|cell| cell.as_mut()) else { continue };
+
+        let drones = room.repaired_count();
+        if drones == 0 || module.state == ModuleState::Offline {
+            module.drone_positions.clear();
+            continue;
+        }
+
+        // New drones launch from the bay itself before joining the orbit
+        let bay = Layout::grid_to_screen_center(gx, gy);
+        module.drone_positions.resize(drones, bay);
+        module.drone_angle = (module.drone_angle + DRONE_ORBIT_SPEED * dt) % std::f32::consts::TAU;
+        let spacing = std::f32::consts::TAU / drones as f32;
+        for (i, pos) in module.drone_positions.iter_mut().enumerate() {
+            let angle = module.drone_angle + spacing * i as f32;
+            let slot = core + vec2(angle.cos(), angle.sin()) * DRONE_ORBIT_RADIUS;
+            *pos = pos.lerp(slot, (dt * 4.0).min(1.0));
+        }
+
+        module.cooldown -= dt;
+        if module.cooldown > 0.0 {
+            continue;
+        }
+        let Some(target) = find_farthest_enemy(&state.enemies, core).or_else(|| state.spawners.first().map(|s| s.position)) else { continue };
+        let mut fired = false;
+        for &pos in &module.drone_positions {
+            if pos.distance(target) <= range {
+                new_projectiles.push(Projectile::new(pos, target, DRONE_PROJECTILE_SPEED, damage));
+                fired = true;
+            }
+        }
+        if fired {
+            events.push_game(GameEvent::WeaponFired { x: bay.x, y: bay.y });
+            module.cooldown = if fire_rate > 0.001 { 1.0 / fire_rate } else { 10.0 };
+        }
+    }
+
+    state.projectiles.append(&mut new_projectiles);
+}
+
+/// Shots per second of the weapon at a grid cell, scaled by its room's repair progress
+pub fn weapon_fire_rate(state: &GameState, cell: (usize, usize)) -> f32 {
+    let Some(room) = state.interior.rooms.iter().find(|r| {
+        r.room_type == RoomType::Module(ModuleType::Weapon) && r.module_index == Some(cell) && !r.repair_points.is_empty()
+    }) else { return 0.0 };
+
+    let repair_pct = room.repaired_count() as f32 / room.repair_points.len() as f32;
+    let overcharged = state.ship.grid[cell.0][cell.1].as_ref().is_some_and(|m| m.is_overcharged(state.time_survived));
+    let boost = if overcharged { OVERCHARGE_FIRE_RATE_MULTIPLIER } else { 1.0 };
+    state.module_registry.get(ModuleType::Weapon).fire_rate * repair_pct * boost
+}
+
+/// Shortest distance from a point to the segment a-b
+fn distance_to_segment(point: Vec2, a: Vec2, b: Vec2) -> f32 {
+    let ab = b - a;
+    let len_sq = ab.length_squared();
+    if len_sq <= f32::EPSILON {
+        return point.distance(a);
+    }
+    let t = ((point - a).dot(ab) / len_sq).clamp(0.0, 1.0);
+    point.distance(a + ab * t)
+}
+
+fn find_farthest_enemy(enemies: &[Enemy], pos: Vec2) -> Option<Vec2> {
+    enemies.iter()
+        .filter(|e| e.health > 0.0)
+        .max_by(|a, b| pos.distance(a.position).total_cmp(&pos.distance(b.position)))
+        .map(|e| e.position)
+}
+
 fn find_nearest_enemy(enemies: &[Enemy], pos: Vec2, range: f32) -> Option<Vec2> {
     let mut nearest = None;
     let mut min_dist = range;
@@ -111,19 +216,92 @@ fn find_nearest_enemy(enemies: &[Enemy], pos: Vec2, range: f32) -> Option<Vec2>
     nearest
 }
 
+fn find_nearest_spawner(spawners: &[SpawnerRoom], pos: Vec2, range: f32) -> Option<Vec2> {
+    spawners.iter()
+        .filter(|s| s.health > 0.0 && pos.distance(s.position) < range)
+        .min_by(|a, b| pos.distance(a.position).total_cmp(&pos.distance(b.position)))
+        .map(|s| s.position)
+}
+
+/// Index of the first live enemy a ballistic shot at `pos` overlaps, searching the
+/// 3x3 block of buckets around it so no edge cases are missed
+fn find_projectile_hit(pos: Vec2, enemies: &[Enemy], buckets: &std::collections::HashMap<(i32, i32), Vec<usize>>, bucket_size: f32) -> Option<usize> {
+    let bx = (pos.x / bucket_size).floor() as i32;
+    let by = (pos.y / bucket_size).floor() as i32;
+    for dx in -1..=1 {
+        for dy in -1..=1 {
+            let Some(enemy_indices) = buckets.get(&(bx + dx, by + dy)) else { continue };
+            for &idx in enemy_indices {
+                let Some(enemy) = enemies.get(idx) else { continue };
+                if enemy.health <= 0.0 { continue; }
+
+                let hit_radius = match enemy.enemy_type {
+                    EnemyType::Boss => ENEMY_HIT_RADIUS_BOSS,
+                    EnemyType::Nanoguard | EnemyType::SiegeConstruct => ENEMY_HIT_RADIUS_NANOGUARD,
+                    _ => ENEMY_HIT_RADIUS_NANODRONE,
+                };
+                if pos.distance(enemy.position) < hit_radius {
+                    return Some(idx);
+                }
+            }
+        }
+    }
+    None
+}
+
+/// (position, type, elite) of an enemy killed by a projectile
+type Kill = (Vec2, EnemyType, bool);
+
 fn update_projectiles(state: &mut GameState, dt: f32, events: &mut EventBus) {
-    // 1. Move projectiles first
-    for proj in &mut state.projectiles {
-        proj.position += proj.velocity * dt;
-        
-        // Bounds check
-        if proj.position.x < -100.0 || proj.position.x > screen_width() + 100.0 || 
-           proj.position.y < -100.0 || proj.position.y > screen_height() + 100.0 {
+    let mut killed = Vec::new();
+    update_beams(state, dt, events, &mut killed);
+    move_ballistic_projectiles(state, dt, events, &mut killed);
+
+    let wrecks = reward_kills(state, killed, events);
+    pay_spawner_bounties(state, events);
+
+    // Cleanup
+    state.projectiles.retain(|p| p.active);
+    state.enemies.retain(|e| e.health > 0.0);
+    state.spawners.retain(|s| s.health > 0.0);
+
+    split_siege_wrecks(state, wrecks);
+}
+
+/// Beams hit everything along their line on the frame they fire, then linger for the visual
+fn update_beams(state: &mut GameState, dt: f32, events: &mut EventBus, killed: &mut Vec<Kill>) {
+    let (slow_chance, stun_chance) = utility_status_chances(state);
+
+    for proj in state.projectiles.iter_mut() {
+        let ProjectileType::Beam { length, active_time } = &mut proj.kind else { continue };
+        if *active_time >= BEAM_ACTIVE_TIME {
+            let end = proj.position + proj.velocity * *length;
+            for enemy in state.enemies.iter_mut() {
+                if enemy.health <= 0.0 { continue; }
+                if distance_to_segment(enemy.position, proj.position, end) < BEAM_WIDTH {
+                    let dead = hit_enemy(enemy, proj.damage, &mut state.run_stats, &mut state.damage_numbers, slow_chance, stun_chance, events);
+                    if dead { killed.push((enemy.position, enemy.enemy_type.clone(), enemy.elite)); }
+                }
+            }
+            for spawner in state.spawners.iter_mut().filter(|s| s.health > 0.0) {
+                if distance_to_segment(spawner.position, proj.position, end) < SPAWNER_SIZE / 2.0 {
+                    hit_spawner(spawner, proj.damage, &mut state.run_stats, &mut state.damage_numbers);
+                }
+            }
+        }
+        *active_time -= dt;
+        if *active_time <= 0.0 {
             proj.active = false;
         }
     }
-    
-    // 2. Spatial Partitioning for Optimized Collision
+}
+
+/// Move ballistic shots and resolve their hits. Fast shots move in substeps with a
+/// collision check after each, so they can't tunnel through small enemies at low frame rates.
+fn move_ballistic_projectiles(state: &mut GameState, dt: f32, events: &mut EventBus, killed: &mut Vec<Kill>) {
+    let (slow_chance, stun_chance) = utility_status_chances(state);
+
+    // Spatial Partitioning for Optimized Collision
     // Simple grid buckets: Screen width/height divided into 100px chunks
     // Key = (x/100, y/100) -> Vec of Enemy indices
     let bucket_size = 100.0;
@@ -145,86 +323,223 @@ fn update_projectiles(state: &mut GameState, dt: f32, events: &mut EventBus) {
         // Overlap checks for edges (if enemy radius > bucket edge distance)
         // For simplicity in this review pass, we'll assume strict bucket ownership by center point
     }
-    
-    // Run Collisions
+
     for proj in state.projectiles.iter_mut() {
-        if !proj.active { continue; }
-        
-        let bx = (proj.position.x / bucket_size).floor() as i32;
-        let by = (proj.position.y / bucket_size).floor() as i32;
-        
-        // Check local bucket and neighbors (3x3 grid) to ensure no edge cases missed
-        for dx in -1..=1 {
-            for dy in -1..=1 {
-                if let Some(enemy_indices) = buckets.get(&(bx + dx, by + dy)) {
-                    for &idx in enemy_indices {
-                         // Double check index validity just in case
-                        if idx >= state.enemies.len() { continue; }
-                        let enemy = &mut state.enemies[idx];
-                        
-                        if enemy.health <= 0.0 { continue; }
-                        
-                        let hit_radius = match enemy.enemy_type {
-                            EnemyType::Boss => ENEMY_HIT_RADIUS_BOSS,
-                            EnemyType::Nanoguard | EnemyType::SiegeConstruct => ENEMY_HIT_RADIUS_NANOGUARD,
-                            _ => ENEMY_HIT_RADIUS_NANODRONE,
-                        };
-                        
-                        if proj.position.distance(enemy.position) < hit_radius {
-                            enemy.health -= proj.damage;
-                            proj.active = false;
-                            
-                            if enemy.health <= 0.0 {
-                                // Enemy killed
-                                let scrap = match enemy.enemy_type {
-                                    EnemyType::Nanodrone => 3,
-                                    EnemyType::Nanoguard => 10,
-                                    EnemyType::Leech => 5,
-                                    EnemyType::SiegeConstruct => 25,
-                                    EnemyType::Boss => 100,
-                                };
-                                state.resources.add_scrap(scrap);
-                                state.resources.credits += scrap / 2;
-                                
-                                events.push_game(GameEvent::EnemyKilled { 
-                                    x: enemy.position.x, 
-                                    y: enemy.position.y, 
-                                    scrap_dropped: scrap 
-                                });
-                            }
-                            break; // Proj destroyed
-                        }
-                    }
+        if !proj.active || proj.kind != ProjectileType::Ballistic { continue; }
+
+        let substeps = if proj.velocity.length() > SUBSTEP_THRESHOLD_VELOCITY { PROJECTILE_SUBSTEPS } else { 1 };
+        let step_dt = dt / substeps as f32;
+        for _ in 0..substeps {
+            proj.position += proj.velocity * step_dt;
+
+            if let Some(idx) = find_projectile_hit(proj.position, &state.enemies, &buckets, bucket_size) {
+                let enemy = &mut state.enemies[idx];
+                proj.active = false;
+                if hit_enemy(enemy, proj.damage, &mut state.run_stats, &mut state.damage_numbers, slow_chance, stun_chance, events) {
+                    killed.push((enemy.position, enemy.enemy_type.clone(), enemy.elite));
                 }
-                if !proj.active { break; }
+                break; // Proj destroyed
+            }
+            if let Some(spawner) = state.spawners.iter_mut().find(|s| s.health > 0.0 && s.contains(proj.position)) {
+                proj.active = false;
+                hit_spawner(spawner, proj.damage, &mut state.run_stats, &mut state.damage_numbers);
+                break;
             }
-            if !proj.active { break; }
+        }
+
+        // Fading trail, hotter (more orange) for harder-hitting shots
+        proj.trail_cooldown -= dt;
+        if proj.trail_cooldown <= 0.0 {
+            proj.trail_cooldown = PROJECTILE_TRAIL_INTERVAL;
+            let heat = (proj.damage / PROJECTILE_TRAIL_HOT_DAMAGE).clamp(0.0, 1.0);
+            let color = Color::new(1.0, 1.0 - heat * 0.35, 0.6 * (1.0 - heat), 1.0);
+            state.particle_pool.spawn(proj.position, Vec2::ZERO, PROJECTILE_TRAIL_LIFETIME, color, ParticleKind::Trail);
+        }
+        
+        // Bounds check
+        if proj.position.x < -100.0 || proj.position.x > screen_width() + 100.0 || 
+           proj.position.y < -100.0 || proj.position.y > screen_height() + 100.0 {
+            proj.active = false;
         }
     }
-    
-    // Cleanup
-    state.projectiles.retain(|p| p.active);
-    state.enemies.retain(|e| e.health > 0.0);
 }
 
-fn enemy_attacks(state: &mut GameState, dt: f32, events: &mut EventBus) {
-    let attack_range = ENEMY_ATTACK_RANGE;
-    
-    // Calculate shield reduction from all shield rooms
+/// Scrap, credits and kill events for this frame's kills. Returns where Siege
+/// Constructs died so they can split into drones after cleanup.
+fn reward_kills(state: &mut GameState, killed: Vec<Kill>, events: &mut EventBus) -> Vec<Vec2> {
+    let mut wrecks = Vec::new();
+    for (position, enemy_type, elite) in killed {
+        let base_scrap = match enemy_type {
+            EnemyType::Nanodrone => 3,
+            EnemyType::Nanoguard => 10,
+            EnemyType::Leech => 5,
+            EnemyType::SiegeConstruct => 25,
+            EnemyType::Carrier => 40,
+            EnemyType::Boss => 100,
+        };
+        let scrap = if elite { base_scrap * ELITE_SCRAP_MULTIPLIER } else { base_scrap };
+        state.resources.add_scrap(scrap);
+        state.resources.credits += scrap / 2;
+        state.profile.record_kill(&enemy_type);
+        state.enemies_killed += 1;
+        spawn_death_burst(state, position, &enemy_type);
+
+        events.push_game(GameEvent::EnemyKilled {
+            x: position.x,
+            y: position.y,
+            scrap_dropped: scrap,
+            enemy_type: enemy_type.clone(),
+        });
+
+        if enemy_type == EnemyType::SiegeConstruct {
+            wrecks.push(position);
+            events.push_game(GameEvent::SiegeConstructExploded { x: position.x, y: position.y });
+        }
+    }
+    wrecks
+}
+
+/// Destroyed spawners pay a credit bounty
+fn pay_spawner_bounties(state: &mut GameState, events: &mut EventBus) {
+    let destroyed: Vec<Vec2> = state.spawners.iter().filter(|s| s.health <= 0.0).map(|s| s.position).collect();
+    for position in destroyed {
+        state.resources.add_credits(SPAWNER_DESTROY_CREDITS);
+        for color in [RED, ORANGE, GRAY] {
+            spawn_explosion_particles(&mut state.particle_pool, position, 15, color, 220.0);
+        }
+        events.push_game(GameEvent::SpawnerDestroyed { x: position.x, y: position.y });
+    }
+}
+
+/// Destroyed Siege Constructs break apart into drones
+fn split_siege_wrecks(state: &mut GameState, wrecks: Vec<Vec2>) {
+    let hp_scale = state.enemy_scaling().hp;
+    for pos in wrecks {
+        for _ in 0..BOSS_SPLIT_COUNT {
+            let offset = vec2(
+                rng::gen_range(-SIEGE_SPLIT_SCATTER, SIEGE_SPLIT_SCATTER),
+                rng::gen_range(-SIEGE_SPLIT_SCATTER, SIEGE_SPLIT_SCATTER),
+            );
+            let id = generate_enemy_id(state.enemies.len(), state.frame_count);
+            state.enemies.push(Enemy::new(id, EnemyType::Nanodrone, pos + offset, hp_scale));
+        }
+    }
+}
+
+/// Death burst sized to the enemy: drones pop, constructs and bosses blow apart
+fn spawn_death_burst(state: &mut GameState, pos: Vec2, enemy_type: &EnemyType) {
+    let pool = &mut state.particle_pool;
+    match enemy_type {
+        EnemyType::Nanodrone | EnemyType::Leech => spawn_explosion_particles(pool, pos, 8, GREEN, 60.0),
+        EnemyType::Nanoguard => spawn_explosion_particles(pool, pos, 12, YELLOW, 110.0),
+        EnemyType::SiegeConstruct | EnemyType::Carrier => {
+            spawn_explosion_particles(pool, pos, 10, GRAY, 200.0);
+            spawn_explosion_particles(pool, pos, 10, ORANGE, 200.0);
+        }
+        EnemyType::Boss => {
+            for color in [RED, ORANGE, YELLOW, WHITE, PURPLE] {
+                spawn_explosion_particles(pool, pos, 10, color, 240.0);
+            }
+        }
+    }
+}
+
+/// Apply one projectile or beam hit. Returns true if the hit killed the enemy.
+fn hit_enemy(
+    enemy: &mut Enemy,
+    damage: f32,
+    run_stats: &mut RunStats,
+    damage_numbers: &mut Vec<DamageNumber>,
+    slow_chance: f32,
+    stun_chance: f32,
+    events: &mut EventBus,
+) -> bool {
+    let was_armored = enemy.is_armored();
+    let damage = if was_armored { damage * SIEGE_ARMOR_MULTIPLIER } else { damage };
+    enemy.health -= damage;
+    run_stats.damage_dealt += damage;
+    if was_armored && !enemy.is_armored() && enemy.health > 0.0 {
+        events.push_game(GameEvent::SiegeArmorBroken { id: enemy.id });
+    }
+    damage_numbers.push(DamageNumber::new(enemy.position, damage, enemy.health <= 0.0));
+
+    if enemy.health > 0.0 {
+        apply_utility_status(enemy, slow_chance, stun_chance, events);
+        return false;
+    }
+    true
+}
+
+/// Apply one projectile or beam hit to a spawner; it has no armor or status effects
+fn hit_spawner(spawner: &mut SpawnerRoom, damage: f32, run_stats: &mut RunStats, damage_numbers: &mut Vec<DamageNumber>) {
+    spawner.health -= damage;
+    run_stats.damage_dealt += damage;
+    damage_numbers.push(DamageNumber::new(spawner.position, damage, spawner.health <= 0.0));
+}
+
+/// Slow and stun chances per hit from the best repaired, online Utility room
+fn utility_status_chances(state: &GameState) -> (f32, f32) {
+    let repair_pct = state.interior.rooms.iter()
+        .filter(|r| r.room_type == RoomType::Module(ModuleType::Utility) && !r.repair_points.is_empty())
+        .filter(|r| !r.module_index.is_some_and(|cell| state.ship.is_offline(cell)))
+        .map(|r| r.repaired_count() as f32 / r.repair_points.len() as f32)
+        .fold(0.0, f32::max);
+
+    if repair_pct <= 0.0 {
+        return (0.0, 0.0);
+    }
+
+    let stun_level = state.upgrades.get_level("stun_utility");
+    (UTILITY_SLOW_CHANCE * repair_pct, UTILITY_STUN_CHANCE_PER_LEVEL * stun_level as f32)
+}
+
+fn apply_utility_status(enemy: &mut Enemy, slow_chance: f32, stun_chance: f32, events: &mut EventBus) {
+    let (kind, duration) = if rng::gen_range(0.0, 1.0) < stun_chance {
+        (StatusKind::Stunned, UTILITY_STUN_DURATION)
+    } else if rng::gen_range(0.0, 1.0) < slow_chance {
+        (StatusKind::Slowed(UTILITY_SLOW_FACTOR), UTILITY_SLOW_DURATION)
+    } else {
+        return;
+    };
+
+    enemy.apply_status(kind, duration);
+    events.push_game(GameEvent::EnemyStatusApplied { id: enemy.id, kind });
+}
+
+/// Fraction of enemy damage blocked by all online shield rooms together
+fn shield_reduction(state: &GameState) -> f32 {
     let mut shield_reduction: f32 = 0.0;
     for room in &state.interior.rooms {
+        if room.module_index.is_some_and(|cell| state.ship.is_offline(cell)) { continue; }
         if room.room_type == RoomType::Module(ModuleType::Defense) {
             if !room.repair_points.is_empty() {
                 let repair_pct = room.repaired_count() as f32 / room.repair_points.len() as f32;
-                shield_reduction += repair_pct * 0.5; // Each shield room can block up to 50%
+                let synergy_bonus = room.module_index
+                    .and_then(|pos| state.synergies.get(&pos))
+                    .copied()
+                    .unwrap_or(0.0);
+                let overcharged = room.module_index
+                    .and_then(|(x, y)| state.ship.grid[x][y].as_ref())
+                    .is_some_and(|m| m.is_overcharged(state.time_survived));
+                let boost = if overcharged { OVERCHARGE_SHIELD_MULTIPLIER } else { 1.0 };
+                shield_reduction += repair_pct * 0.5 * (1.0 + synergy_bonus) * boost; // Each shield room can block up to 50% (+synergy)
             }
         }
     }
     // Cap at 80% damage reduction max
     shield_reduction = shield_reduction.min(0.8);
+    shield_reduction
+}
+
+fn enemy_attacks(state: &mut GameState, dt: f32, events: &mut EventBus) {
+    let attack_range = ENEMY_ATTACK_RANGE;
+    let shield_reduction = shield_reduction(state);
+    let integrity_before = state.ship_integrity;
+    let mut breaches = Vec::new(); // Cells newly breached by Siege Constructs this frame
+    let mut cell_damage = Vec::new(); // (cell, hull damage) dealt at each struck module this frame
     
     for enemy in &mut state.enemies {
-        if enemy.health <= 0.0 { continue; }
+        if enemy.health <= 0.0 || enemy.is_retreating { continue; }
         
         let mut hit_something = false;
         
@@ -241,11 +556,23 @@ fn enemy_attacks(state: &mut GameState, dt: f32, events: &mut EventBus) {
                             let module_pos = Layout::grid_to_screen_center(nx, ny);
                             let dist = enemy.position.distance(module_pos);
                             
+                            if dist < attack_range && enemy.enemy_type == EnemyType::SiegeConstruct {
+                                cell_damage.push(((nx, ny), enemy.damage * dt));
+                                if let Some(module) = state.ship.grid[nx][ny].as_mut() {
+                                    if siege_attack(enemy, module, &mut state.ship_integrity, (nx, ny), dt, events) {
+                                        breaches.push((nx, ny));
+                                    }
+                                }
+                                hit_something = true;
+                                break 'outer;
+                            }
+
                             if dist < attack_range {
                                 // Apply shield reduction to damage
                                 let base_damage = enemy.damage * dt;
                                 let damage = base_damage * (1.0 - shield_reduction);
                                 state.ship_integrity -= damage;
+                                cell_damage.push(((nx, ny), damage));
                                 
                                 hit_something = true;
                                 
@@ -271,4 +598,50 @@ fn enemy_attacks(state: &mut GameState, dt: f32, events: &mut EventBus) {
             enemy.attacking = false;
         }
     }
+    state.run_stats.damage_taken += (integrity_before - state.ship_integrity).max(0.0);
+    damage_rooms(&mut state.interior.rooms, cell_damage, state.ship_max_integrity, events);
+
+    // Each breach lets a boarder into the room behind the struck module
+    for cell in breaches {
+        let room = state.interior.rooms.iter()
+            .find(|r| r.module_index == Some(cell))
+            .or_else(|| state.interior.rooms.iter().find(|r| r.id == state.interior.player_start_room));
+        if let Some(room) = room {
+            state.interior_enemies.push(InteriorEnemy::new(room.center()));
+            events.push_game(GameEvent::InteriorThreat { room_id: room.id });
+        }
+    }
+}
+
+/// Hull damage dealt at a module wears down the room behind it, scaled to the ship's
+/// max integrity so a room under sustained attack collapses well before the hull fails
+fn damage_rooms(rooms: &mut [Room], cell_damage: Vec<((usize, usize), f32)>, max_integrity: f32, events: &mut EventBus) {
+    if max_integrity <= 0.0 { return; }
+    for (cell, damage) in cell_damage {
+        let Some(room) = rooms.iter_mut().find(|r| r.module_index == Some(cell)) else { continue };
+        let was_collapsed = room.is_collapsed();
+        room.room_damage = (room.room_damage + damage / max_integrity * ROOM_DAMAGE_PER_HULL_FRACTION).min(1.0);
+        if room.is_collapsed() && !was_collapsed {
+            events.push_game(GameEvent::RoomCollapsed { room_id: room.id });
+        }
+    }
+}
+
+/// Siege Constructs hit the hull directly: shields don't reduce their damage and
+/// the module they strike is breached until repaired. Returns true on a new breach.
+fn siege_attack(enemy: &mut Enemy, module: &mut Module, ship_integrity: &mut f32, (x, y): (usize, usize), dt: f32, events: &mut EventBus) -> bool {
+    let damage = enemy.damage * dt;
+    *ship_integrity -= damage;
+
+    let new_breach = !module.hull_breached;
+    if new_breach {
+        module.breach();
+        events.push_game(GameEvent::HullBreached { x, y });
+    }
+
+    if !enemy.attacking {
+        enemy.attacking = true;
+        events.push_game(GameEvent::ModuleDamaged { x, y, damage });
+    }
+    new_breach
 }
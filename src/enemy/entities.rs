@@ -2,15 +2,55 @@ use macroquad::prelude::*;
 use serde::{Serialize, Deserialize};
 use crate::simulation::constants::*;
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum EnemyType {
     Nanodrone,
     Nanoguard,
     Leech,
     SiegeConstruct, // Slow, high HP, attacks hull directly
+    Carrier,        // Slow, tough, periodically drops Nanodrones
     Boss,
 }
 
+impl EnemyType {
+    /// Base (HP, speed, damage) before difficulty scaling
+    pub fn base_stats(&self) -> (f32, f32, f32) {
+        match self {
+            EnemyType::Nanodrone => (ENEMY_DRONE_HP, ENEMY_DRONE_SPEED, ENEMY_DRONE_DAMAGE),
+            EnemyType::Nanoguard => (ENEMY_GUARD_HP, ENEMY_GUARD_SPEED, ENEMY_GUARD_DAMAGE),
+            EnemyType::Leech => (ENEMY_LEECH_HP, ENEMY_LEECH_SPEED, ENEMY_LEECH_DAMAGE),
+            EnemyType::SiegeConstruct => (ENEMY_SIEGE_HP, ENEMY_SIEGE_SPEED, ENEMY_SIEGE_DAMAGE),
+            EnemyType::Carrier => (ENEMY_CARRIER_HP, ENEMY_CARRIER_SPEED, ENEMY_CARRIER_DAMAGE),
+            EnemyType::Boss => (ENEMY_BOSS_HP, ENEMY_BOSS_SPEED, ENEMY_BOSS_DAMAGE),
+        }
+    }
+
+    /// Display name for recaps and the ship log
+    pub fn label(&self) -> &'static str {
+        match self {
+            EnemyType::Nanodrone => "Nanodrone",
+            EnemyType::Nanoguard => "Nanoguard",
+            EnemyType::Leech => "Leech",
+            EnemyType::SiegeConstruct => "Siege Construct",
+            EnemyType::Carrier => "Carrier",
+            EnemyType::Boss => "Boss",
+        }
+    }
+}
+
+/// Crowd-control effects applied by the Utility module
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StatusKind {
+    Slowed(f32), // Speed multiplier while slowed
+    Stunned,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct StatusEffect {
+    pub kind: StatusKind,
+    pub remaining: f32,
+}
+
 #[derive(Debug, Clone)]
 pub struct Enemy {
     pub id: u64,
@@ -24,17 +64,23 @@ pub struct Enemy {
     pub attached_to: Option<(usize, usize)>,   // For Leech: module it's attached to
     pub ability_timer: f32,                     // For Boss: cooldown for special abilities
     pub attacking: bool,                        // Tracks if currently dealing damage (for sound throttling)
+    pub animation_timer: f32,                   // Seconds into the enemy's AnimationSet loop
+    pub status: Option<StatusEffect>,
+    pub trail_timer: f32,                       // Accumulates dt between exhaust particles
+    pub pathfinding_waypoints: Vec<Vec2>,       // For SiegeConstruct: cached route around active modules
+    pub pathfinding_dirty: bool,                // Cached route is stale and should be recomputed
+    pub path_start: Vec2,                       // Where the cached route was computed from
+    pub drops_remaining: u32,                   // For Carrier: drone drops left
+    pub elite: bool,                            // Tougher, faster variant worth double scrap
+    pub is_retreating: bool,                    // Badly damaged and fleeing the ship
 }
 
 impl Enemy {
-    pub fn new(id: u64, enemy_type: EnemyType, position: Vec2) -> Self {
-        let (hp, speed, damage) = match enemy_type {
-            EnemyType::Nanodrone => (ENEMY_DRONE_HP, ENEMY_DRONE_SPEED, ENEMY_DRONE_DAMAGE),
-            EnemyType::Nanoguard => (ENEMY_GUARD_HP, ENEMY_GUARD_SPEED, ENEMY_GUARD_DAMAGE),
-            EnemyType::Leech => (ENEMY_LEECH_HP, ENEMY_LEECH_SPEED, ENEMY_LEECH_DAMAGE),
-            EnemyType::SiegeConstruct => (ENEMY_SIEGE_HP, ENEMY_SIEGE_SPEED, ENEMY_SIEGE_DAMAGE),
-            EnemyType::Boss => (ENEMY_BOSS_HP, ENEMY_BOSS_SPEED, ENEMY_BOSS_DAMAGE),
-        };
+    /// `hp_scale` multiplies the base HP (run difficulty, wave bonus, ...)
+    pub fn new(id: u64, enemy_type: EnemyType, position: Vec2, hp_scale: f32) -> Self {
+        let (hp, speed, damage) = enemy_type.base_stats();
+        let hp = hp * hp_scale;
+        let drops_remaining = if enemy_type == EnemyType::Carrier { CARRIER_DROP_COUNT } else { 0 };
 
         Self {
             id,
@@ -48,16 +94,94 @@ impl Enemy {
             attached_to: None,
             ability_timer: 0.0,
             attacking: false,
+            animation_timer: 0.0,
+            status: None,
+            trail_timer: 0.0,
+            pathfinding_waypoints: Vec::new(),
+            pathfinding_dirty: true,
+            path_start: position,
+            drops_remaining,
+            elite: false,
+            is_retreating: false,
         }
     }
+
+    /// Turn this enemy into its elite variant
+    pub fn make_elite(&mut self) {
+        self.elite = true;
+        self.scale_health(ELITE_HP_MULTIPLIER);
+        self.speed *= ELITE_SPEED_MULTIPLIER;
+    }
+
+    /// Multiply current and max health, e.g. for later waves
+    pub fn scale_health(&mut self, mult: f32) {
+        self.health *= mult;
+        self.max_health *= mult;
+    }
+
+    /// Advance the movement cycle animation; the renderer picks the frame
+    /// from the AnimationSet's own frame duration
+    pub fn update_animation(&mut self, dt: f32) {
+        self.animation_timer += dt;
+    }
+
+    /// Apply a status effect. A stun is never overwritten by a slow.
+    pub fn apply_status(&mut self, kind: StatusKind, duration: f32) {
+        if let Some(current) = &self.status {
+            if current.kind == StatusKind::Stunned && kind != StatusKind::Stunned {
+                return;
+            }
+        }
+        self.status = Some(StatusEffect { kind, remaining: duration });
+    }
+
+    /// Tick down the active status effect
+    pub fn update_status(&mut self, dt: f32) {
+        if let Some(effect) = &mut self.status {
+            effect.remaining -= dt;
+            if effect.remaining <= 0.0 {
+                self.status = None;
+            }
+        }
+    }
+
+    /// Movement speed after status effects
+    pub fn effective_speed(&self) -> f32 {
+        match self.status.map(|s| s.kind) {
+            Some(StatusKind::Slowed(factor)) => self.speed * factor,
+            Some(StatusKind::Stunned) => 0.0,
+            None => self.speed,
+        }
+    }
+
+    pub fn is_slowed(&self) -> bool {
+        matches!(self.status.map(|s| s.kind), Some(StatusKind::Slowed(_)))
+    }
+
+    pub fn is_stunned(&self) -> bool {
+        matches!(self.status.map(|s| s.kind), Some(StatusKind::Stunned))
+    }
+
+    /// Siege Constructs shrug off part of each hit until their armor cracks
+    pub fn is_armored(&self) -> bool {
+        self.enemy_type == EnemyType::SiegeConstruct && self.health / self.max_health > SIEGE_ARMOR_THRESHOLD
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct Projectile {
     pub position: Vec2,
-    pub velocity: Vec2,
+    pub velocity: Vec2, // Unit direction for beams
     pub damage: f32,
     pub active: bool,
+    pub trail_cooldown: f32, // Seconds until the next trail particle
+    pub kind: ProjectileType,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ProjectileType {
+    Ballistic,
+    Beam { length: f32, active_time: f32 }, // Instant line hit, visible for active_time
 }
 
 impl Projectile {
@@ -68,11 +192,34 @@ impl Projectile {
             velocity: direction * speed,
             damage,
             active: true,
+            trail_cooldown: 0.0,
+            kind: ProjectileType::Ballistic,
+        }
+    }
+
+    /// A beam from `position` toward `target`, reaching `length` pixels
+    pub fn beam(position: Vec2, target: Vec2, length: f32, damage: f32) -> Self {
+        Self {
+            position,
+            velocity: (target - position).normalize_or_zero(),
+            damage,
+            active: true,
+            trail_cooldown: 0.0,
+            kind: ProjectileType::Beam { length, active_time: BEAM_ACTIVE_TIME },
         }
     }
 }
 
-#[derive(Debug, Clone)]
+/// How a particle moves: explosion debris falls, trails and sparks fly straight
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ParticleKind {
+    Explosion,
+    #[default]
+    Trail,
+    Spark,
+}
+
+#[derive(Debug, Clone, Copy)]
 pub struct Particle {
     pub position: Vec2,
     pub velocity: Vec2,
@@ -80,10 +227,11 @@ pub struct Particle {
     pub max_lifetime: f32,
     pub color: Color,
     pub active: bool,
+    pub kind: ParticleKind,
 }
 
 impl Particle {
-    pub fn new(position: Vec2, velocity: Vec2, lifetime: f32, color: Color) -> Self {
+    pub fn new(position: Vec2, velocity: Vec2, lifetime: f32, color: Color, kind: ParticleKind) -> Self {
         Self {
             position,
             velocity,
@@ -91,6 +239,20 @@ impl Particle {
             max_lifetime: lifetime,
             color,
             active: true,
+            kind,
+        }
+    }
+
+    /// An empty slot for the particle pool
+    pub fn inactive() -> Self {
+        Self {
+            position: Vec2::ZERO,
+            velocity: Vec2::ZERO,
+            lifetime: 0.0,
+            max_lifetime: 0.0,
+            color: BLANK,
+            active: false,
+            kind: ParticleKind::Trail,
         }
     }
 }
@@ -99,6 +261,7 @@ pub struct ScrapPile {
     pub position: Vec2, // Room-relative or Global? Global is easier for drawing/collision
     pub amount: i32,
     pub active: bool,
+    pub contains_schematic: Option<String>, // Upgrade id discovered when gathered
 }
 
 impl ScrapPile {
@@ -107,6 +270,50 @@ impl ScrapPile {
             position,
             amount,
             active: true,
+            contains_schematic: None,
+        }
+    }
+}
+
+/// Enemy structure parked at the screen edge that keeps building Siege Constructs
+/// until it is shot down
+#[derive(Debug, Clone)]
+pub struct SpawnerRoom {
+    pub position: Vec2,
+    pub health: f32,
+    pub spawn_timer: f32, // Seconds until the next Siege Construct
+}
+
+impl SpawnerRoom {
+    pub fn new(position: Vec2) -> Self {
+        Self {
+            position,
+            health: SPAWNER_HP,
+            spawn_timer: SPAWNER_INTERVAL,
+        }
+    }
+
+    /// Whether a point lies inside the spawner's square
+    pub fn contains(&self, point: Vec2) -> bool {
+        let half = SPAWNER_SIZE / 2.0;
+        (point.x - self.position.x).abs() <= half && (point.y - self.position.y).abs() <= half
+    }
+}
+
+/// A boarder inside the ship that chases the player through the interior
+#[derive(Debug, Clone)]
+pub struct InteriorEnemy {
+    pub position: Vec2, // Interior coordinates
+    pub health: f32,
+    pub speed: f32,
+}
+
+impl InteriorEnemy {
+    pub fn new(position: Vec2) -> Self {
+        Self {
+            position,
+            health: INVADER_HP,
+            speed: INVADER_SPEED,
         }
     }
 }
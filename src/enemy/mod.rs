@@ -2,3 +2,4 @@ pub mod ai;
 pub mod entities;
 pub mod combat;
 pub mod wave;
+pub mod pathfinding;
@@ -0,0 +1,81 @@
+// pathfinding.rs - Grid routing for enemies that steer around working modules
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use macroquad::prelude::*;
+use crate::ship::layout::Layout;
+use crate::ship::ship::{ModuleState, Ship};
+use crate::simulation::constants::SIEGE_ACTIVE_CELL_COST;
+
+/// Route from `from` to `to` across the ship grid, treating Active modules as
+/// obstacles that cost SIEGE_ACTIVE_CELL_COST times more to cross than open cells.
+/// Returns cell-center waypoints ending at `to`; empty if there is nowhere to go.
+pub fn find_path_around_modules(ship: &Ship, from: Vec2, to: Vec2) -> Vec<Vec2> {
+    let width = ship.grid.len();
+    let height = if width > 0 { ship.grid[0].len() } else { 0 };
+    if width == 0 || height == 0 {
+        return vec![to];
+    }
+
+    let start = Layout::screen_to_grid_clamped(from);
+    let goal = Layout::screen_to_grid_clamped(to);
+    let index = |(x, y): (usize, usize)| x * height + y;
+
+    // Cost of stepping into a cell; the goal itself never counts as an obstacle
+    let step_cost = |cell: (usize, usize)| -> u32 {
+        let blocked = cell != goal && ship.grid[cell.0][cell.1]
+            .as_ref()
+            .is_some_and(|m| m.state == ModuleState::Active);
+        if blocked { SIEGE_ACTIVE_CELL_COST } else { 1 }
+    };
+
+    // Uniform-cost search (BFS with weighted cells)
+    let mut cost = vec![u32::MAX; width * height];
+    let mut came_from: Vec<Option<(usize, usize)>> = vec![None; width * height];
+    let mut frontier = BinaryHeap::new();
+    cost[index(start)] = 0;
+    frontier.push(Reverse((0u32, start)));
+
+    while let Some(Reverse((current_cost, cell))) = frontier.pop() {
+        if cell == goal { break; }
+        if current_cost > cost[index(cell)] { continue; }
+
+        let (x, y) = cell;
+        let neighbors = [
+            (x.wrapping_sub(1), y),
+            (x + 1, y),
+            (x, y.wrapping_sub(1)),
+            (x, y + 1),
+        ];
+        for next in neighbors {
+            if next.0 >= width || next.1 >= height { continue; }
+            let next_cost = current_cost + step_cost(next);
+            if next_cost < cost[index(next)] {
+                cost[index(next)] = next_cost;
+                came_from[index(next)] = Some(cell);
+                frontier.push(Reverse((next_cost, next)));
+            }
+        }
+    }
+
+    if cost[index(goal)] == u32::MAX {
+        return vec![to];
+    }
+
+    // Walk back from the goal; the goal cell is replaced by the exact target
+    let mut cells = Vec::new();
+    let mut cell = goal;
+    while let Some(prev) = came_from[index(cell)] {
+        cells.push(prev);
+        cell = prev;
+    }
+    cells.reverse();
+
+    let mut waypoints: Vec<Vec2> = cells.into_iter()
+        .skip(1) // Start cell: the enemy is already there (or heading in from off-grid)
+        .map(|(x, y)| Layout::grid_to_screen_center(x, y))
+        .collect();
+    waypoints.push(to);
+    waypoints
+}
@@ -1,9 +1,17 @@
-// wave.rs - Structured state for enemy spawn timing
+// wave.rs - Structured state for enemy spawn timing and wave escalation
+
+use crate::simulation::constants::{BOSS_WARNING_DURATION, WAVE_DURATION, WAVE_HP_BONUS_PER_WAVE};
 
 #[derive(Debug, Clone)]
 pub struct WaveState {
     pub spawn_timer: f32,
     pub guard_timer: f32,
+    pub wave_number: u32,
+    pub wave_bonus_mult: f32, // Health multiplier for newly spawned drones and guards
+    pub carrier_spawned: bool, // One Carrier per round
+    pub spawner_spawned: bool, // One Siege Construct spawner per round
+    pub boss_warning_timer: f32, // Seconds until the boss arrives; 0 when no warning is running
+    pub boss_warned: bool,       // Warning already given for the current engine charge
 }
 
 impl WaveState {
@@ -11,6 +19,12 @@ impl WaveState {
         Self {
             spawn_timer: 0.0,
             guard_timer: 0.0,
+            wave_number: 0,
+            wave_bonus_mult: 1.0,
+            carrier_spawned: false,
+            spawner_spawned: false,
+            boss_warning_timer: 0.0,
+            boss_warned: false,
         }
     }
     
@@ -26,4 +40,28 @@ impl WaveState {
     pub fn reset_guard_timer(&mut self) {
         self.guard_timer = 0.0;
     }
+
+    /// Count down the boss warning. Returns true when a new whole second is reached,
+    /// including the moment the warning starts.
+    pub fn tick_boss_warning(&mut self, dt: f32) -> bool {
+        if !self.boss_warned {
+            self.boss_warned = true;
+            self.boss_warning_timer = BOSS_WARNING_DURATION;
+            return true;
+        }
+        let before = self.boss_warning_timer.ceil();
+        self.boss_warning_timer = (self.boss_warning_timer - dt).max(0.0);
+        self.boss_warning_timer > 0.0 && self.boss_warning_timer.ceil() < before
+    }
+
+    /// Sync the wave number to the run clock. Returns true when a new wave began.
+    pub fn update_wave_number(&mut self, time_survived: f32) -> bool {
+        let wave = (time_survived / WAVE_DURATION) as u32;
+        if wave <= self.wave_number {
+            return false;
+        }
+        self.wave_number = wave;
+        self.wave_bonus_mult = 1.0 + wave as f32 * WAVE_HP_BONUS_PER_WAVE;
+        true
+    }
 }
@@ -0,0 +1,184 @@
+//! Visual and audio feedback for game events, drained once per frame by the main loop
+
+use macroquad::prelude::*;
+
+use crate::enemy::entities::EnemyType;
+use crate::ship::layout::Layout;
+use crate::simulation::constants::*;
+use crate::simulation::environment::EnvEventKind;
+use crate::simulation::events::GameEvent;
+use crate::state::{self, FlavorKind, GameState, LogKind, ViewMode};
+use crate::ui::renderer::Renderer;
+use crate::ui::sound_manager::{SoundEffect, SoundManager};
+
+/// Reacts to one game event, handing module and interior events to their own handlers
+pub fn handle_game_event(event: GameEvent, game_state: &mut GameState, renderer: &mut Renderer, sound_manager: &mut SoundManager) {
+    match event {
+        GameEvent::EnemyKilled { x, y, scrap_dropped, enemy_type } => {
+            game_state.run_stats.record_kill(&enemy_type);
+            game_state.run_stats.scrap_gathered += scrap_dropped;
+            game_state.break_repair_combo();
+            game_state.spawn_flavor_popup(FlavorKind::Kill, vec2(x, y), ViewMode::Exterior);
+            if enemy_type == EnemyType::Boss {
+                game_state.log_event("Boss destroyed".to_string(), LogKind::Warning);
+                renderer.add_trauma_with_frequency(BOSS_KILL_TRAUMA, SHAKE_FREQ_LOW);
+            } else {
+                game_state.log_event(format!("{} destroyed (+{} scrap)", enemy_type.label(), scrap_dropped), LogKind::Info);
+                renderer.add_trauma_with_frequency(ENEMY_KILL_TRAUMA, SHAKE_FREQ_HIGH);
+            }
+            sound_manager.play_sfx_at(SoundEffect::EnemyKilled, x, y, &game_state.settings);
+        }
+        GameEvent::WeaponFired { x, y } => {
+            sound_manager.play_turret_fire(x, y, &game_state.settings);
+        }
+        GameEvent::CoreDestroyed => {
+            renderer.add_trauma_with_frequency(CORE_DESTROY_TRAUMA, SHAKE_FREQ_MEDIUM);
+            sound_manager.play_sfx(SoundEffect::GameOver, &game_state.settings);
+        }
+        GameEvent::WaveTierChanged { new_tier } => {
+            renderer.show_wave_summary(new_tier);
+            game_state.log_event(format!("Enemy activity rising: wave tier {}", new_tier), LogKind::Warning);
+        }
+        GameEvent::BossWarning => {
+            // No dedicated siren yet, the game over sting stands in
+            sound_manager.play_sfx(SoundEffect::GameOver, &game_state.settings);
+        }
+        GameEvent::EngineActivated => {
+            game_state.log_event("Boss detected on approach".to_string(), LogKind::Critical);
+            renderer.add_trauma_with_frequency(ENGINE_ACTIVATE_TRAUMA, SHAKE_FREQ_LOW);
+            sound_manager.play_sfx(SoundEffect::EngineCharge, &game_state.settings);
+        }
+        GameEvent::EscapeSuccess => {
+            sound_manager.play_sfx(SoundEffect::Victory, &game_state.settings);
+        }
+        GameEvent::VictoryAnimationComplete => {
+            game_state.particle_pool.clear();
+            game_state.phase = state::GamePhase::InterRound;
+            game_state.auto_save();
+        }
+        GameEvent::SpawnerDestroyed { x, y } => {
+            renderer.add_trauma_with_frequency(SPAWNER_DESTROY_TRAUMA, SHAKE_FREQ_LOW);
+            game_state.show_toast(format!("Spawner destroyed! +{} credits", SPAWNER_DESTROY_CREDITS));
+            game_state.log_event("Siege Construct spawner destroyed".to_string(), LogKind::Warning);
+            sound_manager.play_sfx_at(SoundEffect::ModuleDestroyed, x, y, &game_state.settings);
+        }
+        GameEvent::SiegeConstructExploded { x, y } => {
+            renderer.add_trauma(SIEGE_EXPLODE_TRAUMA);
+            game_state.spawn_explosion(vec2(x, y));
+            sound_manager.play_sfx_at(SoundEffect::ModuleDestroyed, x, y, &game_state.settings);
+        }
+        GameEvent::EnemyRetreated { .. } => {
+            sound_manager.play_sfx(SoundEffect::EnemyRetreat, &game_state.settings);
+        }
+        GameEvent::SiegeArmorBroken { id } => {
+            renderer.add_trauma(SIEGE_ARMOR_BREAK_TRAUMA);
+            // No dedicated armor sound yet, the module destroyed crunch stands in
+            match game_state.enemies.iter().find(|e| e.id == id) {
+                Some(enemy) => sound_manager.play_sfx_at(SoundEffect::ModuleDestroyed, enemy.position.x, enemy.position.y, &game_state.settings),
+                None => sound_manager.play_sfx(SoundEffect::ModuleDestroyed, &game_state.settings),
+            }
+        }
+        GameEvent::CarrierDeployed { x, y } => {
+            sound_manager.play_sfx_at(SoundEffect::CarrierDrop, x, y, &game_state.settings);
+        }
+        GameEvent::FormationSpawned { .. } => {
+            sound_manager.play_sfx(SoundEffect::FormationWarning, &game_state.settings);
+        }
+        other => handle_module_event(other, game_state, renderer, sound_manager),
+    }
+}
+
+/// Module damage, repairs and layout changes on the exterior grid
+fn handle_module_event(event: GameEvent, game_state: &mut GameState, renderer: &mut Renderer, sound_manager: &mut SoundManager) {
+    match event {
+        GameEvent::ModuleDamaged { x, y, damage } => {
+            renderer.add_trauma(damage * MODULE_DAMAGE_TRAUMA);
+            let pos = Layout::grid_to_screen_center(x, y);
+            sound_manager.play_sfx_at(SoundEffect::ModuleDamaged, pos.x, pos.y, &game_state.settings);
+        }
+        GameEvent::ModuleDestroyed { x, y } => {
+            game_state.on_module_destroyed(x, y);
+            game_state.refresh_synergies();
+            game_state.invalidate_siege_paths();
+            renderer.add_trauma(MODULE_DESTROY_TRAUMA);
+            let pos = Layout::grid_to_screen_center(x, y);
+            sound_manager.play_sfx_at(SoundEffect::ModuleDestroyed, pos.x, pos.y, &game_state.settings);
+        }
+        GameEvent::ModuleRepaired { x, y, cost } => {
+            game_state.run_stats.repairs_performed += 1;
+            // Interior repairs happen where the player stands
+            let view = game_state.view_mode;
+            let pos = match view {
+                ViewMode::Interior => game_state.player.position,
+                ViewMode::Exterior => Layout::grid_to_screen_center(x, y),
+            };
+            game_state.spawn_flavor_popup(FlavorKind::Repair, pos, view);
+            // Interior repairs report (0, 0), so the coordinates are left out
+            game_state.log_event(format!("Repair completed ({} scrap)", cost), LogKind::Info);
+            game_state.refresh_synergies();
+            game_state.invalidate_siege_paths();
+            sound_manager.play_sfx(SoundEffect::Repair, &game_state.settings);
+        }
+        GameEvent::ModuleSold { x, y, refund } => {
+            game_state.refresh_synergies();
+            game_state.invalidate_siege_paths();
+            game_state.show_toast(format!("Module sold for {} scrap", refund));
+            let pos = Layout::grid_to_screen_center(x, y);
+            sound_manager.play_sfx_at(SoundEffect::ScrapCollected, pos.x, pos.y, &game_state.settings);
+        }
+        GameEvent::ModuleOvercharged { x, y } => {
+            game_state.show_toast(format!("Module overcharged for {:.0}s", OVERCHARGE_DURATION));
+            let pos = Layout::grid_to_screen_center(x, y);
+            sound_manager.play_sfx_at(SoundEffect::EngineCharge, pos.x, pos.y, &game_state.settings);
+        }
+        GameEvent::HullBreached { x, y } => {
+            renderer.add_trauma(HULL_BREACH_TRAUMA);
+            game_state.log_event(format!("Hull breached at ({}, {})", x, y), LogKind::Warning);
+            let pos = Layout::grid_to_screen_center(x, y);
+            sound_manager.play_sfx_at(SoundEffect::HullBreach, pos.x, pos.y, &game_state.settings);
+        }
+        GameEvent::LayoutChanged { old_size, new_size } => {
+            game_state.on_layout_changed(old_size, new_size);
+        }
+        other => handle_interior_event(other, game_state, renderer, sound_manager),
+    }
+}
+
+/// Environment hazards, boarders, collapsed rooms and scrap inside the ship
+fn handle_interior_event(event: GameEvent, game_state: &mut GameState, renderer: &mut Renderer, sound_manager: &mut SoundManager) {
+    match event {
+        GameEvent::EnvironmentEventStarted { kind, .. } => {
+            renderer.add_trauma(ENV_EVENT_TRAUMA);
+            let sfx = match kind {
+                EnvEventKind::PowerSurge => SoundEffect::ModuleDamaged,
+                EnvEventKind::HullBreach => SoundEffect::HullBreach,
+            };
+            sound_manager.play_sfx(sfx, &game_state.settings);
+            game_state.show_toast(format!("{}!", kind.label()));
+        }
+        GameEvent::InteriorThreat { .. } => {
+            sound_manager.play_sfx(SoundEffect::HullBreach, &game_state.settings);
+            game_state.show_toast("INTRUDER ALERT: boarders inside the ship".to_string());
+        }
+        GameEvent::RoomCollapsed { room_id } => {
+            renderer.add_trauma(HULL_BREACH_TRAUMA);
+            let name = game_state.interior.rooms.iter()
+                .find(|r| r.id == room_id)
+                .map(|r| r.name())
+                .filter(|n| !n.is_empty())
+                .unwrap_or("A room");
+            game_state.show_toast(format!("{} collapsed! Clear it for {} scrap", name, ROOM_COLLAPSE_REPAIR_COST));
+            game_state.log_event(format!("{} collapsed (clear for {} scrap)", name, ROOM_COLLAPSE_REPAIR_COST), LogKind::Warning);
+            sound_manager.play_sfx(SoundEffect::HullBreach, &game_state.settings);
+        }
+        GameEvent::ScrapGathered { .. } => {
+            let pos = game_state.player.position;
+            game_state.spawn_flavor_popup(FlavorKind::Scrap, pos, ViewMode::Interior);
+            sound_manager.play_sfx(SoundEffect::ScrapCollected, &game_state.settings);
+        }
+        GameEvent::ScrapRespawned { count } => {
+            game_state.show_toast(format!("Scrap restocked: {} new piles", count));
+        }
+        _ => {}
+    }
+}
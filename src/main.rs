@@ -7,18 +7,32 @@ mod simulation;
 mod state;
 mod ui;
 mod data;
+mod event_handler;
 
 use state::GameState;
 // use ui::assets::AssetManager;
 use ui::renderer::Renderer;
 use ui::sound_manager::{SoundManager, SoundEffect};
-use simulation::events::{EventBus, GameEvent};
-use simulation::constants::*;
+use simulation::events::EventBus;
+use simulation::constants::MAX_GAME_EVENTS;
 
 #[macroquad::main("Scrapyard Planet")]
 async fn main() {
+    macroquad::rand::srand(macroquad::miniquad::date::now() as u64);
+    // Touches are turned into clicks by ui::touch, which also tells taps from swipes
+    simulate_mouse_with_touch(false);
+    let tips = ui::ui_renderer::load_tips();
+    let tip = if tips.is_empty() { "" } else { tips[macroquad::rand::gen_range(0, tips.len())] };
+
+    ui::ui_renderer::draw_loading_screen(0.0, tip);
+    next_frame().await;
+
     let mut game_state = GameState::new();
-    game_state.assets.load_assets().await;
+    game_state.assets
+        .load_assets_with_progress(|progress| ui::ui_renderer::draw_loading_screen(progress, tip))
+        .await;
+    // The packed atlas is optional; individual textures are used without it
+    let _ = game_state.assets.load_atlas("assets/atlas.png", "assets/atlas.json").await;
     
     let mut sound_manager = SoundManager::new();
     sound_manager.load_sounds().await;
@@ -33,6 +47,12 @@ async fn main() {
         
         // 1. Gather input and push UI events
         input_manager.update(&mut game_state, &mut event_bus);
+        renderer.set_hover(input_manager.hovered_module);
+        renderer.set_zoom(game_state.settings.zoom);
+        renderer.set_interior_focus(
+            game_state.player.position,
+            vec2(game_state.interior.width, game_state.interior.height),
+        );
         
         // 2. Process UI events
         state::process_ui_events(&mut game_state, &mut event_bus);
@@ -41,54 +61,52 @@ async fn main() {
         if !game_state.paused {
             game_state.update(dt, &mut event_bus);
         }
+        game_state.sync_phase();
         
         // 4. Update renderer (shake decay)
-        renderer.update(dt);
+        renderer.update(dt, &mut event_bus);
         
         // 5. Process game events for visual and audio feedback
         // Update sound enabled state based on master volume
         sound_manager.set_enabled(game_state.settings.master_volume > 0.0);
+        sound_manager.begin_frame(dt);
         
-        for event in event_bus.drain_game() {
-            match event {
-                GameEvent::EnemyKilled { .. } => {
-                    renderer.add_trauma(ENEMY_KILL_TRAUMA);
-                    sound_manager.play_sfx(SoundEffect::EnemyKilled, &game_state.settings);
-                }
-                GameEvent::ModuleDamaged { damage, .. } => {
-                    renderer.add_trauma(damage * MODULE_DAMAGE_TRAUMA);
-                    sound_manager.play_sfx(SoundEffect::ModuleDamaged, &game_state.settings);
-                }
-                GameEvent::ModuleDestroyed { .. } => {
-                    renderer.add_trauma(MODULE_DESTROY_TRAUMA);
-                    sound_manager.play_sfx(SoundEffect::ModuleDestroyed, &game_state.settings);
-                }
-                GameEvent::ModuleRepaired { .. } => {
-                    sound_manager.play_sfx(SoundEffect::Repair, &game_state.settings);
-                }
-                GameEvent::CoreDestroyed => {
-                    renderer.add_trauma(CORE_DESTROY_TRAUMA);
-                    sound_manager.play_sfx(SoundEffect::GameOver, &game_state.settings);
-                }
-                GameEvent::EngineActivated => {
-                    renderer.add_trauma(ENGINE_ACTIVATE_TRAUMA);
-                    sound_manager.play_sfx(SoundEffect::EngineCharge, &game_state.settings);
-                }
-                GameEvent::EscapeSuccess => {
-                    sound_manager.play_sfx(SoundEffect::Victory, &game_state.settings);
-                }
-                _ => {}
-            }
+        // One frame's worth at most; anything pushed past that was already dropped
+        for event in event_bus.drain_game_with_limit(MAX_GAME_EVENTS) {
+            event_handler::handle_game_event(event, &mut game_state, &mut renderer, &mut sound_manager);
         }
 
+        // Fire crackle loops while any room is burning
+        let fire_audible = game_state.phase == state::GamePhase::Playing && !game_state.paused
+            && game_state.interior.any_burning();
+        sound_manager.set_looping(SoundEffect::Fire, fire_audible, &game_state.settings);
+
+        // Music tension only tracks threat during an active run
+        let (engine_stress, nanite_alert) = if game_state.phase == state::GamePhase::Playing {
+            (game_state.engine_stress, game_state.nanite_alert)
+        } else {
+            (0.0, 0.0)
+        };
+        sound_manager.update(engine_stress, nanite_alert, &game_state.settings);
+
         // Draw
         clear_background(BLACK);
         renderer.draw(&game_state);
         
-        // Debug: show sound status
-        if game_state.settings.show_fps && sound_manager.has_sounds() {
-            macroquad::prelude::draw_text("♪ Sound ON", 10.0, 30.0, 16.0, GREEN);
+        // Debug: show sound status and event bus load
+        if game_state.settings.show_fps {
+            if sound_manager.has_sounds() {
+                macroquad::prelude::draw_text("♪ Sound ON", 10.0, 30.0, 16.0, GREEN);
+            }
+            let stats = event_bus.stats();
+            let text = format!(
+                "FPS: {} | Events: {} pushed, {} processed, {} dropped ({} total)",
+                get_fps(), stats.pushed, stats.processed, stats.dropped, stats.total_dropped
+            );
+            let color = if stats.total_dropped > 0 { ORANGE } else { GRAY };
+            macroquad::prelude::draw_text(&text, 10.0, 48.0, 16.0, color);
         }
+        event_bus.end_frame();
 
         next_frame().await
     }
@@ -8,6 +8,7 @@ use crate::ship::ship::ModuleType;
 pub const ROOM_SIZE: f32 = 64.0;
 pub const CORRIDOR_WIDTH: f32 = 32.0;
 pub const REPAIR_POINT_SIZE: f32 = 24.0;
+pub const DOOR_THICKNESS: f32 = 12.0;
 
 /// A repair point within a room (subsystem to repair)
 #[derive(Debug, Clone)]
@@ -16,11 +17,12 @@ pub struct RepairPoint {
     pub x: f32,         // Position relative to room
     pub y: f32,
     pub repaired: bool, // Whether this point has been repaired
+    pub repair_progress: f32, // 0-1 while the player holds E on it; reset on release
 }
 
 impl RepairPoint {
     pub fn new(id: usize, x: f32, y: f32) -> Self {
-        Self { id, x, y, repaired: false }
+        Self { id, x, y, repaired: false, repair_progress: 0.0 }
     }
     
     /// Check if position is within this repair point
@@ -56,6 +58,50 @@ pub struct RoomData {
     pub connections: Vec<usize>,
     #[serde(default)]
     pub repair_points: Vec<RepairPointData>,
+    #[serde(default)]
+    pub event: Option<RoomEvent>,
+}
+
+/// Scripted moment attached to a room in the ship JSON
+#[derive(Debug, Clone, Deserialize)]
+pub struct RoomEvent {
+    #[serde(default)]
+    pub trigger: RoomEventTrigger,
+    #[serde(rename = "type")]
+    pub kind: RoomEventKind,
+    #[serde(default)]
+    pub message: String, // Shown as a popup and logged; optional except for flavor_text
+    #[serde(default)]
+    pub amount: i32,     // Scrap for give_scrap
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RoomEventTrigger {
+    #[default]
+    FirstEntry,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RoomEventKind {
+    FlavorText,
+    GiveScrap,
+    SpawnEnemyNearby, // A boarder appears in the first connected room
+    TutorialHint,     // Advances the tutorial one step
+}
+
+/// JSON structure for a door between two touching rooms
+#[derive(Debug, Clone, Deserialize)]
+pub struct DoorData {
+    pub room_a: usize,
+    pub room_b: usize,
+    #[serde(default = "default_door_open")]
+    pub open: bool,
+}
+
+fn default_door_open() -> bool {
+    true
 }
 
 /// JSON structure for ship data
@@ -66,9 +112,45 @@ pub struct ShipData {
     pub width: f32,
     pub height: f32,
     pub rooms: Vec<RoomData>,
+    #[serde(default)]
+    pub doors: Vec<DoorData>,
     pub player_start_room: usize,
 }
 
+/// Path of the hull used when no other ship has been chosen
+pub const DEFAULT_SHIP_PATH: &str = "ships/starter_ship.json";
+
+/// Ship layouts embedded at compile time, keyed by their manifest path
+const EMBEDDED_SHIPS: &[(&str, &str)] = &[
+    ("ships/starter_ship.json", include_str!("../../assets/ships/starter_ship.json")),
+    ("ships/heavy_carrier.json", include_str!("../../assets/ships/heavy_carrier.json")),
+];
+
+/// A selectable hull listed in the ship manifest
+#[derive(Debug, Clone, Deserialize)]
+pub struct ShipManifestEntry {
+    pub name: String,
+    pub description: String,
+    pub path: String,
+}
+
+/// JSON structure listing every selectable hull
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ShipManifest {
+    pub ships: Vec<ShipManifestEntry>,
+}
+
+impl ShipManifest {
+    /// Load the ship manifest from JSON (embedded at compile time)
+    pub fn load() -> Self {
+        const MANIFEST_JSON: &str = include_str!("../../assets/ships/manifest.json");
+        serde_json::from_str(MANIFEST_JSON).unwrap_or_else(|e| {
+            eprintln!("Warning: Failed to load ship manifest: {}. No hulls to select.", e);
+            Self::default()
+        })
+    }
+}
+
 /// Type of room in the ship interior
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum RoomType {
@@ -77,6 +159,7 @@ pub enum RoomType {
     Storage,
     Cockpit,
     Medbay,
+    Laboratory,
     Empty,
 }
 
@@ -88,10 +171,12 @@ impl RoomType {
             "shield" => RoomType::Module(ModuleType::Defense),
             "engine" => RoomType::Module(ModuleType::Engine),
             "utility" => RoomType::Module(ModuleType::Utility),
+            "drone_bay" => RoomType::Module(ModuleType::DroneBay),
             "corridor" => RoomType::Corridor,
             "storage" => RoomType::Storage,
             "cockpit" => RoomType::Cockpit,
             "medbay" => RoomType::Medbay,
+            "laboratory" => RoomType::Laboratory,
             _ => RoomType::Empty,
         }
     }
@@ -109,6 +194,9 @@ pub struct Room {
     pub module_index: Option<(usize, usize)>,
     pub connections: Vec<usize>,
     pub repair_points: Vec<RepairPoint>,
+    pub fire_level: f32, // 0.0 = not burning, 1.0 = fully ablaze
+    pub room_damage: f32, // Structural damage: 0.0 = intact, 1.0 = collapsed
+    pub event: Option<RoomEvent>,
 }
 
 impl Room {
@@ -123,14 +211,33 @@ impl Room {
             module_index: None,
             connections: Vec::new(),
             repair_points: Vec::new(),
+            fire_level: 0.0,
+            room_damage: 0.0,
+            event: None,
         }
     }
 
+    pub fn is_burning(&self) -> bool {
+        self.fire_level > 0.0
+    }
+
+    /// Collapsed rooms block the player until the rubble is cleared
+    pub fn is_collapsed(&self) -> bool {
+        self.room_damage >= 1.0
+    }
+
     /// Count how many repair points are repaired
     pub fn repaired_count(&self) -> usize {
         self.repair_points.iter().filter(|p| p.repaired).count()
     }
 
+    /// Repaired points plus the progress of any point being repaired right now
+    pub fn repair_amount(&self) -> f32 {
+        self.repair_points.iter()
+            .map(|p| if p.repaired { 1.0 } else { p.repair_progress })
+            .sum()
+    }
+
     /// Check if all repair points are repaired
     pub fn is_fully_repaired(&self) -> bool {
         !self.repair_points.is_empty() && self.repair_points.iter().all(|p| p.repaired)
@@ -165,6 +272,12 @@ impl Room {
         vec2(self.x + self.width / 2.0, self.y + self.height / 2.0)
     }
 
+    /// Distance from `pos` to the nearest edge of the room (0 when inside)
+    pub fn distance_to(&self, pos: Vec2) -> f32 {
+        let nearest = pos.clamp(vec2(self.x, self.y), vec2(self.x + self.width, self.y + self.height));
+        nearest.distance(pos)
+    }
+
     pub fn color(&self) -> Color {
         match self.room_type {
             RoomType::Module(ModuleType::Core) => color_u8!(100, 30, 30, 255),
@@ -172,11 +285,13 @@ impl Room {
             RoomType::Module(ModuleType::Defense) => color_u8!(30, 50, 100, 255),
             RoomType::Module(ModuleType::Engine) => color_u8!(80, 30, 100, 255),
             RoomType::Module(ModuleType::Utility) => color_u8!(30, 80, 50, 255),
+            RoomType::Module(ModuleType::DroneBay) => color_u8!(30, 75, 85, 255),
             RoomType::Module(ModuleType::Empty) => color_u8!(50, 50, 55, 255),
             RoomType::Corridor => color_u8!(40, 40, 45, 255),
             RoomType::Storage => color_u8!(60, 55, 45, 255),
             RoomType::Cockpit => color_u8!(50, 70, 90, 255),
             RoomType::Medbay => color_u8!(80, 80, 100, 255),
+            RoomType::Laboratory => color_u8!(70, 50, 90, 255),
             RoomType::Empty => color_u8!(20, 20, 25, 255),
         }
     }
@@ -188,21 +303,72 @@ impl Room {
             RoomType::Module(ModuleType::Defense) => "SHIELDS",
             RoomType::Module(ModuleType::Engine) => "ENGINES",
             RoomType::Module(ModuleType::Utility) => "SYSTEMS",
+            RoomType::Module(ModuleType::DroneBay) => "DRONE BAY",
             RoomType::Module(ModuleType::Empty) => "",
             RoomType::Corridor => "",
             RoomType::Storage => "STORAGE",
             RoomType::Cockpit => "COCKPIT",
             RoomType::Medbay => "MEDBAY",
+            RoomType::Laboratory => "LABORATORY",
             RoomType::Empty => "",
         }
     }
 }
 
+/// A door on the shared wall of two rooms; closed doors block movement
+#[derive(Debug, Clone)]
+pub struct Door {
+    pub id: usize,
+    pub room_a: usize,
+    pub room_b: usize,
+    pub open: bool,
+    pub position: Vec2, // Midpoint of the shared wall
+    pub size: Vec2,     // Extent of the door across the wall
+}
+
+impl Door {
+    /// Place a door on the wall `a` and `b` share. None if the rooms don't touch.
+    pub fn between(id: usize, a: &Room, b: &Room, open: bool) -> Option<Self> {
+        let overlap = |a0: f32, a1: f32, b0: f32, b1: f32| (a0.max(b0), a1.min(b1));
+        let touching = |x: f32, y: f32| (x - y).abs() < 0.5;
+
+        let (position, size) = if touching(a.x + a.width, b.x) || touching(b.x + b.width, a.x) {
+            let wall_x = if touching(a.x + a.width, b.x) { b.x } else { a.x };
+            let (y0, y1) = overlap(a.y, a.y + a.height, b.y, b.y + b.height);
+            if y1 <= y0 { return None; }
+            (vec2(wall_x, (y0 + y1) / 2.0), vec2(DOOR_THICKNESS, y1 - y0))
+        } else if touching(a.y + a.height, b.y) || touching(b.y + b.height, a.y) {
+            let wall_y = if touching(a.y + a.height, b.y) { b.y } else { a.y };
+            let (x0, x1) = overlap(a.x, a.x + a.width, b.x, b.x + b.width);
+            if x1 <= x0 { return None; }
+            (vec2((x0 + x1) / 2.0, wall_y), vec2(x1 - x0, DOOR_THICKNESS))
+        } else {
+            return None;
+        };
+
+        Some(Self { id, room_a: a.id, room_b: b.id, open, position, size })
+    }
+
+    pub fn contains(&self, pos: Vec2) -> bool {
+        let half = self.size / 2.0;
+        (pos.x - self.position.x).abs() <= half.x && (pos.y - self.position.y).abs() <= half.y
+    }
+
+    /// Distance from `pos` to the nearest edge of the door (0 when inside)
+    pub fn distance_to(&self, pos: Vec2) -> f32 {
+        let half = self.size / 2.0;
+        let nearest = pos.clamp(self.position - half, self.position + half);
+        nearest.distance(pos)
+    }
+}
+
 /// The ship interior layout
 pub struct ShipInterior {
     pub rooms: Vec<Room>,
+    pub doors: Vec<Door>,
     pub width: f32,
     pub height: f32,
+    pub player_start_room: usize,
 }
 
 impl ShipInterior {
@@ -214,6 +380,7 @@ impl ShipInterior {
             let room_type = RoomType::from_str(&rd.room_type);
             let mut room = Room::new(rd.id, room_type, rd.x, rd.y, rd.w, rd.h);
             room.connections = rd.connections.clone();
+            room.event = rd.event.clone();
             if let Some([gx, gy]) = rd.module {
                 room.module_index = Some((gx, gy));
             }
@@ -224,29 +391,75 @@ impl ShipInterior {
             room
         }).collect();
 
+        let room = |id: usize| rooms.iter().find(|r| r.id == id);
+        let doors = data.doors.iter().enumerate()
+            .filter_map(|(i, dd)| {
+                let door = Door::between(i, room(dd.room_a)?, room(dd.room_b)?, dd.open);
+                if door.is_none() {
+                    eprintln!("Warning: Rooms {} and {} don't share a wall, skipping door", dd.room_a, dd.room_b);
+                }
+                door
+            })
+            .collect();
+
         Ok(Self {
             rooms,
+            doors,
             width: data.width,
             height: data.height,
+            player_start_room: data.player_start_room,
         })
     }
 
     /// Create the starter ship layout from JSON
     pub fn starter_ship() -> Self {
-        const SHIP_JSON: &str = include_str!("../../assets/ships/starter_ship.json");
-        Self::from_json(SHIP_JSON).unwrap_or_else(|e| {
-            eprintln!("Warning: Failed to load starter ship: {}. Using fallback.", e);
-            Self {
-                rooms: Vec::new(),
-                width: 1000.0,
-                height: 600.0,
+        Self::load(DEFAULT_SHIP_PATH)
+    }
+
+    /// Load a ship layout by its manifest path, falling back to the starter ship
+    pub fn load(path: &str) -> Self {
+        let json = EMBEDDED_SHIPS.iter()
+            .find(|(p, _)| *p == path)
+            .map(|(_, json)| *json);
+
+        match json.map(Self::from_json) {
+            Some(Ok(interior)) => interior,
+            Some(Err(e)) if path != DEFAULT_SHIP_PATH => {
+                eprintln!("Warning: Failed to load ship {}: {}. Using starter ship.", path, e);
+                Self::starter_ship()
+            }
+            None if path != DEFAULT_SHIP_PATH => {
+                eprintln!("Warning: Unknown ship {}. Using starter ship.", path);
+                Self::starter_ship()
+            }
+            Some(Err(e)) => {
+                eprintln!("Warning: Failed to load starter ship: {}. Using fallback.", e);
+                Self::fallback()
             }
+            None => Self::fallback(),
+        }
+    }
+
+    fn fallback() -> Self {
+        Self {
+            rooms: Vec::new(),
+            doors: Vec::new(),
+            width: 1000.0,
+            height: 600.0,
+            player_start_room: 0,
+        }
+    }
+
+    /// Grid cells and module types that the interior's module rooms map onto
+    pub fn module_slots(&self) -> impl Iterator<Item = ((usize, usize), ModuleType)> + '_ {
+        self.rooms.iter().filter_map(|r| match (r.room_type, r.module_index) {
+            (RoomType::Module(module_type), Some(cell)) => Some((cell, module_type)),
+            _ => None,
         })
     }
 
     pub fn player_start_position(&self) -> Vec2 {
-        // Room 12 is the core (player start)
-        if let Some(room) = self.rooms.iter().find(|r| r.id == 12) {
+        if let Some(room) = self.rooms.iter().find(|r| r.id == self.player_start_room) {
             room.center()
         } else if let Some(first) = self.rooms.first() {
             first.center()
@@ -260,17 +473,71 @@ impl ShipInterior {
         self.rooms.iter().find(|r| r.contains(pos))
     }
 
-    /// Check if position is walkable (in a non-Empty room)
+    /// Check if position is walkable (in a non-Empty, uncollapsed room and not inside a closed door)
     pub fn is_walkable(&self, pos: Vec2) -> bool {
+        if self.doors.iter().any(|d| !d.open && d.contains(pos)) {
+            return false;
+        }
         if let Some(room) = self.room_at(pos) {
-            room.room_type != RoomType::Empty
+            room.room_type != RoomType::Empty && !room.is_collapsed()
         } else {
             false
         }
     }
 
+    /// Index of the closest collapsed room within `range` of a position
+    pub fn collapsed_room_near(&self, pos: Vec2, range: f32) -> Option<usize> {
+        self.rooms.iter().enumerate()
+            .filter(|(_, r)| r.is_collapsed())
+            .map(|(i, r)| (i, r.distance_to(pos)))
+            .filter(|(_, dist)| *dist <= range)
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(i, _)| i)
+    }
+
+    /// Closest door within `range` of a position
+    pub fn door_near(&self, pos: Vec2, range: f32) -> Option<usize> {
+        self.doors.iter().enumerate()
+            .map(|(i, d)| (i, d.distance_to(pos)))
+            .filter(|(_, dist)| *dist <= range)
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(i, _)| i)
+    }
+
+    /// Check if any room is currently on fire
+    pub fn any_burning(&self) -> bool {
+        self.rooms.iter().any(|r| r.is_burning())
+    }
+
     /// Get module room if player is in one
     pub fn module_room_at(&self, pos: Vec2) -> Option<&Room> {
         self.room_at(pos).filter(|r| matches!(r.room_type, RoomType::Module(_)))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_manifest_ship_parses() {
+        let manifest = ShipManifest::load();
+        assert!(!manifest.ships.is_empty(), "ship manifest lists no hulls");
+
+        for entry in &manifest.ships {
+            let (_, json) = EMBEDDED_SHIPS.iter()
+                .find(|(p, _)| *p == entry.path)
+                .unwrap_or_else(|| panic!("{} is not embedded", entry.path));
+            let data: ShipData = serde_json::from_str(json)
+                .unwrap_or_else(|e| panic!("{} failed to parse: {}", entry.path, e));
+
+            let has_room = |id: usize| data.rooms.iter().any(|r| r.id == id);
+            assert!(has_room(data.player_start_room), "{}: start room {} missing", entry.path, data.player_start_room);
+            for room in &data.rooms {
+                for &id in &room.connections {
+                    assert!(has_room(id), "{}: room {} connects to missing room {}", entry.path, room.id, id);
+                }
+            }
+        }
+    }
+}
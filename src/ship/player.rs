@@ -3,6 +3,7 @@
 use macroquad::prelude::*;
 use crate::simulation::constants::*;
 use crate::ship::interior::ShipInterior;
+use crate::data::settings::KeyBindings;
 
 /// Interior scale: each grid cell becomes a large room
 /// Room size in pixels = CELL_SIZE * ROOM_SCALE
@@ -19,6 +20,10 @@ pub struct Player {
     pub facing: Vec2,         // Direction player is facing
     pub velocity: Vec2,       // Current velocity (for gathering logic)
     pub nearby_module: Option<(usize, usize)>, // Module player can interact with
+    pub stamina: f32,
+    pub max_stamina: f32,
+    pub sprinting: bool,      // Sprinted this frame
+    pub winded: bool,         // Ran out of stamina; no sprinting until STAMINA_RECOVER_THRESHOLD
 }
 
 impl Player {
@@ -34,46 +39,66 @@ impl Player {
             facing: vec2(0.0, -1.0),
             velocity: Vec2::ZERO,
             nearby_module: None,
+            stamina: PLAYER_MAX_STAMINA,
+            max_stamina: PLAYER_MAX_STAMINA,
+            sprinting: false,
+            winded: false,
         }
     }
 
-    /// Update player movement based on input
-    pub fn update(&mut self, dt: f32, interior: &ShipInterior) {
+    /// Update player movement based on input.
+    /// `move_override` (e.g. a gamepad stick) is used when no movement keys are held.
+    pub fn update(&mut self, dt: f32, interior: &ShipInterior, move_override: Option<Vec2>, sprint: bool, bindings: &KeyBindings) {
         let mut move_dir = Vec2::ZERO;
         
-        // WASD and Arrow key movement
-        if is_key_down(KeyCode::W) || is_key_down(KeyCode::Up) {
+        // Bound movement keys (WASD by default) and Arrow keys
+        if is_key_down(bindings.move_up) || is_key_down(KeyCode::Up) {
             move_dir.y -= 1.0;
         }
-        if is_key_down(KeyCode::S) || is_key_down(KeyCode::Down) {
+        if is_key_down(bindings.move_down) || is_key_down(KeyCode::Down) {
             move_dir.y += 1.0;
         }
-        if is_key_down(KeyCode::A) || is_key_down(KeyCode::Left) {
+        if is_key_down(bindings.move_left) || is_key_down(KeyCode::Left) {
             move_dir.x -= 1.0;
         }
-        if is_key_down(KeyCode::D) || is_key_down(KeyCode::Right) {
+        if is_key_down(bindings.move_right) || is_key_down(KeyCode::Right) {
             move_dir.x += 1.0;
         }
+        if move_dir == Vec2::ZERO {
+            if let Some(dir) = move_override {
+                move_dir = dir;
+            }
+        }
+
+        let moving = move_dir.length_squared() > 0.0;
+        self.update_stamina(dt, moving && sprint);
 
-        if move_dir.length_squared() > 0.0 {
+        if moving {
             move_dir = move_dir.normalize();
             self.facing = move_dir;
-            self.velocity = move_dir * self.speed;
+            let speed = if self.sprinting { self.speed * SPRINT_SPEED_MULTIPLIER } else { self.speed };
+            self.velocity = move_dir * speed;
             
             let new_pos = self.position + self.velocity * dt;
+
+            // A room that collapses around the player still lets them walk out
+            let stranded = interior.room_at(self.position).is_some_and(|r| r.is_collapsed());
+            let walkable = |pos: Vec2| {
+                interior.is_walkable(pos) || (stranded && interior.room_at(pos).is_some_and(|r| r.is_collapsed()))
+            };
             
             // Check collision with rooms - try full movement first
-            if interior.is_walkable(new_pos) {
+            if walkable(new_pos) {
                 self.position = new_pos;
             } else {
                 // Try X-only movement
                 let new_x = vec2(new_pos.x, self.position.y);
-                if interior.is_walkable(new_x) {
+                if walkable(new_x) {
                     self.position = new_x;
                 }
                 // Try Y-only movement
                 let new_y = vec2(self.position.x, new_pos.y);
-                if interior.is_walkable(new_y) {
+                if walkable(new_y) {
                     self.position = new_y;
                 }
             }
@@ -82,6 +107,24 @@ impl Player {
         }
     }
 
+    /// Drain stamina while sprinting and recover it otherwise. Running dry leaves the
+    /// player winded until stamina climbs back to STAMINA_RECOVER_THRESHOLD.
+    fn update_stamina(&mut self, dt: f32, wants_sprint: bool) {
+        if self.winded && self.stamina >= STAMINA_RECOVER_THRESHOLD {
+            self.winded = false;
+        }
+        self.sprinting = wants_sprint && !self.winded && self.stamina > 0.0;
+
+        if self.sprinting {
+            self.stamina = (self.stamina - STAMINA_DRAIN_RATE * dt).max(0.0);
+            if self.stamina <= 0.0 {
+                self.winded = true;
+            }
+        } else {
+            self.stamina = (self.stamina + STAMINA_RECOVER_RATE * dt).min(self.max_stamina);
+        }
+    }
+
     /// Convert player position to grid coordinates
     pub fn get_grid_position(&self) -> (usize, usize) {
         let gx = (self.position.x / (CELL_SIZE * ROOM_SCALE)).floor() as usize;
@@ -1,5 +1,6 @@
 use macroquad::prelude::*;
 use serde::{Serialize, Deserialize};
+use crate::simulation::constants::HULL_BREACH_HEALTH_PENALTY;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum ModuleType {
@@ -8,6 +9,7 @@ pub enum ModuleType {
     Utility,
     Engine,
     Core,
+    DroneBay,
     Empty, // Slot exists but no module built
 }
 
@@ -27,6 +29,23 @@ pub struct Module {
     pub max_health: f32,
     #[serde(default)]
     pub cooldown: f32,
+    #[serde(default)]
+    pub hull_breached: bool,
+    /// Direction (radians) of the weapon's last target
+    #[serde(default)]
+    pub target_angle: f32,
+    /// Direction the turret currently faces, eased toward `target_angle`
+    #[serde(default)]
+    pub turret_angle: f32,
+    /// Orbit angle (radians) of a drone bay's first drone around the core
+    #[serde(default)]
+    pub drone_angle: f32,
+    /// Screen positions of a drone bay's active drones, one per repaired point
+    #[serde(skip)]
+    pub drone_positions: Vec<Vec2>,
+    /// `time_survived` when the current overcharge ends; 0 when not overcharged
+    #[serde(default)]
+    pub overcharged_until: f32,
 }
 
 impl Module {
@@ -38,8 +57,39 @@ impl Module {
             health: 100.0,
             max_health: 100.0,
             cooldown: 0.0,
+            hull_breached: false,
+            target_angle: -std::f32::consts::FRAC_PI_2, // Facing up
+            turret_angle: -std::f32::consts::FRAC_PI_2,
+            drone_angle: 0.0,
+            drone_positions: Vec::new(),
+            overcharged_until: 0.0,
         }
     }
+
+    /// Mark the module as breached, cutting its max health until repaired
+    pub fn breach(&mut self) {
+        if self.hull_breached { return; }
+        self.hull_breached = true;
+        self.max_health *= 1.0 - HULL_BREACH_HEALTH_PENALTY;
+        self.health = self.health.min(self.max_health);
+    }
+
+    /// Whether an overcharge is boosting this module at time `now`
+    pub fn is_overcharged(&self, now: f32) -> bool {
+        now < self.overcharged_until
+    }
+
+    /// Scrap cost to upgrade to the next level
+    pub fn upgrade_cost(&self, base_cost: i32) -> i32 {
+        (base_cost as f32 * (self.level as f32 * 0.5 + 1.0)) as i32
+    }
+
+    /// Restore max health lost to a breach
+    pub fn seal_breach(&mut self) {
+        if !self.hull_breached { return; }
+        self.hull_breached = false;
+        self.max_health /= 1.0 - HULL_BREACH_HEALTH_PENALTY;
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -97,6 +147,19 @@ impl Ship {
         }
     }
 
+    /// Add a destroyed module to each unused grid cell claimed by an interior room.
+    /// Cells that already hold a module or build slot are left untouched.
+    pub fn add_room_modules(&mut self, slots: impl IntoIterator<Item = ((usize, usize), ModuleType)>) {
+        for ((x, y), module_type) in slots {
+            if let Some(cell) = self.grid.get_mut(x).and_then(|col| col.get_mut(y)) {
+                if cell.is_none() {
+                    *cell = Some(Module::new(module_type));
+                }
+            }
+        }
+        self.invalidate_cache();
+    }
+
     /// Check if a grid coordinate is a valid slot (has a module or empty slot).
     pub fn is_valid_slot(&self, x: usize, y: usize) -> bool {
         if x >= self.grid.len() {
@@ -108,6 +171,11 @@ impl Ship {
         self.grid[x][y].is_some()
     }
 
+    /// Whether the module in this cell has been switched off or knocked out by a power surge.
+    pub fn is_offline(&self, (x, y): (usize, usize)) -> bool {
+        self.grid[x][y].as_ref().is_some_and(|m| m.state == ModuleState::Offline)
+    }
+
     /// Find the core position in the grid.
     pub fn find_core(&self) -> Option<(usize, usize)> {
         for (x, row) in self.grid.iter().enumerate() {
@@ -14,13 +14,31 @@ pub const MIN_SCRAP_PILES: usize = 8;
 pub const MAX_SCRAP_PILES: usize = 12;
 pub const SCRAP_PILE_MIN_AMOUNT: i32 = 15;
 pub const SCRAP_PILE_MAX_AMOUNT: i32 = 40;
+pub const COMBO_REPAIR_WINDOW: f32 = 5.0; // Max seconds between repairs to keep a combo going
+pub const COMBO_SCRAP_BONUS: i32 = 3;     // Bonus scrap per combo step
+pub const SCRAP_RESPAWN_INTERVAL: f32 = 60.0; // Seconds between scrap top-ups
+pub const SCRAP_RESPAWN_MIN_PILES: usize = 2;
+pub const SCRAP_RESPAWN_MAX_PILES: usize = 3;
 pub const SCRAP_SPAWN_PADDING: f32 = 20.0;
+pub const SCHEMATIC_PILE_CHANCE: f32 = 0.08; // Chance a new pile hides an undiscovered schematic
 
 // Combat
 pub const ENEMY_ATTACK_RANGE: f32 = 30.0;
+pub const RETREAT_HEALTH_THRESHOLD: f32 = 0.2; // Health fraction below which drones and guards flee
+pub const RETREAT_EXIT_MARGIN: f32 = 100.0;    // Distance past the screen edge where fleeing enemies escape
 pub const ENEMY_HIT_RADIUS_NANODRONE: f32 = 10.0;
 pub const ENEMY_HIT_RADIUS_NANOGUARD: f32 = 15.0;
 pub const ENEMY_HIT_RADIUS_BOSS: f32 = 40.0;
+pub const PROJECTILE_SUBSTEPS: u32 = 2;             // Collision checks per frame for fast shots
+pub const SUBSTEP_THRESHOLD_VELOCITY: f32 = 300.0;  // Speed above which shots are substepped
+pub const TURRET_TURN_SPEED: f32 = 10.0; // Fraction of the remaining turn closed per second
+
+// Utility module status effects
+pub const UTILITY_SLOW_CHANCE: f32 = 0.25;       // Chance per hit at full Utility repair
+pub const UTILITY_SLOW_FACTOR: f32 = 0.5;        // Speed multiplier while slowed
+pub const UTILITY_SLOW_DURATION: f32 = 3.0;
+pub const UTILITY_STUN_CHANCE_PER_LEVEL: f32 = 0.05; // From the stun_utility upgrade
+pub const UTILITY_STUN_DURATION: f32 = 1.5;
 
 // Enemy Stats (HP, Speed, Damage)
 pub const ENEMY_DRONE_HP: f32 = 10.0;
@@ -39,18 +57,84 @@ pub const ENEMY_LEECH_POWER_DRAIN: i32 = 1; // Power drained per tick when attac
 pub const ENEMY_SIEGE_HP: f32 = 200.0;
 pub const ENEMY_SIEGE_SPEED: f32 = 15.0;
 pub const ENEMY_SIEGE_DAMAGE: f32 = 30.0;
+pub const HULL_BREACH_HEALTH_PENALTY: f32 = 0.1; // Max health lost by a breached module until repaired
+
+pub const ENEMY_CARRIER_HP: f32 = 300.0;
+pub const ENEMY_CARRIER_SPEED: f32 = 10.0;
+pub const ENEMY_CARRIER_DAMAGE: f32 = 5.0;
+pub const CARRIER_DROP_INTERVAL: f32 = 8.0; // Seconds between drone drops
+pub const CARRIER_DROP_SIZE: usize = 2;     // Drones released per drop
+pub const CARRIER_DROP_COUNT: u32 = 5;      // Drops before the Carrier is empty
+pub const ELITE_SPAWN_CHANCE: f32 = 0.1;    // Share of drones and guards that spawn elite
+pub const ELITE_HP_MULTIPLIER: f32 = 2.0;
+pub const ELITE_SPEED_MULTIPLIER: f32 = 1.5;
+pub const ELITE_SCRAP_MULTIPLIER: i32 = 2;
 
 pub const ENEMY_BOSS_HP: f32 = 1000.0;
 pub const ENEMY_BOSS_SPEED: f32 = 20.0;
 pub const ENEMY_BOSS_DAMAGE: f32 = 50.0;
 pub const BOSS_ABILITY_COOLDOWN: f32 = 8.0; // Seconds between boss abilities
-pub const BOSS_SPLIT_COUNT: usize = 3; // Number of drones spawned on boss death
+pub const BOSS_WARNING_DURATION: f32 = 5.0; // Seconds of warning between engine charge and boss spawn
+pub const SIEGE_ACTIVE_CELL_COST: u32 = 20; // Path cost of crossing an Active module vs an open cell
+pub const PATH_RECOMPUTE_DISTANCE: f32 = 100.0; // Travel from the route start before it is recomputed
+pub const PATH_RECOMPUTE_FRAME_BUDGET: usize = 3; // Max Siege Construct routes recomputed per frame
+pub const SIEGE_WAYPOINT_RADIUS: f32 = 5.0;  // Distance at which a waypoint counts as reached
+pub const BOSS_SPLIT_COUNT: usize = 3; // Number of drones spawned when a Siege Construct dies
+pub const SIEGE_SPLIT_SCATTER: f32 = 25.0; // Max offset of split drones from the wreck
+pub const SIEGE_EXPLOSION_PARTICLES: usize = 40;
+pub const SIEGE_ARMOR_MULTIPLIER: f32 = 0.5; // Projectile damage taken while the armor holds
+pub const SIEGE_ARMOR_THRESHOLD: f32 = 0.5;  // Health fraction below which the armor cracks
+
+// Spawner (one per round from WAVE_T3_POWER, builds Siege Constructs until destroyed)
+pub const SPAWNER_HP: f32 = 1000.0;
+pub const SPAWNER_INTERVAL: f32 = 15.0;       // Seconds between Siege Constructs
+pub const SPAWNER_SIZE: f32 = 40.0;           // Side of the square, also its hit box
+pub const SPAWNER_EDGE_INSET: f32 = 60.0;     // Distance from the screen border so it stays visible
+pub const SPAWNER_DESTROY_CREDITS: i32 = 150;
+
+// Formations (from WAVE_T2_POWER onwards, some drone spawns become formations)
+pub const FORMATION_SPAWN_CHANCE: f32 = 0.3;
+pub const FORMATION_SPACING: f32 = 40.0;   // Distance between drones in a V
+pub const FORMATION_LINE_COUNT: usize = 5;
+pub const FORMATION_RING_COUNT: usize = 6;
+
+// Enemy animation (sprite sheets: enemy_<name>_f0.png ... _f3.png)
+pub const ENEMY_ANIMATION_FRAMES: usize = 4;
+pub const ENEMY_ANIMATION_FRAME_DURATION: f32 = 0.12; // Seconds per frame
+
+// Enemy exhaust trails
+pub const ENEMY_TRAIL_INTERVAL: f32 = 0.025;    // Seconds between particles (~40/s)
+pub const ENEMY_TRAIL_LIFETIME: f32 = 0.25;
+pub const ENEMY_TRAIL_LIFETIME_BOSS: f32 = 0.4;
+pub const ENEMY_TRAIL_SPEED: f32 = 30.0;        // Drift speed opposite the direction of travel
+
+// Beam weapons
+pub const BEAM_UPGRADE_LEVEL: u8 = 3;          // Weapon module level that switches to beams
+pub const BEAM_WIDTH: f32 = 8.0;               // Max distance from the beam line that still hits
+pub const BEAM_ACTIVE_TIME: f32 = 0.15;        // Seconds a beam stays visible
+pub const BEAM_DAMAGE_MULTIPLIER: f32 = 0.6;   // Beams hit multiple targets, so each hit is weaker
+
+// Drone bays
+pub const DRONE_ORBIT_RADIUS: f32 = 180.0;     // Distance from the core drones circle at
+pub const DRONE_ORBIT_SPEED: f32 = 1.0;        // Radians per second
+pub const DRONE_PROJECTILE_SPEED: f32 = 350.0;
+
+// Projectile trails
+pub const PROJECTILE_TRAIL_INTERVAL: f32 = 0.05;
+pub const PROJECTILE_TRAIL_LIFETIME: f32 = 0.15;
+pub const PROJECTILE_TRAIL_HOT_DAMAGE: f32 = 15.0; // Damage at which the trail is fully orange
+pub const PARTICLE_GRAVITY: f32 = 50.0; // Downward pull on explosion debris (px/s²)
 
 // Wave Logic
+pub const WAVE_DURATION: f32 = 60.0;          // Seconds of survival per wave number
+pub const WAVE_HP_BONUS_PER_WAVE: f32 = 0.1;  // Extra drone/guard HP fraction per wave
+pub const WAVE_TOAST_DURATION: f32 = 2.0;
 pub const WAVE_GRACE_POWER: i32 = 4;   // Enemies don't spawn until player has more power
 pub const WAVE_T1_POWER: i32 = 16;      // First tier of enemy scaling
 pub const WAVE_T2_POWER: i32 = 24;      // Second tier - guards start appearing
 pub const WAVE_T3_POWER: i32 = 40;      // Final tier - full assault
+pub const WAVE_SUMMARY_DURATION: f32 = 3.0; // Seconds the tier change banner stays up
+pub const WAVE_SUMMARY_FADE: f32 = 0.5;     // Seconds of fade at each end of the banner
 
 pub const SPAWN_INTERVAL_DRONE_T0: f32 = 15.0; // Much slower initial spawns
 pub const SPAWN_INTERVAL_DRONE_T1: f32 = 8.0;  // Still manageable
@@ -66,43 +150,166 @@ pub const POWER_COST_WEAPON: i32 = 1;
 pub const POWER_COST_DEFENSE: i32 = 1;
 pub const POWER_COST_UTILITY: i32 = 1;
 pub const POWER_COST_ENGINE: i32 = 1;     // Was 2, now matches other modules
+pub const POWER_COST_DRONE_BAY: i32 = 1;
 pub const POWER_COST_COCKPIT: i32 = 1;
 pub const POWER_COST_MEDBAY: i32 = 1;
+pub const COCKPIT_PATH_PREVIEW_SECONDS: f32 = 2.0; // Movement shown ahead of each enemy with a repaired Cockpit
 
 // Economy
 pub const BASE_ESCAPE_CREDITS: i32 = 500;
+pub const DEFENSE_BONUS_FRACTION: f32 = 0.5; // Share of base credits paid for an untouched hull
+pub const SELL_CONFIRM_WINDOW: f32 = 2.0;      // Seconds to confirm a module sale with a second right-click
+pub const IN_RUN_SHOP_OFFERS: usize = 3;       // Upgrades on sale in the Cockpit shop
+pub const IN_RUN_SHOP_SCRAP_RATE: f32 = 0.5;   // Scrap price as a fraction of the credit price
+pub const IN_RUN_SHOP_REFRESH_INTERVAL: f32 = 120.0; // Seconds survived between restocks
+pub const IN_RUN_SHOP_REFRESHES: u32 = 3;      // Restocks per round
+pub const UPGRADE_BASE_UNLOCK_COUNT: usize = 3; // Ship improvements available without a Laboratory
 pub const CREDIT_BONUS_PER_LEVEL: f32 = 0.25;
 pub const SCRAP_EFFICIENCY_BONUS: f32 = 0.20;
 
 // Interaction
 pub const INTERACTION_RANGE: f32 = 40.0;
 pub const GATHERING_TIME_SECONDS: f32 = 2.0;
+pub const PARTIAL_REPAIR_RATE: f32 = 0.5; // Repair point progress per second of holding E
 
 // Nano-robots
 pub const NANO_REPAIR_RATE_PER_LEVEL: f32 = 2.0;
 pub const NANO_REPAIR_INTERVAL_SECONDS: f32 = 2.0;
+pub const DRONE_REPAIR_MIN_LEVEL: u32 = 2;          // auto_repairs level that unlocks the repair drone
+pub const DRONE_REPAIR_THRESHOLD: f32 = 30.0;       // Progress needed to restore a destroyed module
+pub const DRONE_REPAIR_RATE_PER_LEVEL: f32 = 1.0;   // Progress per second per auto_repairs level
+pub const DRONE_SPEED: f32 = 120.0;
 
 // Engine system
 pub const ENGINE_CHARGE_BASE_TIME: f32 = 60.0;
+pub const ESCAPE_RING_THICKNESS: f32 = 6.0;    // Width of the escape progress ring around the screen edge
+pub const ESCAPE_RING_FLASH_PROGRESS: f32 = 0.9; // Progress past which the ring flashes
 pub const ENGINE_MIN_REPAIR_PERCENT: f32 = 1.0;
 
 // Screen shake
 pub const TRAUMA_DECAY_RATE: f32 = 1.5; // Adjusted to match current renderer.rs
 pub const SHAKE_INTENSITY: f32 = 15.0; // Adjusted to match current renderer.rs
+pub const SHAKE_FREQ_HIGH: f32 = 10.0;   // Fast jitter (small hits)
+pub const SHAKE_FREQ_MEDIUM: f32 = 5.0;
+pub const SHAKE_FREQ_LOW: f32 = 2.0;     // Slow roll (big rumbles)
+pub const SHAKE_JITTER_FREQUENCY: f32 = 8.0; // At or above this, offsets are random each frame
 pub const MODULE_DAMAGE_TRAUMA: f32 = 0.02;
 pub const MODULE_DESTROY_TRAUMA: f32 = 0.4;
 pub const CORE_DESTROY_TRAUMA: f32 = 1.0;
 pub const ENGINE_ACTIVATE_TRAUMA: f32 = 0.3;
 pub const ENEMY_KILL_TRAUMA: f32 = 0.1;
+pub const BOSS_KILL_TRAUMA: f32 = 0.6;
+pub const HULL_BREACH_TRAUMA: f32 = 0.6;
+pub const SIEGE_EXPLODE_TRAUMA: f32 = 0.7;
+pub const SIEGE_ARMOR_BREAK_TRAUMA: f32 = 0.3;
+pub const SPAWNER_DESTROY_TRAUMA: f32 = 0.8;
+
+// Fire hazard
+pub const FIRE_IGNITE_CHANCE: f32 = 0.5;        // Chance a room catches fire when its module is destroyed
+pub const FIRE_START_LEVEL: f32 = 0.2;
+pub const FIRE_GROWTH_RATE: f32 = 0.02;         // Per second while the room is damaged
+pub const FIRE_DECAY_RATE: f32 = 0.1;           // Per second once the room is fully repaired
+pub const FIRE_SPREAD_RATE: f32 = 0.05;         // Per second, scaled by each burning neighbour's level
+pub const FIRE_HULL_DAMAGE_PER_SEC: f32 = 5.0;  // At fire_level 1.0
+
+// Structural damage
+pub const ROOM_DAMAGE_PER_HULL_FRACTION: f32 = 4.0; // Room damage per fraction of max hull taken at its module (25% collapses it)
+pub const ROOM_COLLAPSE_REPAIR_COST: i32 = 50;      // Scrap to clear a collapsed room
+pub const ROOM_DAMAGE_MAX_DARKEN: f32 = 0.7;        // Overlay alpha at room_damage 1.0
+
+// Environmental events
+pub const ENV_EVENT_INTERVAL: f32 = 45.0;       // Seconds between random room events
+pub const ENV_SURGE_DURATION: f32 = 8.0;        // Seconds a surged module stays offline
+pub const ENV_BREACH_DURATION: f32 = 10.0;
+pub const ENV_BREACH_DAMAGE_PER_SEC: f32 = 8.0; // Hull integrity lost while a breach is open
+pub const ENV_BREACH_SEAL_TIME: f32 = 2.0;      // Seconds holding interact to seal a breach
+pub const ENV_EVENT_TRAUMA: f32 = 0.3;
+pub const FIRE_PLAYER_DAMAGE_PER_SEC: f32 = 4.0;
+
+// Interior invaders (boarders dropped by Siege Constructs)
+pub const INVADER_HP: f32 = 30.0;
+pub const INVADER_SPEED: f32 = 70.0;          // Slower than the player so they can be outrun
+pub const INVADER_DAMAGE_PER_SEC: f32 = 12.0; // Player health drained while in contact
+pub const INVADER_CONTACT_RADIUS: f32 = 18.0;
+pub const INVADER_SIZE: f32 = 10.0;
+pub const INVADER_TURRET_DPS: f32 = 25.0;     // Inward turret damage while the player mans a weapon point
+pub const INVADER_TURRET_RANGE: f32 = 250.0;
+
+// Player
+pub const PLAYER_MAX_HEALTH: f32 = 100.0;
+pub const MEDBAY_HEAL_RATE: f32 = 10.0; // Health per second in a fully repaired Medbay
+pub const PLAYER_MAX_STAMINA: f32 = 100.0;
+pub const SPRINT_SPEED_MULTIPLIER: f32 = 1.8;   // Hold Left Shift in the interior
+pub const STAMINA_DRAIN_RATE: f32 = 25.0;       // Per second while sprinting
+pub const STAMINA_RECOVER_RATE: f32 = 15.0;     // Per second while not sprinting
+pub const STAMINA_RECOVER_THRESHOLD: f32 = 30.0; // Stamina needed to sprint again after running dry
 
 // Ship
 pub const SHIP_BASE_INTEGRITY: f32 = 1000.0;
 pub const HULL_UPGRADE_BONUS: f32 = 200.0;  // HP added per hull upgrade level
+pub const PERMANENT_SCRAP_BONUS: i32 = 25;  // Starting scrap per "starting_scrap" profile level
+pub const REPAIR_DISCOUNT_PER_LEVEL: f32 = 0.1; // Repair scrap cost cut per "repair_discount" profile level
+pub const HULL_CRACK_THRESHOLDS: [f32; 4] = [0.75, 0.5, 0.25, 0.1]; // Integrity fractions that add a set of cracks
+pub const HULL_CRACKS_PER_STAGE: usize = 5;
+pub const HULL_CRACK_SEGMENTS: usize = 3;      // Line segments per jagged crack
+pub const HULL_ALARM_THRESHOLD: f32 = 0.25;    // Integrity fraction where the hull outline flashes red
+pub const HULL_SMOKE_THRESHOLD: f32 = 0.1;     // Integrity fraction where the hull corners smoke
+pub const HULL_SMOKE_INTERVAL: f32 = 0.15;     // Seconds between smoke puffs per corner
+pub const VICTORY_ANIMATION_DURATION: f32 = 3.0; // Seconds the ship takes to fly off after an escape
+pub const VICTORY_THRUST_INTERVAL: f32 = 0.05;   // Seconds between thruster bursts per engine
 
 // Module upgrades
 pub const MODULE_MAX_LEVEL: u8 = 5;
 pub const MODULE_UPGRADE_HP_MULTIPLIER: f32 = 1.5;  // HP multiplier per upgrade level
 
+// Module overcharge
+pub const OVERCHARGE_DURATION: f32 = 10.0;          // Seconds a module stays boosted
+pub const OVERCHARGE_DAMAGE: f32 = 30.0;            // Module health burned when the boost ends
+pub const OVERCHARGE_FIRE_RATE_MULTIPLIER: f32 = 2.0;
+pub const OVERCHARGE_SHIELD_MULTIPLIER: f32 = 2.0;
+pub const OVERCHARGE_ENGINE_MULTIPLIER: f32 = 1.5;
+
+// Veteran scaling (each completed escape makes later runs a little harder)
+pub const DIFFICULTY_SPEED_SCALE_PER_RUN: f32 = 0.05; // Spawn interval reduction per escape
+pub const DIFFICULTY_SPEED_SCALE_MIN: f32 = 0.5;      // Spawns at most twice as often
+pub const DIFFICULTY_HP_SCALE_PER_RUN: f32 = 0.03;    // Enemy HP increase per escape
+pub const DIFFICULTY_HP_SCALE_MAX: f32 = 2.5;
+
+// Flavor popups
+pub const FLAVOR_POPUP_DURATION: f32 = 1.0; // Seconds each quip stays up
+pub const FLAVOR_POPUP_MAX: usize = 5;      // Oldest quip is replaced past this
+pub const FLAVOR_POPUP_DRIFT: f32 = 30.0;   // Pixels a quip rises over its lifetime
+
+// Event bus
+pub const MAX_GAME_EVENTS: usize = 128; // Game events queued per frame before new ones are dropped
+
+// Save slots
+pub const MAX_SAVE_SLOTS: usize = 5;
+pub const CURRENT_SAVE_VERSION: u32 = 2; // Bump with a migration in persistence.rs when SaveData changes shape
+pub const AUTO_SAVE_SLOT: usize = 9;  // Written on GameOver/Victory/InterRound, hidden from slot select
+pub const IRONMAN_CREDIT_MULTIPLIER: f32 = 1.5; // Escape credits bonus for runs without saving
+
+// Audio
+pub const SPATIAL_FALLOFF: f32 = 0.5;          // Volume lost at the screen corners
+pub const SPATIAL_PAN_ATTENUATION: f32 = 0.25; // Extra volume lost at the far left/right edge
+pub const TURRET_SFX_MIN_INTERVAL: f32 = 0.1;  // Seconds between audible turret shots
+
+// UI
+pub const TOAST_DURATION: f32 = 2.5;  // Seconds a status message stays on screen
+pub const AUTO_SAVE_TOAST_DURATION: f32 = 2.0;
+pub const ACHIEVEMENT_TOAST_DURATION: f32 = 3.0;
+pub const SHIP_LOG_CAPACITY: usize = 50;   // Oldest ship log entries are dropped past this
+pub const SHIP_LOG_ROWS: usize = 20;       // Entries shown on the ship log screen
+pub const SHIP_LOG_RECENT_WINDOW: f32 = 60.0; // Entries older than this many seconds are dimmed
+pub const WAVE_WARNING_FRACTION: f32 = 0.2; // Spawn bars turn red below this fraction remaining
+pub const HIGH_SCORE_ROWS: usize = 5; // Escapes listed on the high score screen
+pub const INTERIOR_MAP_SCALE: f32 = 0.25; // Interior map overlay size relative to the ship
+pub const CAMERA_MARGIN: f32 = 20.0;      // Gap the interior camera leaves past the ship's outer walls
+pub const CAMERA_LERP_SPEED: f32 = 8.0;   // How quickly the interior camera catches up to the player
+pub const ZOOM_MIN: f32 = 0.5;  // Exterior view zoom bounds
+pub const ZOOM_MAX: f32 = 2.0;
+pub const ZOOM_STEP: f32 = 0.1; // Zoom change per mouse wheel notch
+
 // Repair costs
 pub const REPAIR_SCRAP_COST: i32 = 10;  // Scrap cost per interior repair point
 
@@ -112,6 +319,7 @@ pub const STRESS_DECAY_IDLE: f32 = 2.0;       // Per second
 pub const STRESS_THRESHOLD_STRAINED: f32 = 16.0;
 pub const STRESS_THRESHOLD_UNSTABLE: f32 = 31.0;
 pub const STRESS_THRESHOLD_CRITICAL: f32 = 46.0;
+pub const HUD_THRESHOLD_BAR_MAX: f32 = 50.0;  // Value that fills the HUD alert and stress bars
 pub const CASCADE_DAMAGE_PER_SEC: f32 = 50.0; // Rapid internal damage during cascade
 pub const CASCADE_BOSS_SPAWN_STRESS: f32 = 46.0; // Redundant with threshold but semantic
 pub const NANITE_ALERT_BASE: f32 = 16.0;      // Base divisor for charging stress
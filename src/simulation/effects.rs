@@ -0,0 +1,46 @@
+// effects.rs - Short-lived visual feedback that lives in game state
+
+use macroquad::prelude::*;
+use crate::simulation::pool::ParticlePool;
+use crate::enemy::entities::ParticleKind;
+
+pub const DAMAGE_NUMBER_LIFETIME: f32 = 1.2;
+
+/// Floating number shown where an enemy took a hit
+#[derive(Debug, Clone)]
+pub struct DamageNumber {
+    pub position: Vec2,
+    pub amount: f32,
+    pub timer: f32,
+    pub max_timer: f32,
+    pub lethal: bool, // Hit brought the enemy's health to zero
+}
+
+impl DamageNumber {
+    pub fn new(position: Vec2, amount: f32, lethal: bool) -> Self {
+        Self {
+            position,
+            amount,
+            timer: DAMAGE_NUMBER_LIFETIME,
+            max_timer: DAMAGE_NUMBER_LIFETIME,
+            lethal,
+        }
+    }
+}
+
+/// Tick down damage numbers and drop expired ones
+pub fn update_damage_numbers(numbers: &mut Vec<DamageNumber>, dt: f32) {
+    for number in numbers.iter_mut() {
+        number.timer -= dt;
+    }
+    numbers.retain(|n| n.timer > 0.0);
+}
+
+/// Burst of particles flying out in every direction from `pos`
+pub fn spawn_explosion_particles(particles: &mut ParticlePool, pos: Vec2, count: u32, color: Color, speed: f32) {
+    for _ in 0..count {
+        let dir = vec2(rand::gen_range(-1.0, 1.0), rand::gen_range(-1.0, 1.0)).normalize_or_zero();
+        let vel = dir * speed * rand::gen_range(0.5, 1.0);
+        particles.spawn(pos, vel, rand::gen_range(0.3, 0.7), color, ParticleKind::Explosion);
+    }
+}
@@ -0,0 +1,48 @@
+// environment.rs - Random mid-game ship events (power surges, hull breaches)
+
+/// Kind of environmental event affecting a room
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EnvEventKind {
+    /// The room's module is knocked offline until the timer runs out
+    PowerSurge,
+    /// The room leaks hull integrity until the timer runs out or the player seals it
+    HullBreach,
+}
+
+impl EnvEventKind {
+    pub fn duration(&self) -> f32 {
+        match self {
+            EnvEventKind::PowerSurge => crate::simulation::constants::ENV_SURGE_DURATION,
+            EnvEventKind::HullBreach => crate::simulation::constants::ENV_BREACH_DURATION,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            EnvEventKind::PowerSurge => "Power surge",
+            EnvEventKind::HullBreach => "Hull breach",
+        }
+    }
+}
+
+/// An active event in one interior room
+#[derive(Debug, Clone)]
+pub struct EnvironmentEvent {
+    pub kind: EnvEventKind,
+    pub room_id: usize,
+    pub timer: f32,          // Seconds remaining
+    pub seal_progress: f32,  // Seconds the player has spent sealing a breach
+    pub sealing: bool,       // Player is holding interact in the room this frame
+}
+
+impl EnvironmentEvent {
+    pub fn new(kind: EnvEventKind, room_id: usize) -> Self {
+        Self {
+            kind,
+            room_id,
+            timer: kind.duration(),
+            seal_progress: 0.0,
+            sealing: false,
+        }
+    }
+}
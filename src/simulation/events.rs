@@ -1,5 +1,11 @@
 // events.rs - Event Bus and UI Events for decoupled game logic
 
+use crate::enemy::ai::FormationType;
+use crate::enemy::entities::{EnemyType, StatusKind};
+use crate::simulation::constants::MAX_GAME_EVENTS;
+use crate::simulation::environment::EnvEventKind;
+use macroquad::math::Vec2;
+
 /// UI Events generated by input handling
 #[derive(Debug, Clone)]
 
@@ -10,6 +16,10 @@ pub enum UIEvent {
     Upgrade(usize, usize),
     /// Toggle a module online/offline at the given grid coordinate
     Toggle(usize, usize),
+    /// Scrap a module for a partial refund at the given grid coordinate
+    SellModule(usize, usize),
+    /// Boost a module for a while at the cost of its health
+    Overcharge(usize, usize),
     /// Pause the game
     Pause,
     /// Resume the game
@@ -18,14 +28,20 @@ pub enum UIEvent {
     ReturnToMenu,
     /// Start a new game
     StartGame,
+    /// Start today's daily challenge run
+    StartDailyChallenge,
     /// Purchase an upgrade by ID
     PurchaseUpgrade(String),
+    /// Purchase a permanent (cross-run) upgrade by ID
+    PurchasePermanentUpgrade(String),
     /// Proceed to next round
     NextRound,
     /// Save game to slot
     SaveGame(usize),
     /// Load game from slot
     LoadGame(usize),
+    /// Open the codex from the pause menu
+    OpenCodex,
     /// Exit the game
     ExitGame,
 }
@@ -40,20 +56,58 @@ pub enum GameEvent {
     ModuleUpgraded { x: usize, y: usize, new_level: u8 },
     /// Module was destroyed
     ModuleDestroyed { x: usize, y: usize },
+    /// Module was sold back for scrap
+    ModuleSold { x: usize, y: usize, refund: i32 },
+    /// Module was overcharged
+    ModuleOvercharged { x: usize, y: usize },
     /// Enemy was killed
-    EnemyKilled { x: f32, y: f32, scrap_dropped: i32 },
+    EnemyKilled { x: f32, y: f32, scrap_dropped: i32, enemy_type: EnemyType },
+    /// A badly damaged enemy fled off screen without dropping scrap
+    EnemyRetreated { scrap_dropped: i32 },
     /// Enemy reached and damaged a module
     ModuleDamaged { x: usize, y: usize, damage: f32 },
     /// Core took damage
     CoreDamaged { damage: f32, remaining_hp: f32 },
     /// Engine activated - escape sequence started
     EngineActivated,
+    /// Boss arrives soon; sent once per second of the warning countdown
+    BossWarning,
     /// Escape successful
     EscapeSuccess,
+    /// The ship finished flying off screen (or the player skipped it)
+    VictoryAnimationComplete,
     /// Game over - core destroyed
     CoreDestroyed,
     /// Weapon fired a projectile
     WeaponFired { x: f32, y: f32 },
+    /// Siege Construct was destroyed and split into drones
+    SiegeConstructExploded { x: f32, y: f32 },
+    /// A Siege Construct dropped below half health and lost its damage reduction
+    SiegeArmorBroken { id: u64 },
+    /// A Siege Construct spawner was shot down
+    SpawnerDestroyed { x: f32, y: f32 },
+    /// A Carrier released a batch of Nanodrones
+    CarrierDeployed { x: f32, y: f32 },
+    /// Siege Construct breached the hull at a module
+    HullBreached { x: usize, y: usize },
+    /// A group of drones spawned in formation
+    FormationSpawned { formation_type: FormationType },
+    /// A Utility-module hit slowed or stunned an enemy
+    EnemyStatusApplied { id: u64, kind: StatusKind },
+    /// A power surge or hull breach started in an interior room
+    EnvironmentEventStarted { kind: EnvEventKind, room_id: usize },
+    /// A Siege Construct breach let a boarder into the ship interior
+    InteriorThreat { room_id: usize },
+    /// Structural damage brought down a room; it blocks the player until cleared
+    RoomCollapsed { room_id: usize },
+    /// The player finished gathering a scrap pile
+    ScrapGathered { amount: i32 },
+    /// Fresh scrap piles appeared in picked-clean rooms
+    ScrapRespawned { count: usize },
+    /// The window was resized; the centered ship grid moved with it
+    LayoutChanged { old_size: Vec2, new_size: Vec2 },
+    /// Power rose into a higher enemy scaling tier
+    WaveTierChanged { new_tier: u8 },
 }
 
 /// Event bus for decoupling UI input from game logic updates
@@ -61,6 +115,17 @@ pub enum GameEvent {
 pub struct EventBus {
     ui_events: Vec<UIEvent>,
     game_events: Vec<GameEvent>,
+    dropped_events: u64,     // Game events discarded over the whole session
+    frame: EventBusStats,    // Counts since the last end_frame
+}
+
+/// Event counts for one frame, shown in the FPS overlay
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EventBusStats {
+    pub pushed: usize,
+    pub processed: usize,
+    pub dropped: usize,
+    pub total_dropped: u64,
 }
 
 
@@ -69,29 +134,61 @@ impl EventBus {
         Self {
             ui_events: Vec::new(),
             game_events: Vec::new(),
+            dropped_events: 0,
+            frame: EventBusStats::default(),
         }
     }
 
     /// Push a UI event to be processed
     pub fn push_ui(&mut self, event: UIEvent) {
+        self.frame.pushed += 1;
         self.ui_events.push(event);
     }
 
-    /// Push a game event (for audio/visual feedback)
+    /// Push a game event (for audio/visual feedback). Once MAX_GAME_EVENTS are
+    /// queued, further events are dropped rather than growing the queue.
     pub fn push_game(&mut self, event: GameEvent) {
+        self.frame.pushed += 1;
+        if self.game_events.len() >= MAX_GAME_EVENTS {
+            if self.frame.dropped == 0 {
+                eprintln!("Warning: Event queue full ({} events), dropping {:?}", MAX_GAME_EVENTS, event);
+            }
+            self.frame.dropped += 1;
+            self.dropped_events += 1;
+            return;
+        }
         self.game_events.push(event);
     }
 
     /// Drain all UI events for processing
     pub fn drain_ui(&mut self) -> Vec<UIEvent> {
+        self.frame.processed += self.ui_events.len();
         std::mem::take(&mut self.ui_events)
     }
 
     /// Drain all game events for processing
     pub fn drain_game(&mut self) -> Vec<GameEvent> {
+        self.frame.processed += self.game_events.len();
         std::mem::take(&mut self.game_events)
     }
 
+    /// Drain at most `max` game events, oldest first; the rest stay queued
+    pub fn drain_game_with_limit(&mut self, max: usize) -> Vec<GameEvent> {
+        let count = max.min(self.game_events.len());
+        self.frame.processed += count;
+        self.game_events.drain(..count).collect()
+    }
+
+    /// Counts for the frame so far
+    pub fn stats(&self) -> EventBusStats {
+        EventBusStats { total_dropped: self.dropped_events, ..self.frame }
+    }
+
+    /// Reset the per-frame counts; call once at the end of each frame
+    pub fn end_frame(&mut self) {
+        self.frame = EventBusStats::default();
+    }
+
     /// Check if there are pending UI events
     pub fn has_ui_events(&self) -> bool {
         !self.ui_events.is_empty()
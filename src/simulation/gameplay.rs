@@ -83,6 +83,7 @@ impl ModuleRegistry {
                  "defense" => Some(ModuleType::Defense),
                  "utility" => Some(ModuleType::Utility),
                  "engine" => Some(ModuleType::Engine),
+                 "drone_bay" => Some(ModuleType::DroneBay),
                  "empty" => Some(ModuleType::Empty),
                  _ => None,
              }
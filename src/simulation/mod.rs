@@ -1,3 +1,8 @@
 pub mod gameplay;
 pub mod constants;
 pub mod events;
+pub mod pool;
+pub mod effects;
+pub mod synergy;
+pub mod environment;
+pub mod rng;
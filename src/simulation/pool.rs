@@ -0,0 +1,74 @@
+// pool.rs - Fixed-size particle storage that recycles inactive slots
+
+use macroquad::prelude::*;
+use crate::enemy::entities::{Particle, ParticleKind};
+use crate::simulation::constants::PARTICLE_GRAVITY;
+
+/// Maximum number of particles alive at once
+pub const PARTICLE_POOL_SIZE: usize = 512;
+
+/// Fixed-capacity particle pool; spawning never allocates
+#[derive(Debug, Clone)]
+pub struct ParticlePool {
+    pub slots: [Particle; PARTICLE_POOL_SIZE],
+    pub next_free: usize,
+}
+
+impl ParticlePool {
+    pub fn new() -> Self {
+        Self {
+            slots: [Particle::inactive(); PARTICLE_POOL_SIZE],
+            next_free: 0,
+        }
+    }
+
+    /// Activate the first free slot. Returns false if the pool is full.
+    pub fn spawn(&mut self, pos: Vec2, vel: Vec2, lifetime: f32, color: Color, kind: ParticleKind) -> bool {
+        self.insert(Particle::new(pos, vel, lifetime, color, kind))
+    }
+
+    /// Place an already-built particle into the first free slot (used when restoring saves)
+    pub fn insert(&mut self, particle: Particle) -> bool {
+        // Start searching from the last known free slot, wrapping around once
+        for offset in 0..PARTICLE_POOL_SIZE {
+            let idx = (self.next_free + offset) % PARTICLE_POOL_SIZE;
+            if !self.slots[idx].active {
+                self.slots[idx] = particle;
+                self.next_free = (idx + 1) % PARTICLE_POOL_SIZE;
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Advance live particles and retire expired ones
+    pub fn update(&mut self, dt: f32) {
+        for (i, particle) in self.slots.iter_mut().enumerate() {
+            if !particle.active { continue; }
+            if particle.kind == ParticleKind::Explosion {
+                particle.velocity.y += PARTICLE_GRAVITY * dt;
+            }
+            particle.position += particle.velocity * dt;
+            particle.lifetime -= dt;
+            if particle.lifetime <= 0.0 {
+                particle.active = false;
+                if i < self.next_free {
+                    self.next_free = i;
+                }
+            }
+        }
+    }
+
+    /// Iterate over live particles only
+    pub fn iter_active(&self) -> impl Iterator<Item = &Particle> {
+        self.slots.iter().filter(|p| p.active)
+    }
+
+    /// Deactivate every slot
+    pub fn clear(&mut self) {
+        for particle in self.slots.iter_mut() {
+            particle.active = false;
+        }
+        self.next_free = 0;
+    }
+}
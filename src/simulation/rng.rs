@@ -0,0 +1,144 @@
+// rng.rs - Seeded RNG for gameplay randomness, so a run seed reproduces a run
+//
+// Scrap piles, room events and per-hit rolls draw from the gameplay stream. Enemy spawn
+// sides, positions and formations draw from a separate spawn stream, so hits and fires
+// landing on different frames can't shift where later waves appear. Purely visual
+// randomness (particles, screen shake) keeps using macroquad's RNG: how many particles
+// get spawned depends on frame timing, which would otherwise desync seeded runs.
+
+use std::cell::RefCell;
+use std::thread::LocalKey;
+
+/// Mixed into the run seed for the spawn stream so it doesn't mirror the gameplay stream
+const SPAWN_STREAM_SALT: u64 = 0x5350_4157_4E00_0001;
+
+/// SplitMix64: tiny, fast and gives the same stream on every platform, WASM included
+pub struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Value in `[0, 1)` from the top 53 bits
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Value in `[0, n)`; `n` must be above zero
+    fn below(&mut self, n: u64) -> u64 {
+        ((self.next_u64() as u128 * n as u128) >> 64) as u64
+    }
+
+    /// Fisher-Yates shuffle
+    fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.below(i as u64 + 1) as usize;
+            items.swap(i, j);
+        }
+    }
+}
+
+/// Types `gen_range` can draw, mirroring macroquad's `RandomRange`
+pub trait RandomRange: Copy + PartialOrd {
+    fn sample(rng: &mut SplitMix64, low: Self, high: Self) -> Self;
+}
+
+macro_rules! impl_random_range_int {
+    ($($t:ty),*) => {$(
+        impl RandomRange for $t {
+            fn sample(rng: &mut SplitMix64, low: Self, high: Self) -> Self {
+                let span = (high as i128 - low as i128) as u64;
+                let offset = rng.below(span);
+                (low as i128 + offset as i128) as $t
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_random_range_float {
+    ($($t:ty),*) => {$(
+        impl RandomRange for $t {
+            fn sample(rng: &mut SplitMix64, low: Self, high: Self) -> Self {
+                let unit = rng.next_f64();
+                let value = low + (high - low) * unit as $t;
+                // Rounding can land exactly on `high`, which the range excludes
+                if value < high { value } else { low }
+            }
+        }
+    )*};
+}
+
+impl_random_range_int!(i32, i64, u32, u64, usize);
+impl_random_range_float!(f32, f64);
+
+thread_local! {
+    static RNG: RefCell<SplitMix64> = RefCell::new(SplitMix64(0));
+    static SPAWN_RNG: RefCell<SplitMix64> = RefCell::new(SplitMix64(SPAWN_STREAM_SALT));
+}
+
+/// Restart the gameplay and spawn streams from a seed
+pub fn seed(seed: u64) {
+    RNG.with(|rng| *rng.borrow_mut() = SplitMix64(seed));
+    SPAWN_RNG.with(|rng| *rng.borrow_mut() = SplitMix64(seed ^ SPAWN_STREAM_SALT));
+}
+
+/// A fresh seed from the clock (works on native and WASM)
+pub fn new_seed() -> u64 {
+    (macroquad::miniquad::date::now() * 1_000_000.0) as u64
+}
+
+/// `count` values in `[0, 1)` from a one-off generator, leaving the gameplay stream untouched.
+/// The same seed always gives the same values.
+pub fn unit_values_seeded(seed: u64, count: usize) -> Vec<f32> {
+    let mut rng = SplitMix64(seed);
+    (0..count).map(|_| rng.next_f64() as f32).collect()
+}
+
+/// Seed shared by everyone playing the daily challenge on a YYYY-MM-DD date
+pub fn daily_seed(date: &str) -> u64 {
+    let digits: String = date.chars().filter(|c| c.is_ascii_digit()).collect();
+    let yyyymmdd = digits.parse::<u64>().unwrap_or(0);
+    // Spread neighbouring dates apart: the first SplitMix64 output mixes every bit
+    SplitMix64(yyyymmdd).next_u64()
+}
+
+/// Value in `[low, high)`. An empty range returns `low`, like macroquad's `gen_range`.
+pub fn gen_range<T: RandomRange>(low: T, high: T) -> T {
+    range_from(&RNG, low, high)
+}
+
+/// `gen_range` on the spawn stream, for enemy spawn sides, positions and formations
+pub fn spawn_range<T: RandomRange>(low: T, high: T) -> T {
+    range_from(&SPAWN_RNG, low, high)
+}
+
+fn range_from<T: RandomRange>(stream: &'static LocalKey<RefCell<SplitMix64>>, low: T, high: T) -> T {
+    if low >= high {
+        return low;
+    }
+    stream.with(|rng| T::sample(&mut rng.borrow_mut(), low, high))
+}
+
+/// Random element of a slice, or None if it is empty
+pub fn choose<T>(items: &[T]) -> Option<&T> {
+    if items.is_empty() {
+        return None;
+    }
+    let i = RNG.with(|rng| rng.borrow_mut().below(items.len() as u64)) as usize;
+    items.get(i)
+}
+
+pub fn shuffle<T>(items: &mut [T]) {
+    RNG.with(|rng| rng.borrow_mut().shuffle(items));
+}
+
+/// Shuffle with a one-off generator, leaving the gameplay stream untouched.
+/// The same seed always gives the same order.
+pub fn shuffle_seeded<T>(seed: u64, items: &mut [T]) {
+    SplitMix64(seed).shuffle(items);
+}
@@ -0,0 +1,48 @@
+// synergy.rs - Adjacency bonuses between ship modules
+
+use std::collections::HashMap;
+
+use crate::ship::ship::{ModuleState, ModuleType, Ship};
+
+/// Bonus granted to each side of an adjacent Weapon + Defense pair
+pub const SYNERGY_BONUS: f32 = 0.15;
+
+/// Find every working Weapon/Defense module with a working partner in one of its
+/// four cardinal neighbors. Maps grid position to its bonus (0.15 = +15%).
+pub fn calculate_synergies(ship: &Ship) -> HashMap<(usize, usize), f32> {
+    let mut synergies = HashMap::new();
+    let width = ship.grid.len();
+
+    for x in 0..width {
+        let height = ship.grid[x].len();
+        for y in 0..height {
+            let Some(module) = &ship.grid[x][y] else { continue };
+            if module.state == ModuleState::Destroyed { continue; }
+
+            let partner = match module.module_type {
+                ModuleType::Weapon => ModuleType::Defense,
+                ModuleType::Defense => ModuleType::Weapon,
+                _ => continue,
+            };
+
+            let neighbors = [
+                (x.wrapping_sub(1), y),
+                (x + 1, y),
+                (x, y.wrapping_sub(1)),
+                (x, y + 1),
+            ];
+            let has_partner = neighbors.iter().any(|&(nx, ny)| {
+                ship.grid.get(nx)
+                    .and_then(|col| col.get(ny))
+                    .and_then(|cell| cell.as_ref())
+                    .is_some_and(|m| m.module_type == partner && m.state != ModuleState::Destroyed)
+            });
+
+            if has_partner {
+                synergies.insert((x, y), SYNERGY_BONUS);
+            }
+        }
+    }
+
+    synergies
+}
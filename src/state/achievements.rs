@@ -0,0 +1,74 @@
+//! Data-driven achievements (definitions loaded from JSON, progress kept in PlayerProfile)
+
+use serde::Deserialize;
+use crate::enemy::entities::EnemyType;
+use crate::simulation::constants::ACHIEVEMENT_TOAST_DURATION;
+use super::profile::PlayerProfile;
+
+/// What has to happen for an achievement to unlock
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "type")]
+pub enum AchievementCondition {
+    /// Successful escapes across all runs
+    Escapes { count: u32 },
+    /// Lifetime kills, of one enemy type or of any type if None
+    Kills { enemy_type: Option<EnemyType>, count: u32 },
+    /// Time survived in a single run
+    SurviveTime { seconds: f32 },
+}
+
+impl AchievementCondition {
+    pub fn is_met(&self, profile: &PlayerProfile, time_survived: f32) -> bool {
+        match self {
+            AchievementCondition::Escapes { count } => profile.runs_completed >= *count,
+            AchievementCondition::Kills { enemy_type, count } => profile.kills(enemy_type.as_ref()) >= *count,
+            AchievementCondition::SurviveTime { seconds } => time_survived >= *seconds,
+        }
+    }
+}
+
+/// A single achievement definition (loaded from JSON)
+#[derive(Debug, Clone, Deserialize)]
+pub struct AchievementDef {
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    pub condition: AchievementCondition,
+}
+
+/// Achievement definitions (loaded from JSON)
+#[derive(Debug, Clone, Deserialize)]
+pub struct AchievementConfig {
+    pub achievements: Vec<AchievementDef>,
+}
+
+impl AchievementConfig {
+    /// Load achievement definitions from embedded JSON
+    pub fn load() -> Self {
+        serde_json::from_str(include_str!("../../assets/achievements.json"))
+            .unwrap_or_else(|e| {
+                eprintln!("Warning: Failed to load achievements.json: {}. Using no achievements.", e);
+                Self { achievements: Vec::new() }
+            })
+    }
+
+    pub fn get(&self, id: &str) -> Option<&AchievementDef> {
+        self.achievements.iter().find(|a| a.id == id)
+    }
+}
+
+/// "Achievement unlocked" popup shown in the corner of the screen
+#[derive(Debug, Clone)]
+pub struct AchievementNotification {
+    pub name: String,
+    pub timer: f32,
+}
+
+impl AchievementNotification {
+    pub fn new(name: String) -> Self {
+        Self {
+            name,
+            timer: ACHIEVEMENT_TOAST_DURATION,
+        }
+    }
+}
@@ -0,0 +1,34 @@
+//! In-game encyclopedia of enemies and modules (lore loaded from JSON, stats from game data)
+
+use serde::Deserialize;
+use crate::enemy::entities::EnemyType;
+use crate::ship::ship::ModuleType;
+
+/// What a codex entry describes; decides which stats are shown next to the lore
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub enum CodexCategory {
+    Enemy(EnemyType),
+    Module(ModuleType),
+}
+
+/// A single codex page (loaded from JSON)
+#[derive(Debug, Clone, Deserialize)]
+pub struct CodexEntry {
+    pub title: String,
+    pub body: String,
+    pub category: CodexCategory,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct CodexFile {
+    entries: Vec<CodexEntry>,
+}
+
+pub fn load_codex() -> Vec<CodexEntry> {
+    serde_json::from_str::<CodexFile>(include_str!("../../assets/codex.json"))
+        .map(|file| file.entries)
+        .unwrap_or_else(|e| {
+            eprintln!("Warning: Failed to load codex.json: {}. Codex will be empty.", e);
+            Vec::new()
+        })
+}
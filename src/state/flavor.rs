@@ -0,0 +1,48 @@
+//! Short floating quips for scrap pickups, repairs and kills (text loaded from JSON)
+
+use macroquad::prelude::*;
+use serde::Deserialize;
+use super::game_state::ViewMode;
+
+/// What triggered a popup; picks its pool of texts
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FlavorKind {
+    Scrap,
+    Repair,
+    Kill,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct FlavorTexts {
+    pub scrap: Vec<String>,
+    pub repair: Vec<String>,
+    pub kill: Vec<String>,
+}
+
+impl FlavorTexts {
+    pub fn load() -> Self {
+        serde_json::from_str(include_str!("../../assets/flavor_text.json")).unwrap_or_else(|e| {
+            eprintln!("Warning: Failed to load flavor_text.json: {}. Showing no popups.", e);
+            Self::default()
+        })
+    }
+
+    /// A random line for the event, or None if its pool is empty
+    pub fn pick(&self, kind: FlavorKind) -> Option<&str> {
+        let pool = match kind {
+            FlavorKind::Scrap => &self.scrap,
+            FlavorKind::Repair => &self.repair,
+            FlavorKind::Kill => &self.kill,
+        };
+        if pool.is_empty() { return None; }
+        Some(&pool[rand::gen_range(0, pool.len())])
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct FlavorPopup {
+    pub text: String,
+    pub position: Vec2, // In the coordinates of `view` (exterior world or interior space)
+    pub timer: f32,     // Seconds left; counts down from FLAVOR_POPUP_DURATION
+    pub view: ViewMode, // Only drawn while this view is showing
+}
@@ -7,6 +7,7 @@ use crate::ship::ship::{ModuleType, ModuleState};
 use crate::ship::interior::RoomType;
 use crate::simulation::events::{EventBus, GameEvent};
 use crate::simulation::constants::*;
+use crate::economy::items::Item;
 
 impl GameState {
     pub fn attempt_repair(&mut self, x: usize, y: usize, events: &mut EventBus) -> bool {
@@ -19,6 +20,7 @@ impl GameState {
             self.resources.deduct(repair_cost);
             if let Some(module) = &mut self.ship.grid[x][y] {
                 module.state = ModuleState::Active;
+                module.seal_breach();
                 events.push_game(GameEvent::ModuleRepaired { x, y, cost: repair_cost });
                 return true;
             }
@@ -26,11 +28,46 @@ impl GameState {
         false
     }
 
+    /// Scrap an active, non-Core module for half its repair cost. Its room's
+    /// repair points reset, so it has to be rebuilt from scratch.
+    pub fn attempt_sell_module(&mut self, x: usize, y: usize, events: &mut EventBus) -> bool {
+        let refund = match &self.ship.grid[x][y] {
+            Some(module) if module.state == ModuleState::Active && module.module_type != ModuleType::Core => {
+                self.module_registry.get(module.module_type).base_cost / 2
+            }
+            _ => return false,
+        };
+
+        if let Some(module) = &mut self.ship.grid[x][y] {
+            module.state = ModuleState::Destroyed;
+            module.health = 0.0;
+        }
+        if let Some(room) = self.interior.rooms.iter_mut().find(|r| r.module_index == Some((x, y))) {
+            for point in &mut room.repair_points {
+                point.repaired = false;
+            }
+        }
+        self.resources.add_scrap(refund);
+        events.push_game(GameEvent::ModuleSold { x, y, refund });
+        true
+    }
+
+    /// Boost an active module for OVERCHARGE_DURATION seconds. It takes damage once the boost ends.
+    pub fn attempt_overcharge(&mut self, x: usize, y: usize, events: &mut EventBus) -> bool {
+        let now = self.time_survived;
+        let Some(module) = &mut self.ship.grid[x][y] else { return false };
+        if module.state != ModuleState::Active || module.module_type == ModuleType::Core { return false; }
+        if module.overcharged_until > 0.0 { return false; } // Already boosted or cooling off
+
+        module.overcharged_until = now + OVERCHARGE_DURATION;
+        events.push_game(GameEvent::ModuleOvercharged { x, y });
+        true
+    }
+
     pub fn attempt_upgrade(&mut self, x: usize, y: usize, events: &mut EventBus) -> bool {
         let upgrade_cost = if let Some(module) = &self.ship.grid[x][y] {
             if module.state == ModuleState::Destroyed || module.level >= MODULE_MAX_LEVEL { return false; }
-            let base_cost = self.module_registry.get(module.module_type).base_cost;
-            (base_cost as f32 * (module.level as f32 * 0.5 + 1.0)) as i32
+            module.upgrade_cost(self.module_registry.get(module.module_type).base_cost)
         } else { return false; };
 
         if self.resources.can_afford(upgrade_cost) {
@@ -56,16 +93,26 @@ impl GameState {
         }
     }
 
+    /// Open or close the door nearest the player. A door can't close on the player.
+    pub fn toggle_nearby_door(&mut self) {
+        let pos = self.player.position;
+        let Some(idx) = self.interior.door_near(pos, INTERACTION_RANGE) else { return };
+        let door = &mut self.interior.doors[idx];
+        if door.open && door.contains(pos) { return; }
+        door.open = !door.open;
+    }
+
     pub fn get_repair_cost(&self, room_idx: usize, _point_idx: usize) -> Option<(i32, i32)> {
         if room_idx >= self.interior.rooms.len() { return None; }
         let room = &self.interior.rooms[room_idx];
-        let scrap_cost = REPAIR_SCRAP_COST;
+        let scrap_cost = ((REPAIR_SCRAP_COST as f32 * self.repair_cost_multiplier).round() as i32).max(1);
         let power_cost = match room.room_type {
             RoomType::Module(ModuleType::Core) => 0,
             RoomType::Module(ModuleType::Weapon) => POWER_COST_WEAPON,
             RoomType::Module(ModuleType::Defense) => POWER_COST_DEFENSE,
             RoomType::Module(ModuleType::Utility) => POWER_COST_UTILITY,
             RoomType::Module(ModuleType::Engine) => POWER_COST_ENGINE,
+            RoomType::Module(ModuleType::DroneBay) => POWER_COST_DRONE_BAY,
             RoomType::Cockpit => POWER_COST_COCKPIT,
             RoomType::Medbay => POWER_COST_MEDBAY,
             _ => 0,
@@ -87,6 +134,136 @@ impl GameState {
          if self.resources.scrap < scrap_cost { return false; }
          if !is_reactor && (self.used_power + power_cost > self.total_power) { return false; }
          self.resources.deduct(scrap_cost);
+         self.complete_interior_repair(room_idx, point_idx, scrap_cost, events);
+         self.advance_repair_combo();
+         true
+    }
+
+    /// Hold-to-repair: fill a point's progress while affordable, then pay for and complete
+    /// the repair once it reaches 1.0. Returns true on the frame the repair completes.
+    pub fn advance_partial_repair(&mut self, room_idx: usize, point_idx: usize, dt: f32, events: &mut EventBus) -> bool {
+        if self.partial_repair_target != Some((room_idx, point_idx)) {
+            self.cancel_partial_repair();
+            self.partial_repair_target = Some((room_idx, point_idx));
+        }
+        let Some((scrap_cost, power_cost)) = self.get_repair_cost(room_idx, point_idx) else { return false };
+        if !self.resources.can_afford(scrap_cost) { return false; }
+
+        let room = &self.interior.rooms[room_idx];
+        let is_reactor = matches!(room.room_type, RoomType::Module(ModuleType::Core));
+        let Some(point) = room.repair_points.get(point_idx) else { return false };
+        if point.repaired { return false; }
+        // used_power already counts this point's partial share; without power to finish,
+        // drop the progress instead of filling to full and failing the check every time
+        let held_share = (point.repair_progress * power_cost as f32).round() as i32;
+        if !is_reactor && self.used_power - held_share + power_cost > self.total_power {
+            self.cancel_partial_repair();
+            return false;
+        }
+        let point = &mut self.interior.rooms[room_idx].repair_points[point_idx];
+        point.repair_progress = (point.repair_progress + PARTIAL_REPAIR_RATE * dt).min(1.0);
+        if point.repair_progress < 1.0 { return false; }
+
+        point.repair_progress = 0.0;
+        self.partial_repair_target = None;
+        self.update_power(); // Drop the point's partial share before the power check
+        self.attempt_interior_repair(room_idx, point_idx, events)
+    }
+
+    /// Throw away the progress of the point being held (E released or player moved off)
+    pub fn cancel_partial_repair(&mut self) {
+        let Some((room_idx, point_idx)) = self.partial_repair_target.take() else { return };
+        if let Some(point) = self.interior.rooms.get_mut(room_idx).and_then(|r| r.repair_points.get_mut(point_idx)) {
+            point.repair_progress = 0.0;
+        }
+    }
+
+    /// Run every queued repair in order, stopping at the first one that can't be paid for.
+    /// Returns the number of points repaired.
+    pub fn process_repair_queue(&mut self, events: &mut EventBus) -> usize {
+        self.prune_repair_queue();
+        let mut repaired = 0;
+        while let Some(&(room_idx, point_idx)) = self.repair_queue.front() {
+            let done = self.use_repair_kit(room_idx, point_idx, events)
+                || self.attempt_interior_repair(room_idx, point_idx, events);
+            if !done { break; }
+            self.repair_queue.pop_front();
+            repaired += 1;
+        }
+        repaired
+    }
+
+    /// Scrap and power needed to repair everything still broken in the queue
+    pub fn repair_queue_cost(&self) -> (i32, i32) {
+        self.repair_queue.iter()
+            .filter(|&&entry| !self.is_queued_point_repaired(entry))
+            .filter_map(|&(room_idx, point_idx)| self.get_repair_cost(room_idx, point_idx))
+            .fold((0, 0), |(scrap, power), (s, p)| (scrap + s, power + p))
+    }
+
+    /// Drop queued points that were repaired some other way, e.g. by the repair drone
+    pub fn prune_repair_queue(&mut self) {
+        let mut queue = std::mem::take(&mut self.repair_queue);
+        queue.retain(|&entry| !self.is_queued_point_repaired(entry));
+        self.repair_queue = queue;
+    }
+
+    fn is_queued_point_repaired(&self, (room_idx, point_idx): (usize, usize)) -> bool {
+        self.interior.rooms.get(room_idx)
+            .and_then(|r| r.repair_points.get(point_idx))
+            .is_some_and(|p| p.repaired)
+    }
+
+    /// Chain repairs made within COMBO_REPAIR_WINDOW of each other for bonus scrap
+    fn advance_repair_combo(&mut self) {
+        if self.time_survived - self.last_repair_time < COMBO_REPAIR_WINDOW {
+            self.repair_combo += 1;
+            self.resources.add_scrap(self.repair_combo as i32 * COMBO_SCRAP_BONUS);
+        } else {
+            self.repair_combo = 0;
+        }
+        self.last_repair_time = self.time_survived;
+    }
+
+    pub fn break_repair_combo(&mut self) {
+        self.repair_combo = 0;
+    }
+
+    /// Collapsed room that E would clear: one the player stands in, or one nearby when
+    /// there's no repair point or Cockpit shop under the player to use E on instead
+    pub fn collapsed_room_to_clear(&self) -> Option<usize> {
+        let pos = self.player.position;
+        let room_idx = self.interior.collapsed_room_near(pos, INTERACTION_RANGE)?;
+        if self.interior.rooms[room_idx].contains(pos) { return Some(room_idx); }
+
+        let other_use = self.interior.room_at(pos)
+            .is_some_and(|r| r.repair_point_at(pos).is_some() || r.room_type == RoomType::Cockpit);
+        (!other_use).then_some(room_idx)
+    }
+
+    /// Spend scrap to clear the rubble from a collapsed room, restoring it to intact
+    pub fn clear_collapsed_room(&mut self, room_idx: usize) -> bool {
+        let Some(room) = self.interior.rooms.get(room_idx) else { return false };
+        if !room.is_collapsed() || !self.resources.can_afford(ROOM_COLLAPSE_REPAIR_COST) { return false; }
+
+        self.resources.deduct(ROOM_COLLAPSE_REPAIR_COST);
+        self.interior.rooms[room_idx].room_damage = 0.0;
+        true
+    }
+
+    /// Spend a matching RepairKit to repair a point for free (no scrap or power check)
+    pub fn use_repair_kit(&mut self, room_idx: usize, point_idx: usize, events: &mut EventBus) -> bool {
+         let Some(room) = self.interior.rooms.get(room_idx) else { return false };
+         let RoomType::Module(module_type) = room.room_type else { return false };
+         if room.repair_points.get(point_idx).map_or(true, |p| p.repaired) { return false; }
+
+         let Some(kit_idx) = self.inventory.iter().position(|item| *item == Item::RepairKit(module_type)) else { return false };
+         self.inventory.remove(kit_idx);
+         self.complete_interior_repair(room_idx, point_idx, 0, events);
+         true
+    }
+
+    fn complete_interior_repair(&mut self, room_idx: usize, point_idx: usize, scrap_cost: i32, events: &mut EventBus) {
          self.interior.rooms[room_idx].repair_points[point_idx].repaired = true;
          
          // Engine Stress Logic
@@ -100,29 +277,69 @@ impl GameState {
             if let Some((gx, gy)) = self.interior.rooms[room_idx].module_index {
                 if let Some(module) = &mut self.ship.grid[gx][gy] {
                     module.state = ModuleState::Active;
+                    module.seal_breach();
                     module.health = module.max_health;
                 }
             }
          }
-         true
+    }
+
+    /// Craft the recipe at `recipe_idx`, paying its scrap cost
+    pub fn craft(&mut self, recipe_idx: usize) -> bool {
+        let Some(recipe) = self.recipe_book.recipes.get(recipe_idx) else { return false };
+        if !self.resources.can_afford(recipe.scrap_cost) { return false; }
+
+        let item = recipe.output;
+        self.resources.deduct(recipe.scrap_cost);
+        self.inventory.push(item);
+        true
+    }
+
+    /// Buy the next level of a permanent upgrade with the profile's banked credits
+    pub fn purchase_permanent_upgrade(&mut self, upgrade_id: &str) -> bool {
+        let Some(template) = self.permanent_upgrade_templates.iter().find(|t| t.id == upgrade_id) else { return false };
+        if !self.profile.purchase_permanent_upgrade(template) { return false; }
+
+        let _ = self.profile.save();
+        true
     }
 
     pub fn purchase_upgrade(&mut self, upgrade_id: &str) -> bool {
-        let template = self.upgrade_templates.iter().find(|t| t.id == upgrade_id).cloned();
+        let unlocked = self.unlocked_upgrade_count();
+        let template = self.upgrade_templates.iter().take(unlocked)
+            .find(|t| t.id == upgrade_id && self.schematic_known(t))
+            .cloned();
         if let Some(template) = template {
             let current_level = self.upgrades.get_level(upgrade_id);
             if current_level < template.max_level {
                 let cost = self.upgrades.get_cost(&template);
                 if self.resources.deduct_credits(cost) {
-                    self.upgrades.levels.insert(upgrade_id.to_string(), current_level + 1);
-                    if upgrade_id == "hull_reinforcement" {
-                        self.ship_max_integrity += HULL_UPGRADE_BONUS;
-                        self.ship_integrity += HULL_UPGRADE_BONUS;
-                    }
+                    self.raise_upgrade_level(upgrade_id);
                     return true;
                 }
             }
         }
         false
     }
+
+    /// Buy an upgrade from the Cockpit shop with scrap. It leaves the shop once bought.
+    pub fn purchase_in_run_upgrade(&mut self, upgrade_id: &str, scrap_cost: i32) -> bool {
+        if !self.shop_offers.iter().any(|id| id == upgrade_id) { return false; }
+        if !self.resources.can_afford(scrap_cost) { return false; }
+
+        self.resources.deduct(scrap_cost);
+        self.raise_upgrade_level(upgrade_id);
+        self.shop_offers.retain(|id| id != upgrade_id);
+        self.shop_selection = self.shop_selection.min(self.shop_offers.len().saturating_sub(1));
+        true
+    }
+
+    fn raise_upgrade_level(&mut self, upgrade_id: &str) {
+        let current_level = self.upgrades.get_level(upgrade_id);
+        self.upgrades.levels.insert(upgrade_id.to_string(), current_level + 1);
+        if upgrade_id == "hull_reinforcement" {
+            self.ship_max_integrity += HULL_UPGRADE_BONUS;
+            self.ship_integrity += HULL_UPGRADE_BONUS;
+        }
+    }
 }
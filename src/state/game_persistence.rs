@@ -1,10 +1,15 @@
 //! Game persistence (save/load)
 //!
-//! Save and load functionality - only available on native platforms (not WASM).
+//! Save data is written to JSON files on native platforms and to the browser's
+//! localStorage on WASM. Both share the same SaveData conversion.
 
 use crate::state::game_state::GameState;
-use crate::state::persistence::SaveData;
-use crate::enemy::entities::{Enemy, Projectile, Particle, ScrapPile};
+use crate::state::persistence::{parse_save, SaveData, SaveSlotSummary};
+use crate::state::persistence::{SavedEnemy, SavedInteriorEnemy, SavedProjectile, SavedParticle, SavedScrapPile, SavedSpawner};
+use crate::enemy::entities::{Enemy, EnemyType, InteriorEnemy, Projectile, ProjectileType, Particle, ScrapPile, SpawnerRoom};
+use crate::ship::interior::ShipInterior;
+use crate::data::settings::Difficulty;
+use crate::simulation::constants::CURRENT_SAVE_VERSION;
 use macroquad::prelude::*;
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -12,13 +17,10 @@ use std::fs::File;
 #[cfg(not(target_arch = "wasm32"))]
 use std::io::{BufReader, BufWriter};
 
-#[cfg(not(target_arch = "wasm32"))]
-use crate::state::persistence::{SavedEnemy, SavedProjectile, SavedParticle, SavedScrapPile};
-
-#[cfg(not(target_arch = "wasm32"))]
 impl GameState {
-    pub fn save(&self, path: &str) -> std::io::Result<()> {
-        let save_data = SaveData {
+    fn to_save_data(&self) -> SaveData {
+        SaveData {
+            save_version: CURRENT_SAVE_VERSION,
             ship: self.ship.clone(),
             resources: self.resources.clone(),
             phase: self.phase,
@@ -35,49 +37,86 @@ impl GameState {
                 target: e.target_module,
                 attached_to: e.attached_to,
                 ability_timer: e.ability_timer,
+                drops_remaining: e.drops_remaining,
+                elite: e.elite,
+                is_retreating: e.is_retreating,
             }).collect(),
-            projectiles: self.projectiles.iter().map(|p| SavedProjectile {
+            // Beams only live for a few frames, so they aren't worth saving
+            projectiles: self.projectiles.iter().filter(|p| p.kind == ProjectileType::Ballistic).map(|p| SavedProjectile {
                 pos: (p.position.x, p.position.y),
                 vel: (p.velocity.x, p.velocity.y),
                 damage: p.damage,
                 active: p.active,
             }).collect(),
-            particles: self.particles.iter().map(|p| SavedParticle {
+            particles: self.particle_pool.iter_active().map(|p| SavedParticle {
                 pos: (p.position.x, p.position.y),
                 vel: (p.velocity.x, p.velocity.y),
                 life: p.lifetime,
                 max_life: p.max_lifetime,
                 color: (p.color.r, p.color.g, p.color.b, p.color.a),
                 active: p.active,
+                kind: p.kind,
             }).collect(),
             scrap_piles: self.scrap_piles.iter().map(|p| SavedScrapPile {
                 pos: (p.position.x, p.position.y),
                 amount: p.amount,
                 active: p.active,
+                contains_schematic: p.contains_schematic.clone(),
+            }).collect(),
+            spawners: self.spawners.iter().map(|s| SavedSpawner {
+                pos: (s.position.x, s.position.y),
+                hp: s.health,
+                spawn_timer: s.spawn_timer,
+            }).collect(),
+            spawner_spawned: self.wave_state.spawner_spawned,
+            carrier_spawned: self.wave_state.carrier_spawned,
+            interior_enemies: self.interior_enemies.iter().map(|e| SavedInteriorEnemy {
+                pos: (e.position.x, e.position.y),
+                hp: e.health,
             }).collect(),
             upgrades: self.upgrades.clone(),
             frame_count: self.frame_count,
             time_survived: self.time_survived,
+            run_seed: self.run_seed,
+            enemies_killed: self.enemies_killed,
+            run_stats: self.run_stats.clone(),
+            ship_log: self.ship_log.clone(),
+            room_event_triggered: self.room_event_triggered.clone(),
+            discovered_schematics: self.discovered_schematics.clone(),
+            daily_date: self.daily_date.clone(),
+            difficulty: Some(self.difficulty),
+            veteran_level: Some(self.veteran_level),
+            ironman: self.ironman,
+            ship_path: self.run_ship_path().to_string(),
             room_repair_states: self.interior.rooms.iter()
                 .map(|room| room.repair_points.iter().map(|rp| rp.repaired).collect())
                 .collect(),
+            room_fire_levels: self.interior.rooms.iter().map(|room| room.fire_level).collect(),
+            room_damage_levels: self.interior.rooms.iter().map(|room| room.room_damage).collect(),
+            door_states: self.interior.doors.iter().map(|door| door.open).collect(),
+            shop_offers: self.shop_offers.clone(),
+            shop_refreshes_remaining: self.shop_refreshes_remaining,
+            round: self.round,
             player_pos: (self.player.position.x, self.player.position.y),
+            player_health: self.player_health,
+            player_stamina: self.player.stamina,
             view_mode: self.view_mode,
+            inventory: self.inventory.clone(),
             tutorial_index: self.tutorial_state.current_index,
             tutorial_completed: self.tutorial_state.completed,
-        };
-        let file = File::create(path)?;
-        let writer = BufWriter::new(file);
-        serde_json::to_writer_pretty(writer, &save_data)?;
-        Ok(())
+        }
     }
 
-    pub fn load_from_file(path: &str) -> std::io::Result<Self> {
-        let file = File::open(path)?;
-        let reader = BufReader::new(file);
-        let save_data: SaveData = serde_json::from_reader(reader)?;
+    fn from_save_data(save_data: SaveData) -> Self {
         let mut state = GameState::new();
         state.ship = save_data.ship;
+        state.interior = ShipInterior::load(&save_data.ship_path);
+        // A daily run's starter ship shouldn't replace the hull picked for normal runs
+        if save_data.daily_date.is_none() {
+            state.selected_ship_path = save_data.ship_path;
+        }
+        state.refresh_synergies();
+        state.update_power(); // So a restored power level isn't announced as a new wave tier
         state.resources = save_data.resources;
         state.phase = save_data.phase;
         state.engine_state = save_data.engine_state;
@@ -85,6 +124,26 @@ impl GameState {
         state.upgrades = save_data.upgrades;
         state.frame_count = save_data.frame_count;
         state.time_survived = save_data.time_survived;
+        state.run_seed = save_data.run_seed;
+        state.refresh_hull_cracks();
+        state.enemies_killed = save_data.enemies_killed;
+        state.run_stats = save_data.run_stats;
+        state.ship_log = save_data.ship_log;
+        state.room_event_triggered = save_data.room_event_triggered;
+        state.discovered_schematics = save_data.discovered_schematics;
+        state.daily_date = save_data.daily_date;
+        state.difficulty = save_data.difficulty.unwrap_or(if state.daily_date.is_some() {
+            Difficulty::Normal
+        } else {
+            state.settings.difficulty
+        });
+        state.veteran_level = save_data.veteran_level.unwrap_or(if state.daily_date.is_some() {
+            0
+        } else {
+            state.profile.veteran_level()
+        });
+        state.ironman = save_data.ironman;
+        state.refresh_repair_discount();
         state.enemies = save_data.enemies.into_iter().map(|s| Enemy {
             id: s.id,
             enemy_type: s.enemy_type,
@@ -97,25 +156,53 @@ impl GameState {
             attached_to: s.attached_to,
             ability_timer: s.ability_timer,
             attacking: false,
+            animation_timer: 0.0,
+            status: None,
+            trail_timer: 0.0,
+            pathfinding_waypoints: Vec::new(),
+            pathfinding_dirty: true,
+            path_start: vec2(s.pos.0, s.pos.1),
+            drops_remaining: s.drops_remaining,
+            elite: s.elite,
+            is_retreating: s.is_retreating,
         }).collect();
         state.projectiles = save_data.projectiles.into_iter().map(|s| Projectile {
             position: vec2(s.pos.0, s.pos.1),
             velocity: vec2(s.vel.0, s.vel.1),
             damage: s.damage,
             active: s.active,
+            trail_cooldown: 0.0,
+            kind: ProjectileType::Ballistic,
         }).collect();
-        state.particles = save_data.particles.into_iter().map(|s| Particle {
-            position: vec2(s.pos.0, s.pos.1),
-            velocity: vec2(s.vel.0, s.vel.1),
-            lifetime: s.life,
-            max_lifetime: s.max_life,
-            color: Color::new(s.color.0, s.color.1, s.color.2, s.color.3),
-            active: s.active,
-        }).collect();
+        for s in save_data.particles.into_iter().filter(|s| s.active) {
+            state.particle_pool.insert(Particle {
+                position: vec2(s.pos.0, s.pos.1),
+                velocity: vec2(s.vel.0, s.vel.1),
+                lifetime: s.life,
+                max_lifetime: s.max_life,
+                color: Color::new(s.color.0, s.color.1, s.color.2, s.color.3),
+                active: true,
+                kind: s.kind,
+            });
+        }
         state.scrap_piles = save_data.scrap_piles.into_iter().map(|s| ScrapPile {
             position: vec2(s.pos.0, s.pos.1),
             amount: s.amount,
             active: s.active,
+            contains_schematic: s.contains_schematic,
+        }).collect();
+        state.spawners = save_data.spawners.into_iter().map(|s| SpawnerRoom {
+            position: vec2(s.pos.0, s.pos.1),
+            health: s.hp,
+            spawn_timer: s.spawn_timer,
+        }).collect();
+        // Older saves lack the flag; a spawner still standing was spawned this round either way
+        state.wave_state.spawner_spawned = save_data.spawner_spawned || !state.spawners.is_empty();
+        state.wave_state.carrier_spawned = save_data.carrier_spawned
+            || state.enemies.iter().any(|e| e.enemy_type == EnemyType::Carrier);
+        state.interior_enemies = save_data.interior_enemies.into_iter().map(|s| InteriorEnemy {
+            health: s.hp,
+            ..InteriorEnemy::new(vec2(s.pos.0, s.pos.1))
         }).collect();
         
         // Restore interior repair states
@@ -129,21 +216,63 @@ impl GameState {
             }
         }
         
+        for (room, fire_level) in state.interior.rooms.iter_mut().zip(save_data.room_fire_levels) {
+            room.fire_level = fire_level;
+        }
+        for (room, damage) in state.interior.rooms.iter_mut().zip(save_data.room_damage_levels) {
+            room.room_damage = damage;
+        }
+
+        for (door, open) in state.interior.doors.iter_mut().zip(save_data.door_states) {
+            door.open = open;
+        }
+        state.round = save_data.round;
+        state.shop_offers = save_data.shop_offers;
+        state.shop_refreshes_remaining = save_data.shop_refreshes_remaining;
+        state.shop_selection = 0;
+        // Saves from before the Cockpit shop have no offers to restore
+        if state.shop_offers.is_empty() {
+            state.roll_shop_offers();
+        }
+
         // Restore player position
         state.player.position = vec2(save_data.player_pos.0, save_data.player_pos.1);
+        state.player_health = save_data.player_health;
+        state.player.stamina = save_data.player_stamina.clamp(0.0, state.player.max_stamina);
         state.view_mode = save_data.view_mode;
+        state.inventory = save_data.inventory;
         
         // Restore tutorial state
         state.tutorial_state.current_index = save_data.tutorial_index;
         state.tutorial_state.completed = save_data.tutorial_completed;
         
-        Ok(state)
+        state
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl GameState {
+    pub fn save(&self, path: &str) -> std::io::Result<()> {
+        let file = File::create(path)?;
+        let writer = BufWriter::new(file);
+        serde_json::to_writer_pretty(writer, &self.to_save_data())?;
+        Ok(())
+    }
+
+    pub fn load_from_file(path: &str) -> std::io::Result<Self> {
+        let json = std::fs::read_to_string(path)?;
+        let save_data = parse_save(&json).map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+        Ok(Self::from_save_data(save_data))
     }
 
     pub fn get_save_slot_path(slot: usize) -> String {
         format!("save_slot_{}.json", slot)
     }
 
+    pub fn slot_exists(slot: usize) -> bool {
+        std::path::Path::new(&Self::get_save_slot_path(slot)).exists()
+    }
+
     pub fn save_to_slot(&self, slot: usize) -> std::io::Result<()> {
         let path = Self::get_save_slot_path(slot);
         self.save(&path)
@@ -153,4 +282,54 @@ impl GameState {
         let path = Self::get_save_slot_path(slot);
         Self::load_from_file(&path)
     }
+
+    /// Read just the summary fields of a slot, or None if the slot is empty/unreadable
+    pub fn read_slot_summary(slot: usize) -> Option<SaveSlotSummary> {
+        let file = File::open(Self::get_save_slot_path(slot)).ok()?;
+        serde_json::from_reader(BufReader::new(file)).ok()
+    }
+
+    pub fn delete_slot(slot: usize) {
+        let _ = std::fs::remove_file(Self::get_save_slot_path(slot));
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+impl GameState {
+    pub fn get_save_slot_key(slot: usize) -> String {
+        format!("scrapyard_save_{}", slot)
+    }
+
+    /// Raw JSON stored for a slot, if any
+    fn read_slot_json(slot: usize) -> Option<String> {
+        quad_storage::STORAGE.lock().ok()?.get(&Self::get_save_slot_key(slot))
+    }
+
+    pub fn slot_exists(slot: usize) -> bool {
+        Self::read_slot_json(slot).is_some()
+    }
+
+    pub fn save_to_slot(&self, slot: usize) -> Result<(), String> {
+        let json = serde_json::to_string(&self.to_save_data()).map_err(|e| e.to_string())?;
+        let mut storage = quad_storage::STORAGE.lock().map_err(|_| "localStorage unavailable".to_string())?;
+        storage.set(&Self::get_save_slot_key(slot), &json);
+        Ok(())
+    }
+
+    pub fn load_from_slot(slot: usize) -> Result<Self, String> {
+        let json = Self::read_slot_json(slot).ok_or_else(|| format!("slot {} is empty", slot + 1))?;
+        let save_data = parse_save(&json)?;
+        Ok(Self::from_save_data(save_data))
+    }
+
+    /// Read just the summary fields of a slot, or None if the slot is empty/unreadable
+    pub fn read_slot_summary(slot: usize) -> Option<SaveSlotSummary> {
+        serde_json::from_str(&Self::read_slot_json(slot)?).ok()
+    }
+
+    pub fn delete_slot(slot: usize) {
+        if let Ok(mut storage) = quad_storage::STORAGE.lock() {
+            storage.remove(&Self::get_save_slot_key(slot));
+        }
+    }
 }
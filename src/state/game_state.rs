@@ -1,18 +1,34 @@
 use macroquad::prelude::*;
 use serde::{Deserialize, Serialize};
+use std::collections::{HashSet, VecDeque};
 
 use crate::ship::ship::Ship;
-use crate::ship::interior::{ShipInterior, RoomType};
+use crate::ship::interior::{ShipInterior, ShipManifest, Room, RoomType, DEFAULT_SHIP_PATH};
 use crate::ship::player::Player;
 use crate::economy::resources::Resources;
 use crate::economy::upgrades::{GameUpgrades, UpgradeTemplate};
+use crate::economy::items::{Item, RecipeBook};
 use crate::simulation::constants::*;
 use crate::simulation::gameplay::ModuleRegistry;
-use crate::enemy::entities::{Enemy, Projectile, Particle, ScrapPile};
+use crate::enemy::entities::{Enemy, EnemyType, InteriorEnemy, ParticleKind, Projectile, ScrapPile, SpawnerRoom};
 use crate::enemy::wave::WaveState;
+use crate::enemy::ai::EnemyScaling;
+use crate::simulation::pool::ParticlePool;
+use crate::simulation::effects::DamageNumber;
+use crate::simulation::synergy::calculate_synergies;
+use crate::simulation::environment::EnvironmentEvent;
+use crate::simulation::rng;
 use super::tutorial::{TutorialConfig, TutorialState};
-use crate::data::settings::Settings;
+use super::persistence::SaveSlotSummary;
+use super::profile::{today, veteran_multipliers, PlayerProfile};
+use super::achievements::{AchievementConfig, AchievementNotification};
+use super::codex::{load_codex, CodexEntry};
+use super::ship_log::{LogEntry, LogKind};
+use super::flavor::{FlavorKind, FlavorPopup, FlavorTexts};
+use crate::data::settings::{BindAction, Difficulty, Settings};
 use crate::ui::assets::AssetManager;
+#[cfg(debug_assertions)]
+use crate::ui::debug_console::DebugConsole;
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
 pub enum GamePhase {
@@ -20,7 +36,25 @@ pub enum GamePhase {
     Playing,
     GameOver,
     Victory,
+    VictoryAnimation, // Ship flying off before the upgrade screen; the countdown lives in GameState::victory_anim_timer
     InterRound,
+    SaveSlotSelect { mode: SlotSelectMode },
+    DifficultySelect,
+    ShipSelect { selected: usize },
+    Achievements,
+    HighScores,
+    SeedEntry, // Typing a hex seed; the text lives in GameState::seed_buffer
+    DailyChallenge, // Today's fixed-seed run: start it or see the recorded result
+    KeybindCapture { action: BindAction },
+    Crafting,
+    InRunShop, // Cockpit upgrade shop; offers live in GameState::shop_offers
+    Codex { selected_entry: usize },
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+pub enum SlotSelectMode {
+    Save,
+    Load,
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
@@ -36,6 +70,26 @@ pub enum ViewMode {
     Interior,
 }
 
+/// Per-run statistics shown on the end-of-run recap
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RunStats {
+    pub kills_by_type: std::collections::HashMap<EnemyType, u32>,
+    pub damage_dealt: f32,
+    pub repairs_performed: u32,
+    pub scrap_gathered: i32,
+    #[serde(default)]
+    pub damage_taken: f32, // Hull integrity lost to enemy attacks
+    #[serde(default)]
+    pub escape_credits: i32, // Base escape payout, before the defense bonus
+    #[serde(default)]
+    pub defense_bonus: i32,
+}
+
+impl RunStats {
+    pub fn record_kill(&mut self, enemy_type: &EnemyType) {
+        *self.kills_by_type.entry(enemy_type.clone()).or_insert(0) += 1;
+    }
+}
 
 pub struct GameState {
     pub ship: Ship,
@@ -45,9 +99,15 @@ pub struct GameState {
     pub module_registry: ModuleRegistry,
     pub assets: crate::ui::assets::AssetManager,
     pub view_mode: ViewMode,
+    pub show_map: bool, // Interior layout overlay (M)
     pub player: Player,
+    pub player_health: f32,
+    pub player_max_health: f32,
+    pub gamepad_move: Option<Vec2>, // Analog movement from a gamepad stick or touch swipe
+    pub sprint_held: bool, // Sprint key or gamepad bumper, read by Player::update
     pub total_power: i32,
     pub used_power: i32,
+    pub cockpit_fully_repaired: bool, // Exterior view previews enemy movement while true
     pub required_power: i32,
     pub ship_integrity: f32,
     pub ship_max_integrity: f32,
@@ -58,29 +118,93 @@ pub struct GameState {
     pub engine_state: EngineState,
     pub escape_timer: f32,
     pub scrap_piles: Vec<ScrapPile>,
+    pub spawners: Vec<SpawnerRoom>,
     pub gathering_target: Option<usize>,
     pub gathering_timer: f32,
     pub upgrades: GameUpgrades,
     pub upgrade_templates: Vec<UpgradeTemplate>,
+    pub inventory: Vec<Item>,
+    pub recipe_book: RecipeBook,
+    pub crafting_selection: usize,
+    pub shop_offers: Vec<String>, // Upgrade ids on sale in the Cockpit shop
+    pub shop_selection: usize,
+    pub shop_refreshes_remaining: u32,
+    pub round: u32, // Rounds started this run, 1 for the first
     pub enemies: Vec<Enemy>,
+    pub interior_enemies: Vec<InteriorEnemy>, // Boarders chasing the player inside
     pub projectiles: Vec<Projectile>,
-    pub particles: Vec<Particle>,
+    pub particle_pool: ParticlePool,
+    pub damage_numbers: Vec<DamageNumber>,
     pub frame_count: u64,
     pub time_survived: f32,
+    pub enemies_killed: u32, // This run
+    pub run_stats: RunStats,
     pub wave_state: WaveState,
     pub repair_timer: f32,
+    pub last_repair_time: f32, // time_survived at the last interior repair
+    pub repair_cost_multiplier: f32, // Scales REPAIR_SCRAP_COST, lowered by the "repair_discount" permanent upgrade
+    pub repair_combo: u32,
+    pub repair_queue: VecDeque<(usize, usize)>, // (room_idx, point_idx) repairs queued with the mouse wheel
+    pub partial_repair_target: Option<(usize, usize)>, // (room_idx, point_idx) the player is holding E on
+    pub repair_progress: f32,
+    pub repair_drone_pos: Vec2,
+    pub repair_drone_target: Option<(usize, usize)>,
+    pub synergies: std::collections::HashMap<(usize, usize), f32>, // Weapon/Defense adjacency bonuses
     pub pause_menu_selection: usize,
     pub settings_open: bool,
     pub settings_selection: usize,
+    pub keybinds_open: bool,
+    pub keybind_selection: usize,
+    pub ship_log: Vec<LogEntry>, // Newest last, capped at SHIP_LOG_CAPACITY
+    pub ship_log_open: bool,
+    pub flavor_texts: FlavorTexts,
+    pub flavor_popups: Vec<FlavorPopup>, // Oldest first, capped at FLAVOR_POPUP_MAX
+    pub room_event_triggered: HashSet<usize>, // Room ids whose scripted event has fired this run
+    pub discovered_schematics: HashSet<String>, // Upgrade ids whose schematic was found this run
+    pub slot_selection: usize,
+    pub slot_summaries: Vec<Option<SaveSlotSummary>>,
+    pub toast: Option<String>,
+    pub toast_timer: f32,
+    pub sell_confirm: Option<(usize, usize)>, // Module right-clicked once, awaiting a second click to sell
+    pub sell_confirm_timer: f32,
+    pub veteran_reset_confirm: bool, // Shift+clicked New Game once, awaiting a second to reset veteran level
+    pub profile: PlayerProfile,
+    pub achievement_config: AchievementConfig,
+    pub achievement_notifications: Vec<AchievementNotification>,
+    pub codex_entries: Vec<CodexEntry>,
+    pub permanent_upgrade_templates: Vec<UpgradeTemplate>,
+    pub last_phase: GamePhase, // Phase seen on the previous frame, for saving the profile on change
+    pub ship_manifest: ShipManifest,
+    pub ship_previews: Vec<ShipInterior>, // Parsed layouts, parallel to ship_manifest.ships
+    pub selected_ship_path: String,
     pub settings: Settings,
     pub engine_stress: f32,
     pub nanite_alert: f32,
+    pub environment_events: Vec<EnvironmentEvent>,
+    pub event_spawn_timer: f32,
+    pub scrap_respawn_timer: f32,
+    pub hull_crack_positions: Vec<(Vec2, Vec2)>, // Crack segments in hull-relative 0-1 coordinates, from run_seed
+    pub hull_smoke_timer: f32,
+    pub victory_anim_timer: f32,
+    pub victory_thrust_timer: f32,
+    pub run_seed: u64,              // Gameplay RNG seed of the current (or last) run
+    pub pending_seed: Option<u64>,  // Seed typed on the menu for the next run
+    pub seed_buffer: String,
+    pub daily_date: Option<String>, // Set while playing that date's daily challenge
+    pub difficulty: Difficulty,     // Difficulty of the current run; Normal for daily challenges
+    pub veteran_level: u32,         // Profile veteran level when the run started
+    pub ironman: bool,              // Ironman setting when the run started; toggling it later doesn't apply
+    #[cfg(debug_assertions)]
+    pub debug_console: DebugConsole,
 }
 
 impl GameState {
     pub fn new() -> Self {
         let interior = ShipInterior::starter_ship();
         let player = Player::new_at(interior.player_start_position());
+        let settings = Settings::load();
+        let ship_manifest = ShipManifest::load();
+        let ship_previews = ship_manifest.ships.iter().map(|s| ShipInterior::load(&s.path)).collect();
         
         let mut state = Self {
             ship: Ship::new(GRID_WIDTH, GRID_HEIGHT),
@@ -96,22 +220,31 @@ impl GameState {
                 am
             },
             view_mode: ViewMode::Interior,
+            show_map: false,
             player,
+            player_health: PLAYER_MAX_HEALTH,
+            player_max_health: PLAYER_MAX_HEALTH,
+            gamepad_move: None,
+            sprint_held: false,
             total_power: 0,
             used_power: 0,
+            cockpit_fully_repaired: false,
             required_power: 100,
             ship_integrity: SHIP_BASE_INTEGRITY,
             ship_max_integrity: SHIP_BASE_INTEGRITY,
             tutorial_config: TutorialConfig::load(),
-            tutorial_state: TutorialState::new(),
+            tutorial_state: TutorialState::new(&settings),
             tutorial_timer: 0.0,
             paused: false,
             engine_state: EngineState::Idle,
             escape_timer: 60.0,
             enemies: Vec::new(),
+            interior_enemies: Vec::new(),
             projectiles: Vec::new(),
-            particles: Vec::new(),
+            particle_pool: ParticlePool::new(),
+            damage_numbers: Vec::new(),
             scrap_piles: Vec::new(),
+            spawners: Vec::new(),
             gathering_target: None,
             gathering_timer: 0.0,
             upgrades: GameUpgrades::new(),
@@ -120,72 +253,495 @@ impl GameState {
                     eprintln!("Warning: Failed to load upgrades.json: {}. Using empty list.", e);
                     Vec::new()
                 }),
+            inventory: Vec::new(),
+            recipe_book: RecipeBook::load(),
+            crafting_selection: 0,
+            shop_offers: Vec::new(),
+            shop_selection: 0,
+            shop_refreshes_remaining: IN_RUN_SHOP_REFRESHES,
+            round: 0,
             frame_count: 0,
             time_survived: 0.0,
+            enemies_killed: 0,
+            run_stats: RunStats::default(),
             wave_state: WaveState::new(),
             repair_timer: 0.0,
+            last_repair_time: f32::NEG_INFINITY,
+            repair_cost_multiplier: 1.0,
+            repair_combo: 0,
+            repair_queue: VecDeque::new(),
+            partial_repair_target: None,
+            repair_progress: 0.0,
+            repair_drone_pos: vec2(SCREEN_WIDTH / 2.0, SCREEN_HEIGHT / 2.0),
+            repair_drone_target: None,
+            synergies: std::collections::HashMap::new(),
             pause_menu_selection: 0,
             settings_open: false,
             settings_selection: 0,
-            settings: Settings::load(),
+            keybinds_open: false,
+            keybind_selection: 0,
+            ship_log: Vec::new(),
+            ship_log_open: false,
+            flavor_texts: FlavorTexts::load(),
+            flavor_popups: Vec::new(),
+            room_event_triggered: HashSet::new(),
+            discovered_schematics: HashSet::new(),
+            slot_selection: 0,
+            slot_summaries: Vec::new(),
+            toast: None,
+            toast_timer: 0.0,
+            sell_confirm: None,
+            sell_confirm_timer: 0.0,
+            veteran_reset_confirm: false,
+            profile: PlayerProfile::load(),
+            achievement_config: AchievementConfig::load(),
+            achievement_notifications: Vec::new(),
+            codex_entries: load_codex(),
+            permanent_upgrade_templates: serde_json::from_str(include_str!("../../assets/permanent_upgrades.json"))
+                .unwrap_or_else(|e| {
+                    eprintln!("Warning: Failed to load permanent_upgrades.json: {}. Using empty list.", e);
+                    Vec::new()
+                }),
+            last_phase: GamePhase::Menu,
+            ship_manifest,
+            ship_previews,
+            selected_ship_path: DEFAULT_SHIP_PATH.to_string(),
+            settings,
             engine_stress: 0.0,
             nanite_alert: NANITE_ALERT_BASE, // Initial alert level
+            environment_events: Vec::new(),
+            event_spawn_timer: 0.0,
+            scrap_respawn_timer: 0.0,
+            hull_crack_positions: Vec::new(),
+            hull_smoke_timer: 0.0,
+            victory_anim_timer: 0.0,
+            victory_thrust_timer: 0.0,
+            run_seed: rng::new_seed(),
+            pending_seed: None,
+            seed_buffer: String::new(),
+            daily_date: None,
+            difficulty: Difficulty::default(),
+            veteran_level: 0,
+            ironman: false,
+            #[cfg(debug_assertions)]
+            debug_console: DebugConsole::default(),
         };
         
+        state.difficulty = state.settings.difficulty;
+        state.veteran_level = state.profile.veteran_level();
         state.spawn_scrap_piles();
         state
     }
 
+    /// Start a fresh run: seed the gameplay RNG (with the typed seed if any) and start round one
+    pub fn start_run(&mut self) {
+        self.daily_date = None;
+        self.run_seed = self.pending_seed.take().unwrap_or_else(rng::new_seed);
+        rng::seed(self.run_seed);
+        // Schematics last the whole run, across rounds
+        self.discovered_schematics.clear();
+        self.round = 0;
+        self.start_new_game();
+    }
+
+    /// Start today's daily challenge: the date's shared seed on Normal difficulty
+    pub fn start_daily_challenge(&mut self) {
+        let date = today();
+        if date.is_empty() || self.profile.daily_record(&date).is_some() { return; }
+        self.run_seed = rng::daily_seed(&date);
+        self.daily_date = Some(date);
+        rng::seed(self.run_seed);
+        self.discovered_schematics.clear();
+        self.round = 0;
+        self.start_new_game();
+        // Use up the day's attempt now, so quitting the game can't earn a retry
+        self.record_daily_result();
+        let _ = self.profile.save();
+    }
+
+    /// Bank the daily challenge result, overwriting the attempt recorded at the start
+    pub fn record_daily_result(&mut self) {
+        let Some(date) = self.daily_date.clone() else { return };
+        self.profile.record_daily(&date, self.time_survived, self.enemies_killed);
+    }
+
     pub fn start_new_game(&mut self) {
+        // Daily challenges ignore the difficulty setting so every player faces the same run
+        self.difficulty = if self.daily_date.is_some() { Difficulty::Normal } else { self.settings.difficulty };
+        // They also fly the starter ship with no veteran scaling or permanent upgrades
+        self.veteran_level = if self.daily_date.is_some() { 0 } else { self.profile.veteran_level() };
+        self.ironman = self.settings.ironman_mode;
+        self.round += 1;
         self.ship = Ship::new(GRID_WIDTH, GRID_HEIGHT);
-        self.interior = ShipInterior::starter_ship();
+        self.interior = ShipInterior::load(self.run_ship_path());
+        self.ship.add_room_modules(self.interior.module_slots());
         self.resources = Resources::new();
         self.resources.scrap = 50;
         self.enemies.clear();
+        self.interior_enemies.clear();
         self.projectiles.clear();
-        self.particles.clear();
+        self.particle_pool.clear();
+        self.damage_numbers.clear();
+        self.inventory.clear();
         self.frame_count = 0;
         self.time_survived = 0.0;
+        self.enemies_killed = 0;
+        self.run_stats = RunStats::default();
+        self.ship_log.clear();
+        self.flavor_popups.clear();
+        self.room_event_triggered.clear();
         self.paused = false;
         self.engine_state = EngineState::Idle;
         self.escape_timer = 60.0;
         self.view_mode = ViewMode::Interior;
         self.player = Player::new_at(self.interior.player_start_position());
+        self.player_health = PLAYER_MAX_HEALTH;
+        self.player_max_health = PLAYER_MAX_HEALTH;
         self.engine_stress = 0.0;
         self.nanite_alert = NANITE_ALERT_BASE;
+        self.environment_events.clear();
+        self.event_spawn_timer = 0.0;
+        self.scrap_respawn_timer = 0.0;
+        self.refresh_hull_cracks();
+        self.hull_smoke_timer = 0.0;
         
         self.total_power = 0;
         self.used_power = 0;
         self.ship_integrity = SHIP_BASE_INTEGRITY;
         self.ship_max_integrity = SHIP_BASE_INTEGRITY;
-        self.tutorial_state = TutorialState::new();
+        self.tutorial_state = TutorialState::new(&self.settings);
         self.tutorial_timer = 0.0;
         self.phase = GamePhase::Playing;
         self.scrap_piles.clear();
+        self.spawners.clear();
         self.gathering_target = None;
         self.gathering_timer = 0.0;
+        self.repair_queue.clear();
+        self.partial_repair_target = None;
+        self.show_map = false;
+        self.sell_confirm = None;
         
         self.wave_state = WaveState::new();
         self.repair_timer = 0.0;
+        self.last_repair_time = f32::NEG_INFINITY;
+        self.repair_combo = 0;
+        self.repair_progress = 0.0;
+        self.repair_drone_pos = vec2(SCREEN_WIDTH / 2.0, SCREEN_HEIGHT / 2.0);
+        self.repair_drone_target = None;
+        self.refresh_synergies();
         self.pause_menu_selection = 0;
+        self.shop_refreshes_remaining = IN_RUN_SHOP_REFRESHES;
+        self.roll_shop_offers();
 
+        self.apply_profile_bonuses();
         self.spawn_scrap_piles();
     }
 
+    /// Pick the Cockpit shop's offers. Seeded from the run seed and restock count,
+    /// so the same seed sees the same shop.
+    pub fn roll_shop_offers(&mut self) {
+        // Each round and each restock within it shuffles differently
+        let restock = ((self.round as u64) << 32) | (IN_RUN_SHOP_REFRESHES - self.shop_refreshes_remaining) as u64;
+        let mut ids: Vec<String> = self.upgrade_templates.iter()
+            .filter(|t| self.upgrades.get_level(&t.id) < t.max_level && self.schematic_known(t))
+            .map(|t| t.id.clone())
+            .collect();
+        rng::shuffle_seeded(self.run_seed ^ restock.wrapping_mul(0x9E37_79B9_7F4A_7C15), &mut ids);
+        ids.truncate(IN_RUN_SHOP_OFFERS);
+        self.shop_offers = ids;
+        self.shop_selection = 0;
+    }
+
+    /// Scrap price of an upgrade in the Cockpit shop, or None if unknown or maxed
+    pub fn in_run_shop_cost(&self, upgrade_id: &str) -> Option<i32> {
+        let template = self.upgrade_templates.iter().find(|t| t.id == upgrade_id)?;
+        if self.upgrades.get_level(upgrade_id) >= template.max_level || !self.schematic_known(template) { return None; }
+        Some((self.upgrades.get_cost(template) as f32 * IN_RUN_SHOP_SCRAP_RATE) as i32)
+    }
+
+    /// Enemy HP and spawn interval multipliers for the current run
+    pub fn enemy_scaling(&self) -> EnemyScaling {
+        let (interval_scale, hp_scale) = self.difficulty.difficulty_multiplier();
+        let (veteran_interval, veteran_hp) = veteran_multipliers(self.veteran_level);
+        EnemyScaling { hp: hp_scale * veteran_hp, spawn_interval: interval_scale * veteran_interval }
+    }
+
+    /// Whether an upgrade is available to buy: either it needs no schematic or its
+    /// schematic has been found this run
+    pub fn schematic_known(&self, template: &UpgradeTemplate) -> bool {
+        !template.requires_schematic || self.discovered_schematics.contains(&template.id)
+    }
+
+    /// How many ship improvements can be bought between rounds: a base set plus one
+    /// per repaired Laboratory point
+    pub fn unlocked_upgrade_count(&self) -> usize {
+        let lab_repaired_count: usize = self.interior.rooms.iter()
+            .filter(|r| r.room_type == RoomType::Laboratory)
+            .map(|r| r.repaired_count())
+            .sum();
+        UPGRADE_BASE_UNLOCK_COUNT + lab_repaired_count
+    }
+
+    /// Apply permanent upgrades bought with earlier runs' credits to a fresh run
+    fn apply_profile_bonuses(&mut self) {
+        self.resources.add_scrap(PERMANENT_SCRAP_BONUS * self.run_permanent_level("starting_scrap") as i32);
+
+        let hull_bonus = HULL_UPGRADE_BONUS * self.run_permanent_level("hull_bonus") as f32;
+        self.ship_max_integrity += hull_bonus;
+        self.ship_integrity += hull_bonus;
+
+        self.refresh_repair_discount();
+    }
+
+    /// Recompute the repair cost multiplier from the profile's "repair_discount" level
+    pub fn refresh_repair_discount(&mut self) {
+        let level = self.run_permanent_level("repair_discount") as f32;
+        self.repair_cost_multiplier = (1.0 - REPAIR_DISCOUNT_PER_LEVEL * level).max(0.0);
+    }
+
+    /// Permanent upgrade level that applies to this run; daily challenges ignore them all
+    fn run_permanent_level(&self, id: &str) -> u32 {
+        if self.daily_date.is_some() { 0 } else { self.profile.permanent_level(id) }
+    }
+
+    /// Hull the current run flies; daily challenges always use the starter ship
+    pub fn run_ship_path(&self) -> &str {
+        if self.daily_date.is_some() { DEFAULT_SHIP_PATH } else { &self.selected_ship_path }
+    }
+
+    /// Save the profile whenever the phase changes so progress is never lost
+    pub fn sync_phase(&mut self) {
+        if self.phase != self.last_phase {
+            self.last_phase = self.phase;
+            let _ = self.profile.save();
+        }
+    }
+
+    /// Keep screen-space entities aligned with the ship grid, which is centered in the window
+    pub fn on_layout_changed(&mut self, old_size: Vec2, new_size: Vec2) {
+        let shift = (new_size - old_size) / 2.0;
+        for enemy in &mut self.enemies {
+            enemy.position += shift;
+        }
+        for projectile in &mut self.projectiles {
+            projectile.position += shift;
+        }
+        for particle in self.particle_pool.slots.iter_mut().filter(|p| p.active) {
+            particle.position += shift;
+        }
+        for number in &mut self.damage_numbers {
+            number.position += shift;
+        }
+        self.repair_drone_pos += shift;
+    }
+
+    /// Play the ship's departure before the upgrade screen
+    pub fn start_victory_animation(&mut self) {
+        self.phase = GamePhase::VictoryAnimation;
+        self.victory_anim_timer = VICTORY_ANIMATION_DURATION;
+        self.victory_thrust_timer = 0.0;
+        self.particle_pool.clear();
+    }
+
+    /// How far the ship has climbed during the victory animation, in pixels
+    pub fn victory_lift(&self) -> f32 {
+        let progress = 1.0 - self.victory_anim_timer.max(0.0) / VICTORY_ANIMATION_DURATION;
+        progress * screen_height()
+    }
+
+    /// Mark every Siege Construct route stale so it is recomputed against the new module
+    /// layout. The old route is kept until the recompute budget gets to it.
+    pub fn invalidate_siege_paths(&mut self) {
+        for enemy in self.enemies.iter_mut().filter(|e| e.enemy_type == EnemyType::SiegeConstruct) {
+            enemy.pathfinding_dirty = true;
+        }
+    }
+
+    /// Lay out the hull cracks for this run. They only depend on the run seed, so a
+    /// reloaded run cracks in the same places.
+    pub fn refresh_hull_cracks(&mut self) {
+        let crack_count = HULL_CRACK_THRESHOLDS.len() * HULL_CRACKS_PER_STAGE;
+        let values = rng::unit_values_seeded(self.run_seed ^ 0x4855_4C4C, crack_count * (1 + HULL_CRACK_SEGMENTS * 2));
+        let mut values = values.into_iter();
+        let mut next = || values.next().unwrap_or(0.5);
+
+        self.hull_crack_positions.clear();
+        for _ in 0..crack_count {
+            // Start somewhere on the border, heading inwards
+            let t = next() * 4.0;
+            let (mut point, inward) = match t as u32 {
+                0 => (vec2(t.fract(), 0.0), vec2(0.0, 1.0)),
+                1 => (vec2(1.0, t.fract()), vec2(-1.0, 0.0)),
+                2 => (vec2(1.0 - t.fract(), 1.0), vec2(0.0, -1.0)),
+                _ => (vec2(0.0, 1.0 - t.fract()), vec2(1.0, 0.0)),
+            };
+            for _ in 0..HULL_CRACK_SEGMENTS {
+                let angle = (next() - 0.5) * 1.6;
+                let length = 0.02 + next() * 0.05;
+                let end = point + Vec2::from_angle(angle).rotate(inward) * length;
+                self.hull_crack_positions.push((point, end));
+                point = end;
+            }
+        }
+    }
+
+    /// Virtual D-pad and interact button: interior view while playing, once the player
+    /// has touched the screen or turned on touch controls
+    pub fn touch_buttons_visible(&self) -> bool {
+        self.phase == GamePhase::Playing && !self.paused && self.view_mode == ViewMode::Interior
+            && (self.settings.touch_mode || !touches().is_empty())
+    }
+
+    /// Large particle burst for a Siege Construct wreck
+    pub fn spawn_explosion(&mut self, pos: Vec2) {
+        for i in 0..SIEGE_EXPLOSION_PARTICLES {
+            let angle = rand::gen_range(0.0, std::f32::consts::TAU);
+            let speed = rand::gen_range(60.0, 220.0);
+            let color = if i % 3 == 0 { YELLOW } else { ORANGE };
+            self.particle_pool.spawn(pos, vec2(angle.cos(), angle.sin()) * speed, rand::gen_range(0.4, 0.9), color, ParticleKind::Explosion);
+        }
+    }
+
+    /// Recompute module adjacency bonuses after the ship layout or module states change
+    pub fn refresh_synergies(&mut self) {
+        self.synergies = calculate_synergies(&self.ship);
+    }
+
+    /// Re-read the header of every save slot for the slot select screen
+    pub fn refresh_slot_summaries(&mut self) {
+        self.slot_summaries = (0..MAX_SAVE_SLOTS).map(Self::read_slot_summary).collect();
+    }
+
+    /// Unlock an achievement (no-op if already unlocked) and queue its popup
+    pub fn unlock_achievement(&mut self, id: &str) {
+        if self.profile.is_unlocked(id) { return; }
+        let Some(def) = self.achievement_config.get(id) else { return };
+
+        self.profile.achievements.insert(id.to_string(), true);
+        self.achievement_notifications.push(AchievementNotification::new(def.name.clone()));
+        let _ = self.profile.save();
+    }
+
+    /// Unlock every achievement whose condition is now met
+    pub fn check_achievements(&mut self) {
+        let newly_met: Vec<String> = self.achievement_config.achievements.iter()
+            .filter(|a| !self.profile.is_unlocked(&a.id))
+            .filter(|a| a.condition.is_met(&self.profile, self.time_survived))
+            .map(|a| a.id.clone())
+            .collect();
+        for id in newly_met {
+            self.unlock_achievement(&id);
+        }
+    }
+
+    /// Show a short status message at the top of the screen
+    pub fn show_toast(&mut self, message: String) {
+        self.show_toast_for(message, TOAST_DURATION);
+    }
+
+    pub fn show_toast_for(&mut self, message: String, seconds: f32) {
+        self.toast = Some(message);
+        self.toast_timer = seconds;
+    }
+
+    /// Record an event in the ship log, dropping the oldest entry once full
+    pub fn log_event(&mut self, message: String, kind: LogKind) {
+        self.ship_log.push(LogEntry { message, time: self.time_survived, kind });
+        if self.ship_log.len() > SHIP_LOG_CAPACITY {
+            let excess = self.ship_log.len() - SHIP_LOG_CAPACITY;
+            self.ship_log.drain(..excess);
+        }
+    }
+
+    /// Float a random quip for the event above `position`, replacing the oldest
+    /// popup once FLAVOR_POPUP_MAX are showing
+    pub fn spawn_flavor_popup(&mut self, kind: FlavorKind, position: Vec2, view: ViewMode) {
+        let Some(text) = self.flavor_texts.pick(kind) else { return };
+        self.show_popup(text.to_string(), position, view);
+    }
+
+    /// Float a specific line above `position`, with the same cap as flavor quips
+    pub fn show_popup(&mut self, text: String, position: Vec2, view: ViewMode) {
+        let popup = FlavorPopup { text, position, timer: FLAVOR_POPUP_DURATION, view };
+        if self.flavor_popups.len() >= FLAVOR_POPUP_MAX {
+            self.flavor_popups.remove(0);
+        }
+        self.flavor_popups.push(popup);
+    }
+
+    /// Save to the auto-save slot; called when entering GameOver, Victory or InterRound
+    pub fn auto_save(&mut self) {
+        if self.ironman { return; }
+        match self.save_to_slot(AUTO_SAVE_SLOT) {
+            Ok(()) => self.show_toast_for("AUTO-SAVED".to_string(), AUTO_SAVE_TOAST_DURATION),
+            Err(e) => self.show_toast(format!("Auto-save failed: {}", e)),
+        }
+    }
+
+    /// Ironman: erase every save slot holding this run
+    pub fn delete_run_saves(&self) {
+        for slot in (0..MAX_SAVE_SLOTS).chain([AUTO_SAVE_SLOT]) {
+            if Self::read_slot_summary(slot).is_some_and(|s| s.run_seed == self.run_seed) {
+                Self::delete_slot(slot);
+            }
+        }
+    }
+
     pub fn spawn_scrap_piles(&mut self) {
-        use macroquad::rand::ChooseRandom;
-        let count = macroquad::rand::gen_range(MIN_SCRAP_PILES, MAX_SCRAP_PILES + 1);
+        let count = rng::gen_range(MIN_SCRAP_PILES, MAX_SCRAP_PILES + 1);
         for _ in 0..count {
-            if let Some(room) = self.interior.rooms.choose() {
+            if let Some(room) = rng::choose(&self.interior.rooms) {
                 if room.room_type == RoomType::Empty { continue; }
-                let w = room.width - SCRAP_SPAWN_PADDING * 2.0;
-                let h = room.height - SCRAP_SPAWN_PADDING * 2.0;
-                let x = room.x + SCRAP_SPAWN_PADDING + macroquad::rand::gen_range(0.0, w);
-                let y = room.y + SCRAP_SPAWN_PADDING + macroquad::rand::gen_range(0.0, h);
-                let amount = macroquad::rand::gen_range(SCRAP_PILE_MIN_AMOUNT, SCRAP_PILE_MAX_AMOUNT + 1);
-                self.scrap_piles.push(ScrapPile::new(vec2(x, y), amount));
+                let mut pile = random_scrap_pile(room);
+                if rng::gen_range(0.0, 1.0) < SCHEMATIC_PILE_CHANCE {
+                    pile.contains_schematic = self.unclaimed_schematic();
+                }
+                self.scrap_piles.push(pile);
             }
         }
     }
+
+    /// A random schematic upgrade that is neither discovered nor already hidden in a pile
+    fn unclaimed_schematic(&self) -> Option<String> {
+        let ids: Vec<&String> = self.upgrade_templates.iter()
+            .filter(|t| t.requires_schematic && !self.discovered_schematics.contains(&t.id))
+            .map(|t| &t.id)
+            .filter(|id| !self.scrap_piles.iter().any(|p| p.contains_schematic.as_ref() == Some(*id)))
+            .collect();
+        rng::choose(&ids).map(|id| (*id).clone())
+    }
+
+    /// Top up scrap in rooms that have been picked clean, up to MAX_SCRAP_PILES active piles.
+    /// Returns how many piles were added.
+    pub fn spawn_scrap_piles_incremental(&mut self) -> usize {
+        let active = self.scrap_piles.iter().filter(|p| p.active).count();
+        let room_limit = MAX_SCRAP_PILES.saturating_sub(active);
+        let wanted = rng::gen_range(SCRAP_RESPAWN_MIN_PILES, SCRAP_RESPAWN_MAX_PILES + 1).min(room_limit);
+
+        let mut empty_rooms: Vec<usize> = self.interior.rooms.iter().enumerate()
+            .filter(|(_, room)| room.room_type != RoomType::Empty)
+            .filter(|(_, room)| !self.scrap_piles.iter().any(|p| p.active && room.contains(p.position)))
+            .map(|(i, _)| i)
+            .collect();
+        rng::shuffle(&mut empty_rooms);
+
+        let mut spawned = 0;
+        for room_idx in empty_rooms.into_iter().take(wanted) {
+            let pile = random_scrap_pile(&self.interior.rooms[room_idx]);
+            self.scrap_piles.push(pile);
+            spawned += 1;
+        }
+        spawned
+    }
+}
+
+/// A pile with a random amount at a random spot inside the room
+fn random_scrap_pile(room: &Room) -> ScrapPile {
+    let w = room.width - SCRAP_SPAWN_PADDING * 2.0;
+    let h = room.height - SCRAP_SPAWN_PADDING * 2.0;
+    let x = room.x + SCRAP_SPAWN_PADDING + rng::gen_range(0.0, w);
+    let y = room.y + SCRAP_SPAWN_PADDING + rng::gen_range(0.0, h);
+    let amount = rng::gen_range(SCRAP_PILE_MIN_AMOUNT, SCRAP_PILE_MAX_AMOUNT + 1);
+    ScrapPile::new(vec2(x, y), amount)
 }
 
@@ -4,46 +4,146 @@
 
 use crate::state::game_state::{GameState, GamePhase, EngineState, ViewMode};
 use crate::ship::ship::{ModuleType, ModuleState};
-use crate::ship::interior::RoomType;
+use crate::ship::interior::{RoomEventKind, RoomEventTrigger, RoomType};
 use crate::simulation::events::{EventBus, GameEvent};
 use crate::simulation::constants::*;
+use crate::ship::layout::Layout;
+use crate::simulation::environment::{EnvironmentEvent, EnvEventKind};
+use crate::simulation::rng;
+use crate::enemy::entities::{InteriorEnemy, ParticleKind};
+use crate::state::LogKind;
+use macroquad::prelude::{rand, screen_height, screen_width, vec2, Color, SKYBLUE};
 
 impl GameState {
     pub fn update(&mut self, dt: f32, events: &mut EventBus) {
+        if self.toast.is_some() {
+            self.toast_timer -= dt;
+            if self.toast_timer <= 0.0 {
+                self.toast = None;
+            }
+        }
+
+        if self.sell_confirm.is_some() {
+            self.sell_confirm_timer -= dt;
+            if self.sell_confirm_timer <= 0.0 {
+                self.sell_confirm = None;
+            }
+        }
+
+        for notification in &mut self.achievement_notifications {
+            notification.timer -= dt;
+        }
+        self.achievement_notifications.retain(|n| n.timer > 0.0);
+
+        for popup in &mut self.flavor_popups {
+            popup.timer -= dt;
+        }
+        self.flavor_popups.retain(|p| p.timer > 0.0);
+
         match self.phase {
             GamePhase::Playing => {
                 if !self.paused {
                     if self.view_mode == ViewMode::Interior {
-                        self.player.update(dt, &self.interior);
+                        self.player.update(dt, &self.interior, self.gamepad_move, self.sprint_held, &self.settings.key_bindings);
                         self.player.update_nearby_module(&self.interior);
+                        self.update_room_events(events);
                     }
+                    let tier_before = crate::enemy::ai::wave_tier(self.total_power);
                     self.update_power();
+                    let tier = crate::enemy::ai::wave_tier(self.total_power);
+                    if tier > tier_before {
+                        events.push_game(GameEvent::WaveTierChanged { new_tier: tier });
+                    }
                     self.update_resources();
                     self.update_engine(dt, events);
                     crate::enemy::ai::update_wave_logic(
                         self.total_power,
                         &self.engine_state,
                         &mut self.enemies,
+                        &mut self.spawners,
                         &self.upgrades,
                         &mut self.wave_state,
+                        self.enemy_scaling(),
                         self.frame_count,
                         dt,
                         events
                     );
-                    crate::enemy::ai::update_enemies(self, dt);
+                    crate::enemy::ai::update_enemies(self, dt, events);
                     crate::enemy::combat::update_combat(self, dt, events);
+                    self.particle_pool.update(dt);
+                    self.update_hull_smoke(dt);
+                    crate::simulation::effects::update_damage_numbers(&mut self.damage_numbers, dt);
                     self.frame_count += 1;
                     self.time_survived += dt;
+                    self.update_wave_number();
 
-                    self.update_auto_repair(dt);
+                    self.update_auto_repair(dt, events);
+                    self.update_module_angles(dt);
+                    self.update_overcharge(events);
+                    self.update_fire(dt);
+                    self.update_interior_enemies(dt);
+                    self.update_environment_events(dt, events);
+                    self.update_scrap_respawn(dt, events);
+                    self.update_shop_restock();
+                    self.update_medbay(dt);
+                    self.check_achievements();
                     self.check_game_over(events);
                 }
             }
+            GamePhase::VictoryAnimation => self.update_victory_animation(dt, events),
             _ => {}
         }
     }
 
-    fn update_auto_repair(&mut self, dt: f32) {
+    /// Advance the wave counter with the run clock and announce each new wave
+    fn update_wave_number(&mut self) {
+        let previous = self.wave_state.wave_number;
+        if self.wave_state.update_wave_number(self.time_survived) && self.wave_state.wave_number == previous + 1 {
+            let message = format!("WAVE {} INCOMING", self.wave_state.wave_number + 1);
+            self.show_toast_for(message, WAVE_TOAST_DURATION);
+        }
+    }
+
+    /// Ease each turret toward its target, turning the short way around
+    fn update_module_angles(&mut self, dt: f32) {
+        use std::f32::consts::{PI, TAU};
+        let t = (TURRET_TURN_SPEED * dt).min(1.0);
+        for module in self.ship.grid.iter_mut().flatten().flatten() {
+            if module.module_type != ModuleType::Weapon { continue; }
+            let mut diff = module.target_angle - module.turret_angle;
+            if diff.abs() >= PI {
+                diff -= TAU * diff.signum();
+            }
+            module.turret_angle = (module.turret_angle + diff * t).rem_euclid(TAU);
+        }
+    }
+
+    /// Burn out modules whose overcharge has run its course
+    fn update_overcharge(&mut self, events: &mut EventBus) {
+        for x in 0..GRID_WIDTH {
+            for y in 0..GRID_HEIGHT {
+                let Some(module) = &mut self.ship.grid[x][y] else { continue };
+                if module.overcharged_until <= 0.0 || module.is_overcharged(self.time_survived) { continue; }
+
+                module.overcharged_until = 0.0;
+                if module.state == ModuleState::Destroyed { continue; }
+                module.health -= OVERCHARGE_DAMAGE;
+                events.push_game(GameEvent::ModuleDamaged { x, y, damage: OVERCHARGE_DAMAGE });
+                if module.health <= 0.0 {
+                    module.health = 0.0;
+                    module.state = ModuleState::Destroyed;
+                    if let Some(room) = self.interior.rooms.iter_mut().find(|r| r.module_index == Some((x, y))) {
+                        for point in &mut room.repair_points {
+                            point.repaired = false;
+                        }
+                    }
+                    events.push_game(GameEvent::ModuleDestroyed { x, y });
+                }
+            }
+        }
+    }
+
+    fn update_auto_repair(&mut self, dt: f32, events: &mut EventBus) {
         let robotics_level = self.upgrades.get_level("auto_repairs");
         self.repair_timer += dt;
         if self.repair_timer >= NANO_REPAIR_INTERVAL_SECONDS {
@@ -59,48 +159,415 @@ impl GameState {
                 }
             }
         }
+
+        if robotics_level >= DRONE_REPAIR_MIN_LEVEL {
+            self.update_repair_drone(robotics_level, dt, events);
+        }
+    }
+
+    /// Repair drone: flies to the destroyed module closest to the ship center and rebuilds it
+    fn update_repair_drone(&mut self, robotics_level: u32, dt: f32, events: &mut EventBus) {
+        let (cx, cy) = (GRID_WIDTH / 2, GRID_HEIGHT / 2);
+        let target = (0..GRID_WIDTH)
+            .flat_map(|x| (0..GRID_HEIGHT).map(move |y| (x, y)))
+            .filter(|&(x, y)| self.ship.grid[x][y].as_ref().is_some_and(|m| m.state == ModuleState::Destroyed))
+            .min_by_key(|&(x, y)| x.abs_diff(cx) + y.abs_diff(cy));
+
+        if target != self.repair_drone_target {
+            self.repair_drone_target = target;
+            self.repair_progress = 0.0;
+        }
+        let Some((tx, ty)) = target else { return };
+
+        // Travel to the module; only repair once there
+        let dest = Layout::grid_to_screen_center(tx, ty);
+        let to_dest = dest - self.repair_drone_pos;
+        let step = DRONE_SPEED * dt;
+        if to_dest.length() > step {
+            self.repair_drone_pos += to_dest.normalize() * step;
+        } else {
+            self.repair_drone_pos = dest;
+            self.repair_progress += robotics_level as f32 * DRONE_REPAIR_RATE_PER_LEVEL * dt;
+        }
+
+        // Particle trail stands in for a drone sprite
+        let jitter = vec2(macroquad::rand::gen_range(-15.0, 15.0), macroquad::rand::gen_range(-15.0, 15.0));
+        self.particle_pool.spawn(self.repair_drone_pos, jitter, 0.3, SKYBLUE, ParticleKind::Spark);
+
+        if self.repair_progress >= DRONE_REPAIR_THRESHOLD {
+            self.repair_progress = 0.0;
+            self.repair_drone_target = None;
+            if let Some(module) = &mut self.ship.grid[tx][ty] {
+                module.state = ModuleState::Active;
+                module.seal_breach();
+                module.health = module.max_health;
+            }
+            // Keep the interior in sync so the room counts as repaired for power
+            if let Some(room) = self.interior.rooms.iter_mut().find(|r| r.module_index == Some((tx, ty))) {
+                for point in &mut room.repair_points {
+                    point.repaired = true;
+                }
+            }
+            events.push_game(GameEvent::ModuleRepaired { x: tx, y: ty, cost: 0 });
+        }
+    }
+
+    /// Add a few scrap piles every SCRAP_RESPAWN_INTERVAL seconds
+    fn update_scrap_respawn(&mut self, dt: f32, events: &mut EventBus) {
+        self.scrap_respawn_timer += dt;
+        if self.scrap_respawn_timer < SCRAP_RESPAWN_INTERVAL { return; }
+        self.scrap_respawn_timer = 0.0;
+
+        let count = self.spawn_scrap_piles_incremental();
+        if count > 0 {
+            events.push_game(GameEvent::ScrapRespawned { count });
+        }
+    }
+
+    /// Restock the Cockpit shop every IN_RUN_SHOP_REFRESH_INTERVAL seconds survived,
+    /// up to IN_RUN_SHOP_REFRESHES times a round
+    fn update_shop_restock(&mut self) {
+        if self.shop_refreshes_remaining == 0 { return; }
+        let restocks_done = IN_RUN_SHOP_REFRESHES - self.shop_refreshes_remaining;
+        let next_restock = (restocks_done + 1) as f32 * IN_RUN_SHOP_REFRESH_INTERVAL;
+        if self.time_survived < next_restock { return; }
+
+        self.shop_refreshes_remaining -= 1;
+        self.roll_shop_offers();
+        self.show_toast("Cockpit shop restocked".to_string());
+    }
+
+    /// Spawn a random room event every ENV_EVENT_INTERVAL seconds and tick active ones
+    fn update_environment_events(&mut self, dt: f32, events: &mut EventBus) {
+        self.event_spawn_timer += dt;
+        if self.event_spawn_timer >= ENV_EVENT_INTERVAL {
+            self.event_spawn_timer = 0.0;
+            self.spawn_environment_event(events);
+        }
+
+        for event in &mut self.environment_events {
+            match event.kind {
+                EnvEventKind::PowerSurge => event.timer -= dt,
+                EnvEventKind::HullBreach => {
+                    // Holding interact in the room stops the leak and eventually seals it
+                    if event.sealing {
+                        event.seal_progress += dt;
+                        if event.seal_progress >= ENV_BREACH_SEAL_TIME {
+                            event.timer = 0.0;
+                        }
+                    } else {
+                        self.ship_integrity -= ENV_BREACH_DAMAGE_PER_SEC * dt;
+                        event.timer -= dt;
+                    }
+                    event.sealing = false;
+                }
+            }
+        }
+
+        // Surged modules come back online when their event ends
+        for event in self.environment_events.iter().filter(|e| e.timer <= 0.0 && e.kind == EnvEventKind::PowerSurge) {
+            let cell = self.interior.rooms.iter().find(|r| r.id == event.room_id).and_then(|r| r.module_index);
+            if let Some(module) = cell.and_then(|(x, y)| self.ship.grid[x][y].as_mut()) {
+                if module.state == ModuleState::Offline {
+                    module.state = ModuleState::Active;
+                }
+            }
+        }
+        self.environment_events.retain(|e| e.timer > 0.0);
+    }
+
+    fn spawn_environment_event(&mut self, events: &mut EventBus) {
+
+        let busy = |id: usize| self.environment_events.iter().any(|e| e.room_id == id);
+        let kind = if rng::gen_range(0, 2) == 0 { EnvEventKind::PowerSurge } else { EnvEventKind::HullBreach };
+
+        let room_id = match kind {
+            // Only a working module can be knocked offline
+            EnvEventKind::PowerSurge => {
+                let candidates: Vec<(usize, (usize, usize))> = self.interior.rooms.iter()
+                    .filter(|r| !busy(r.id) && r.repaired_count() > 0)
+                    .filter_map(|r| r.module_index.map(|cell| (r.id, cell)))
+                    .filter(|(_, (x, y))| self.ship.grid[*x][*y].as_ref().is_some_and(|m| m.state == ModuleState::Active))
+                    .collect();
+                let Some(&(id, (x, y))) = rng::choose(&candidates) else { return };
+                if let Some(module) = self.ship.grid[x][y].as_mut() {
+                    module.state = ModuleState::Offline;
+                }
+                id
+            }
+            EnvEventKind::HullBreach => {
+                let candidates: Vec<usize> = self.interior.rooms.iter()
+                    .filter(|r| r.room_type != RoomType::Empty && !busy(r.id))
+                    .map(|r| r.id)
+                    .collect();
+                let Some(&id) = rng::choose(&candidates) else { return };
+                id
+            }
+        };
+
+        self.environment_events.push(EnvironmentEvent::new(kind, room_id));
+        events.push_game(GameEvent::EnvironmentEventStarted { kind, room_id });
+    }
+
+    /// Called when a module is destroyed: its room may catch fire
+    pub fn on_module_destroyed(&mut self, x: usize, y: usize) {
+        if rng::gen_range(0.0, 1.0) >= FIRE_IGNITE_CHANCE { return; }
+        if let Some(room) = self.interior.rooms.iter_mut().find(|r| r.module_index == Some((x, y))) {
+            room.fire_level = room.fire_level.max(FIRE_START_LEVEL);
+        }
+    }
+
+    /// Grow, spread and apply damage from room fires
+    fn update_fire(&mut self, dt: f32) {
+        if !self.interior.any_burning() { return; }
+
+        // Work out every room's change first so spread doesn't depend on room order
+        let rooms = &self.interior.rooms;
+        let deltas: Vec<f32> = rooms.iter().map(|room| {
+            let spread: f32 = room.connections.iter()
+                .filter_map(|id| rooms.iter().find(|r| r.id == *id))
+                .map(|neighbour| neighbour.fire_level)
+                .sum::<f32>() * FIRE_SPREAD_RATE;
+
+            let own = if !room.is_burning() {
+                0.0
+            } else if room.is_fully_repaired() {
+                -FIRE_DECAY_RATE
+            } else {
+                FIRE_GROWTH_RATE
+            };
+            (own + spread) * dt
+        }).collect();
+
+        for (room, delta) in self.interior.rooms.iter_mut().zip(deltas) {
+            room.fire_level = (room.fire_level + delta).clamp(0.0, 1.0);
+        }
+
+        let total_fire: f32 = self.interior.rooms.iter().map(|r| r.fire_level).sum();
+        self.ship_integrity -= total_fire * FIRE_HULL_DAMAGE_PER_SEC * dt;
+
+        if let Some(room) = self.interior.room_at(self.player.position) {
+            if room.is_burning() {
+                self.player_health = (self.player_health - FIRE_PLAYER_DAMAGE_PER_SEC * dt).max(0.0);
+            }
+        }
+    }
+
+    /// Boarders chase the player and drain health on contact. Standing on a repaired
+    /// weapon room repair point turns that turret inward on every boarder in range.
+    /// Boarders only move and bite while the player is inside to fight back
+    fn update_interior_enemies(&mut self, dt: f32) {
+        if self.interior_enemies.is_empty() || self.view_mode != ViewMode::Interior { return; }
+
+        let player_pos = self.player.position;
+        for invader in &mut self.interior_enemies {
+            let step = (player_pos - invader.position).normalize_or_zero() * invader.speed * dt;
+            let next = invader.position + step;
+            if self.interior.is_walkable(next) {
+                invader.position = next;
+            } else if self.interior.is_walkable(vec2(next.x, invader.position.y)) {
+                invader.position.x = next.x;
+            } else if self.interior.is_walkable(vec2(invader.position.x, next.y)) {
+                invader.position.y = next.y;
+            }
+
+            if invader.position.distance(player_pos) < INVADER_CONTACT_RADIUS {
+                self.player_health = (self.player_health - INVADER_DAMAGE_PER_SEC * dt).max(0.0);
+            }
+        }
+
+        let manning_turret = self.interior.room_at(player_pos).is_some_and(|room| {
+            room.room_type == RoomType::Module(ModuleType::Weapon)
+                && room.repair_point_at(player_pos).is_some_and(|i| room.repair_points[i].repaired)
+        });
+        if manning_turret {
+            for invader in &mut self.interior_enemies {
+                if invader.position.distance(player_pos) < INVADER_TURRET_RANGE {
+                    invader.health -= INVADER_TURRET_DPS * dt;
+                }
+            }
+            self.interior_enemies.retain(|e| e.health > 0.0);
+        }
+    }
+
+    /// Fire a room's scripted event (from the ship JSON) the first time the player walks in
+    fn update_room_events(&mut self, events: &mut EventBus) {
+        let Some(room) = self.interior.room_at(self.player.position) else { return };
+        let Some(event) = room.event.clone() else { return };
+        if event.trigger != RoomEventTrigger::FirstEntry || !self.room_event_triggered.insert(room.id) { return; }
+        let connections = room.connections.clone();
+
+        let pos = self.player.position;
+        match event.kind {
+            RoomEventKind::FlavorText => {}
+            RoomEventKind::GiveScrap => {
+                self.resources.add_scrap(event.amount);
+                self.run_stats.scrap_gathered += event.amount;
+                if event.message.is_empty() {
+                    self.show_popup(format!("+{} scrap", event.amount), pos, ViewMode::Interior);
+                }
+            }
+            RoomEventKind::SpawnEnemyNearby => {
+                let next = connections.iter().find_map(|id| self.interior.rooms.iter().find(|r| r.id == *id));
+                if let Some(next) = next {
+                    let (center, next_id) = (next.center(), next.id);
+                    self.interior_enemies.push(InteriorEnemy::new(center));
+                    events.push_game(GameEvent::InteriorThreat { room_id: next_id });
+                }
+            }
+            RoomEventKind::TutorialHint => {
+                if !self.tutorial_state.is_complete() {
+                    self.tutorial_state.advance(&self.tutorial_config);
+                }
+            }
+        }
+        if !event.message.is_empty() {
+            self.show_popup(event.message.clone(), pos, ViewMode::Interior);
+            self.log_event(event.message, LogKind::Info);
+        }
+    }
+
+    /// Standing in a repaired Medbay heals the player. Healing scales with the square
+    /// root of the repaired fraction, so the first points already help a fair amount.
+    fn update_medbay(&mut self, dt: f32) {
+        if self.view_mode != ViewMode::Interior { return; }
+        if self.player_health >= self.player_max_health { return; }
+
+        let Some(room) = self.interior.room_at(self.player.position) else { return };
+        if room.room_type != RoomType::Medbay { return; }
+
+        let repaired = room.repaired_count();
+        if repaired == 0 { return; }
+        let repair_pct = repaired as f32 / room.repair_points.len() as f32;
+
+        self.player_health = (self.player_health + MEDBAY_HEAL_RATE * repair_pct.sqrt() * dt)
+            .min(self.player_max_health);
     }
 
     pub(crate) fn update_power(&mut self) {
         self.total_power = 0;
         self.used_power = 0;
+        let mut cockpits = self.interior.rooms.iter().filter(|r| r.room_type == RoomType::Cockpit).peekable();
+        self.cockpit_fully_repaired = cockpits.peek().is_some() && cockpits.all(|r| r.is_fully_repaired());
         for room in &self.interior.rooms {
             if room.repair_points.is_empty() { continue; }
-            let repaired = room.repaired_count() as i32;
-            if repaired > 0 {
+            // An offline module neither draws nor generates power
+            if room.module_index.is_some_and(|cell| self.ship.is_offline(cell)) { continue; }
+            // A point being repaired counts for its fraction of progress
+            let repaired = room.repair_amount();
+            if repaired > 0.0 {
+                let power = |per_point: i32| (repaired * per_point as f32).round() as i32;
                 match room.room_type {
-                    RoomType::Module(ModuleType::Core) => self.total_power += repaired * POWER_PER_CORE_POINT,
-                    RoomType::Module(ModuleType::Weapon) => self.used_power += repaired * POWER_COST_WEAPON,
-                    RoomType::Module(ModuleType::Defense) => self.used_power += repaired * POWER_COST_DEFENSE,
-                    RoomType::Module(ModuleType::Utility) => self.used_power += repaired * POWER_COST_UTILITY,
-                    RoomType::Module(ModuleType::Engine) => self.used_power += repaired * POWER_COST_ENGINE,
-                    RoomType::Cockpit => self.used_power += repaired * POWER_COST_COCKPIT,
-                    RoomType::Medbay => self.used_power += repaired * POWER_COST_MEDBAY,
+                    RoomType::Module(ModuleType::Core) => self.total_power += power(POWER_PER_CORE_POINT),
+                    RoomType::Module(ModuleType::Weapon) => self.used_power += power(POWER_COST_WEAPON),
+                    RoomType::Module(ModuleType::Defense) => self.used_power += power(POWER_COST_DEFENSE),
+                    RoomType::Module(ModuleType::Utility) => self.used_power += power(POWER_COST_UTILITY),
+                    RoomType::Module(ModuleType::Engine) => self.used_power += power(POWER_COST_ENGINE),
+                    RoomType::Module(ModuleType::DroneBay) => self.used_power += power(POWER_COST_DRONE_BAY),
+                    RoomType::Cockpit => self.used_power += power(POWER_COST_COCKPIT),
+                    RoomType::Medbay => self.used_power += power(POWER_COST_MEDBAY),
                     _ => {}
                 }
             }
         }
     }
 
+    /// Puff smoke from the hull corners once integrity is critical
+    fn update_hull_smoke(&mut self, dt: f32) {
+        if self.ship_max_integrity <= 0.0 || self.ship_integrity / self.ship_max_integrity > HULL_SMOKE_THRESHOLD {
+            return;
+        }
+        self.hull_smoke_timer -= dt;
+        if self.hull_smoke_timer > 0.0 { return; }
+        self.hull_smoke_timer = HULL_SMOKE_INTERVAL;
+
+        // Same rectangle as the hull sprite: the grid plus a 50px margin
+        let (w, h) = (GRID_WIDTH as f32 * CELL_SIZE + 100.0, GRID_HEIGHT as f32 * CELL_SIZE + 100.0);
+        let origin = vec2((screen_width() - w) / 2.0, (screen_height() - h) / 2.0);
+        for corner in [origin, origin + vec2(w, 0.0), origin + vec2(0.0, h), origin + vec2(w, h)] {
+            let velocity = vec2(rand::gen_range(-15.0, 15.0), rand::gen_range(-40.0, -20.0));
+            let gray = rand::gen_range(0.3, 0.5);
+            self.particle_pool.spawn(corner, velocity, rand::gen_range(0.8, 1.4), Color::new(gray, gray, gray, 0.6), ParticleKind::Trail);
+        }
+    }
+
+    /// Fly the ship off the top of the screen with its engines firing, then hand over
+    /// to the upgrade screen
+    fn update_victory_animation(&mut self, dt: f32, events: &mut EventBus) {
+        self.particle_pool.update(dt);
+        if self.victory_anim_timer <= 0.0 {
+            events.push_game(GameEvent::VictoryAnimationComplete);
+            return;
+        }
+        self.victory_anim_timer -= dt;
+
+        self.victory_thrust_timer -= dt;
+        if self.victory_thrust_timer > 0.0 { return; }
+        self.victory_thrust_timer = VICTORY_THRUST_INTERVAL;
+
+        let lift = self.victory_lift();
+        for x in 0..GRID_WIDTH {
+            for y in 0..GRID_HEIGHT {
+                let is_engine = self.ship.grid[x][y].as_ref().is_some_and(|m| m.module_type == ModuleType::Engine);
+                if !is_engine { continue; }
+                let nozzle = Layout::grid_to_screen_center(x, y) + vec2(0.0, CELL_SIZE / 2.0 - lift);
+                for _ in 0..4 {
+                    let velocity = vec2(rand::gen_range(-30.0, 30.0), rand::gen_range(120.0, 220.0));
+                    let color = Color::new(1.0, rand::gen_range(0.4, 0.8), 0.1, 0.9);
+                    self.particle_pool.spawn(nozzle, velocity, rand::gen_range(0.3, 0.6), color, ParticleKind::Trail);
+                }
+            }
+        }
+    }
+
     fn check_game_over(&mut self, events: &mut EventBus) {
-        if self.ship_integrity <= 0.0 {
-            self.ship_integrity = 0.0;
+        if self.ship_integrity <= 0.0 || self.player_health <= 0.0 {
+            self.ship_integrity = self.ship_integrity.max(0.0);
             self.phase = GamePhase::GameOver;
+            self.profile.record_defeat(self.integrity_pct(), self.enemies_killed, &self.run_stats);
+            self.record_daily_result();
+            let _ = self.profile.save();
+            self.auto_save();
+            if self.ironman {
+                self.delete_run_saves();
+            }
             events.push_game(GameEvent::CoreDestroyed);
         }
     }
 
+    /// Hull integrity as a percentage of max (0-100)
+    fn integrity_pct(&self) -> f32 {
+        if self.ship_max_integrity <= 0.0 { return 0.0; }
+        (self.ship_integrity / self.ship_max_integrity * 100.0).clamp(0.0, 100.0)
+    }
+
+    /// Escape credits for keeping enemy attacks off the hull: up to half the base payout
+    fn defense_bonus(&self) -> i32 {
+        if self.ship_max_integrity <= 0.0 { return 0; }
+        let unharmed = (1.0 - self.run_stats.damage_taken / self.ship_max_integrity).max(0.0);
+        (unharmed * BASE_ESCAPE_CREDITS as f32 * DEFENSE_BONUS_FRACTION) as i32
+    }
+
     fn update_resources(&mut self) {
         // Power calculation is handled by update_power() - interior-based system only
     }
 
     fn update_engine(&mut self, dt: f32, events: &mut EventBus) {
         let mut engine_repair_pct = 0.0;
+        let mut engine_boost = 1.0;
         for room in &self.interior.rooms {
             if let RoomType::Module(ModuleType::Engine) = room.room_type {
+                 // A surged or switched-off engine can't hold a charge
+                 if room.module_index.is_some_and(|cell| self.ship.is_offline(cell)) { continue; }
                  if !room.repair_points.is_empty() {
                     engine_repair_pct = room.repaired_count() as f32 / room.repair_points.len() as f32;
                  }
+                 let overcharged = room.module_index
+                    .and_then(|(x, y)| self.ship.grid[x][y].as_ref())
+                    .is_some_and(|m| m.is_overcharged(self.time_survived));
+                 if overcharged {
+                    engine_boost = OVERCHARGE_ENGINE_MULTIPLIER;
+                 }
             }
         }
         // Engine Charging Logic with Hysteresis (Safety Shutdown)
@@ -144,13 +611,21 @@ impl GameState {
                 self.engine_stress += gain * dt;
                 
                 // Original Charging Logic within Charging State
-                self.escape_timer -= dt * engine_repair_pct;
+                self.escape_timer -= dt * engine_repair_pct * engine_boost;
                 if self.escape_timer <= 0.0 {
                     self.engine_state = EngineState::Escaped;
                     self.phase = GamePhase::Victory;
                     let bonus_mult = 1.0 + (self.upgrades.get_level("credit_bonus") as f32 * CREDIT_BONUS_PER_LEVEL);
-                    let total_credits = (BASE_ESCAPE_CREDITS as f32 * bonus_mult) as i32;
+                    let escape_credits = (BASE_ESCAPE_CREDITS as f32 * bonus_mult) as i32;
+                    let defense_bonus = self.defense_bonus();
+                    self.run_stats.escape_credits = escape_credits;
+                    self.run_stats.defense_bonus = defense_bonus;
+                    let total_credits = escape_credits + defense_bonus;
                     self.resources.add_credits(total_credits);
+                    self.profile.record_victory(total_credits, self.time_survived, self.integrity_pct(), self.enemies_killed, &self.run_stats, self.difficulty, self.ironman);
+                    let _ = self.profile.save();
+                    self.check_achievements();
+                    self.auto_save();
                     events.push_game(GameEvent::EscapeSuccess);
                 }
             }
@@ -165,7 +640,8 @@ impl GameState {
              // 2. Spawn Boss + Alert Spike
              let has_boss = self.enemies.iter().any(|e| e.enemy_type == crate::enemy::entities::EnemyType::Boss);
              if !has_boss {
-                 crate::enemy::ai::spawn_boss(&mut self.enemies, events, self.frame_count);
+                 let hp_scale = self.enemy_scaling().hp;
+                 crate::enemy::ai::spawn_boss(&mut self.enemies, events, self.frame_count, hp_scale);
                  self.nanite_alert += 8.0; 
              }
              
@@ -1,15 +1,20 @@
 pub mod game_state;
 mod game_update;     // Update logic (impl GameState)
 mod game_actions;    // Player actions (impl GameState)
-#[cfg(not(target_arch = "wasm32"))]
 mod game_persistence; // Save/load (impl GameState)
 pub mod persistence;
 pub mod tutorial;
 pub mod profile;
+pub mod achievements;
+pub mod codex;
+pub mod ship_log;
+pub mod flavor;
 
-pub use game_state::{GameState, GamePhase, EngineState, ViewMode};
+pub use game_state::{GameState, GamePhase, EngineState, ViewMode, SlotSelectMode, RunStats};
 pub use tutorial::TutorialStep;
 pub use profile::PlayerProfile;
+pub use ship_log::{LogEntry, LogKind};
+pub use flavor::FlavorKind;
 
 use crate::simulation::events::{EventBus, UIEvent};
 
@@ -17,9 +22,16 @@ pub fn process_ui_events(state: &mut GameState, events: &mut EventBus) {
     for event in events.drain_ui() {
         match event {
             UIEvent::StartGame => {
-                state.start_new_game();
+                state.start_run();
+            }
+            UIEvent::StartDailyChallenge => {
+                state.start_daily_challenge();
             }
             UIEvent::ReturnToMenu => {
+                // Quitting a daily challenge banks its result so far
+                state.record_daily_result();
+                state.check_achievements();
+                let _ = state.profile.save();
                 state.paused = false;
                 state.phase = GamePhase::Menu;
             }
@@ -39,44 +51,45 @@ pub fn process_ui_events(state: &mut GameState, events: &mut EventBus) {
             UIEvent::Toggle(x, y) => {
                 state.toggle_module(x, y);
             }
+            UIEvent::SellModule(x, y) => {
+                state.attempt_sell_module(x, y, events);
+            }
+            UIEvent::Overcharge(x, y) => {
+                state.attempt_overcharge(x, y, events);
+            }
             UIEvent::PurchaseUpgrade(id) => {
                 if state.phase == GamePhase::Victory {
-                    state.phase = GamePhase::InterRound;
+                    state.start_victory_animation();
                 } else {
                     state.purchase_upgrade(&id);
                 }
             }
+            UIEvent::PurchasePermanentUpgrade(id) => {
+                state.purchase_permanent_upgrade(&id);
+            }
             UIEvent::NextRound => {
+                state.check_achievements();
+                let _ = state.profile.save();
                 state.start_new_game();
             }
             UIEvent::SaveGame(slot) => {
-                #[cfg(not(target_arch = "wasm32"))]
-                {
-                    if let Err(e) = state.save_to_slot(slot) {
-                        eprintln!("Failed to save: {}", e);
-                    }
-                }
-                #[cfg(target_arch = "wasm32")]
-                {
-                    let _ = slot; // Suppress unused warning
-                    eprintln!("Save not supported in WebGL");
+                match state.save_to_slot(slot) {
+                    Ok(()) => state.show_toast(format!("Saved to slot {}", slot + 1)),
+                    Err(e) => state.show_toast(format!("Save failed: {}", e)),
                 }
                 state.paused = false;
             }
             UIEvent::LoadGame(slot) => {
-                #[cfg(not(target_arch = "wasm32"))]
-                {
-                    if let Ok(loaded) = GameState::load_from_slot(slot) {
+                match GameState::load_from_slot(slot) {
+                    Ok(loaded) => {
                         *state = loaded;
-                    } else {
-                        eprintln!("Failed to load slot {}", slot);
+                        state.show_toast(format!("Loaded slot {}", slot + 1));
                     }
+                    Err(e) => state.show_toast(format!("Load failed: {}", e)),
                 }
-                #[cfg(target_arch = "wasm32")]
-                {
-                    let _ = slot; // Suppress unused warning
-                    eprintln!("Load not supported in WebGL");
-                }
+            }
+            UIEvent::OpenCodex => {
+                state.phase = GamePhase::Codex { selected_entry: 0 };
             }
             UIEvent::ExitGame => {
                 std::process::exit(0);
@@ -1,9 +1,15 @@
 use serde::{Serialize, Deserialize};
+use serde_json::{json, Value};
+use std::collections::HashSet;
 use crate::ship::ship::Ship;
 use crate::economy::resources::Resources;
-use crate::enemy::entities::EnemyType;
+use crate::enemy::entities::{EnemyType, ParticleKind};
 use crate::economy::upgrades::GameUpgrades;
-use super::game_state::{GamePhase, EngineState, ViewMode};
+use crate::economy::items::Item;
+use crate::data::settings::Difficulty;
+use super::game_state::{GamePhase, EngineState, ViewMode, RunStats};
+use super::ship_log::LogEntry;
+use crate::simulation::constants::{CURRENT_SAVE_VERSION, ENGINE_CHARGE_BASE_TIME};
 
 #[derive(Serialize, Deserialize)]
 pub struct SavedEnemy {
@@ -17,6 +23,12 @@ pub struct SavedEnemy {
     pub target: Option<(usize, usize)>,
     pub attached_to: Option<(usize, usize)>, // For Leech attachment
     pub ability_timer: f32,                   // For Boss abilities
+    #[serde(default)]
+    pub drops_remaining: u32,                 // For Carrier drone drops
+    #[serde(default)]
+    pub elite: bool,
+    #[serde(default)]
+    pub is_retreating: bool,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -35,6 +47,21 @@ pub struct SavedParticle {
     pub max_life: f32,
     pub color: (f32, f32, f32, f32),
     pub active: bool,
+    #[serde(default)]
+    pub kind: ParticleKind,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SavedSpawner {
+    pub pos: (f32, f32),
+    pub hp: f32,
+    pub spawn_timer: f32,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SavedInteriorEnemy {
+    pub pos: (f32, f32),
+    pub hp: f32,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -42,10 +69,32 @@ pub struct SavedScrapPile {
     pub pos: (f32, f32),
     pub amount: i32,
     pub active: bool,
+    #[serde(default)]
+    pub contains_schematic: Option<String>,
+}
+
+/// Subset of a save file shown on the slot select screen
+#[derive(Debug, Clone, Deserialize)]
+pub struct SaveSlotSummary {
+    pub time_survived: f32,
+    pub phase: GamePhase,
+    #[serde(default)]
+    pub run_seed: u64,
+    #[serde(default = "legacy_save_version")]
+    pub save_version: u32,
+}
+
+impl SaveSlotSummary {
+    /// Written by an older version; loading it runs the save migrations
+    pub fn is_legacy(&self) -> bool {
+        self.save_version < CURRENT_SAVE_VERSION
+    }
 }
 
 #[derive(Serialize, Deserialize)]
 pub struct SaveData {
+    #[serde(default = "legacy_save_version")]
+    pub save_version: u32,
     pub ship: Ship,
     pub resources: Resources,
     pub phase: GamePhase,
@@ -55,15 +104,133 @@ pub struct SaveData {
     pub projectiles: Vec<SavedProjectile>,
     pub particles: Vec<SavedParticle>,
     pub scrap_piles: Vec<SavedScrapPile>,
+    #[serde(default)]
+    pub spawners: Vec<SavedSpawner>,
+    #[serde(default)]
+    pub spawner_spawned: bool, // This round's spawner already appeared, even if since destroyed
+    #[serde(default)]
+    pub carrier_spawned: bool, // Same for this round's Carrier
+    #[serde(default)]
+    pub interior_enemies: Vec<SavedInteriorEnemy>,
     pub upgrades: GameUpgrades,
     pub frame_count: u64,
     pub time_survived: f32,
+    #[serde(default)]
+    pub run_seed: u64,
+    #[serde(default)]
+    pub enemies_killed: u32,
+    #[serde(default)]
+    pub run_stats: RunStats,
+    #[serde(default)]
+    pub ship_log: Vec<LogEntry>,
+    #[serde(default)]
+    pub room_event_triggered: HashSet<usize>,
+    #[serde(default)]
+    pub discovered_schematics: HashSet<String>,
+    #[serde(default)]
+    pub daily_date: Option<String>,
+    #[serde(default)]
+    pub difficulty: Option<Difficulty>, // Missing in older saves, which used the difficulty setting
+    #[serde(default)]
+    pub veteran_level: Option<u32>, // Missing in older saves, which used the profile's level
+    #[serde(default)]
+    pub ironman: bool,
+    #[serde(default = "default_ship_path")]
+    pub ship_path: String,
     // Interior repair states: room_id -> list of repaired repair point indices
     pub room_repair_states: Vec<Vec<bool>>,
+    #[serde(default)]
+    pub room_fire_levels: Vec<f32>,
+    #[serde(default)]
+    pub room_damage_levels: Vec<f32>,
+    #[serde(default)]
+    pub door_states: Vec<bool>, // Open flag per door, in interior order
+    #[serde(default)]
+    pub shop_offers: Vec<String>,
+    #[serde(default = "default_shop_refreshes")]
+    pub shop_refreshes_remaining: u32,
+    #[serde(default = "default_round")]
+    pub round: u32,
     // Player state
     pub player_pos: (f32, f32),
+    #[serde(default = "default_player_health")]
+    pub player_health: f32,
+    #[serde(default = "default_player_stamina")]
+    pub player_stamina: f32,
     pub view_mode: ViewMode,
+    #[serde(default)]
+    pub inventory: Vec<Item>,
     // Tutorial state
     pub tutorial_index: usize,
     pub tutorial_completed: bool,
 }
+
+fn default_player_health() -> f32 {
+    crate::simulation::constants::PLAYER_MAX_HEALTH
+}
+
+fn default_player_stamina() -> f32 {
+    crate::simulation::constants::PLAYER_MAX_STAMINA
+}
+
+fn default_shop_refreshes() -> u32 {
+    crate::simulation::constants::IN_RUN_SHOP_REFRESHES
+}
+
+fn default_round() -> u32 {
+    1
+}
+
+fn default_ship_path() -> String {
+    crate::ship::interior::DEFAULT_SHIP_PATH.to_string()
+}
+
+/// Saves written before `save_version` existed
+fn legacy_save_version() -> u32 {
+    1
+}
+
+/// Fields every save has carried; without them there is no run to rebuild
+const REQUIRED_SAVE_FIELDS: [&str; 3] = ["ship", "resources", "phase"];
+
+/// Parse a save file, migrating older versions up to CURRENT_SAVE_VERSION first
+pub fn parse_save(json: &str) -> Result<SaveData, String> {
+    let mut data: Value = serde_json::from_str(json).map_err(|e| format!("save is not valid JSON ({})", e))?;
+    let version = data.get("save_version").and_then(Value::as_u64).map_or(legacy_save_version(), |v| v as u32);
+    if version > CURRENT_SAVE_VERSION {
+        return Err(format!("save is from a newer version of the game (v{})", version));
+    }
+    if let Some(field) = REQUIRED_SAVE_FIELDS.iter().find(|f| data.get(**f).is_none()) {
+        return Err(format!("save is too old to upgrade (no \"{}\")", field));
+    }
+
+    if version < 2 {
+        migrate_save_v1_to_v2(&mut data);
+    }
+    serde_json::from_value(data).map_err(|e| format!("save is corrupt ({})", e))
+}
+
+/// v1 saves can predate the interior, tutorial and entity lists; missing entries
+/// start the way a fresh run would
+fn migrate_save_v1_to_v2(data: &mut Value) {
+    let Some(fields) = data.as_object_mut() else { return };
+    let defaults = [
+        ("engine_state", json!("Idle")),
+        ("escape_timer", json!(ENGINE_CHARGE_BASE_TIME)),
+        ("enemies", json!([])),
+        ("projectiles", json!([])),
+        ("particles", json!([])),
+        ("scrap_piles", json!([])),
+        ("frame_count", json!(0)),
+        ("time_survived", json!(0.0)),
+        ("room_repair_states", json!([])),
+        ("player_pos", json!([0.0, 0.0])),
+        ("view_mode", json!("Interior")),
+        ("tutorial_index", json!(0)),
+        ("tutorial_completed", json!(false)),
+    ];
+    for (key, value) in defaults {
+        fields.entry(key).or_insert(value);
+    }
+    fields.insert("save_version".to_string(), json!(2));
+}
@@ -4,9 +4,47 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs::File;
 use std::io::{BufReader, BufWriter};
+use crate::enemy::entities::EnemyType;
+use crate::economy::upgrades::UpgradeTemplate;
+use super::game_state::RunStats;
+use crate::data::settings::Difficulty;
+use crate::simulation::constants::{
+    DIFFICULTY_HP_SCALE_MAX, DIFFICULTY_HP_SCALE_PER_RUN, DIFFICULTY_SPEED_SCALE_MIN,
+    DIFFICULTY_SPEED_SCALE_PER_RUN, IRONMAN_CREDIT_MULTIPLIER,
+};
 
 
 const PROFILE_PATH: &str = "player_profile.json";
+/// Number of escape records kept in the profile
+pub const MAX_RUN_RECORDS: usize = 10;
+
+/// (spawn_interval_mult, hp_mult) for a run started at `level`
+pub fn veteran_multipliers(level: u32) -> (f32, f32) {
+    let level = level as f32;
+    (
+        (1.0 - level * DIFFICULTY_SPEED_SCALE_PER_RUN).max(DIFFICULTY_SPEED_SCALE_MIN),
+        (1.0 + level * DIFFICULTY_HP_SCALE_PER_RUN).min(DIFFICULTY_HP_SCALE_MAX),
+    )
+}
+
+/// One successful escape, for the high score table
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunRecord {
+    pub time_survived: f32,
+    pub credits: i32,
+    pub date: String, // YYYY-MM-DD, empty on WASM
+    pub difficulty: String,
+    #[serde(default)]
+    pub ironman: bool,
+}
+
+/// Result of one day's daily challenge run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DailyRecord {
+    pub date: String, // YYYY-MM-DD, one record per day
+    pub time_survived: f32,
+    pub enemies_killed: u32,
+}
 
 /// Persistent player profile that survives across game runs
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -22,6 +60,47 @@ pub struct PlayerProfile {
     pub runs_completed: u32,
     /// Best escape time in seconds
     pub best_time: Option<f32>,
+    /// Lifetime kills per enemy type
+    #[serde(default)]
+    pub kill_counts: HashMap<EnemyType, u32>,
+    /// Achievement id -> unlocked
+    #[serde(default)]
+    pub achievements: HashMap<String, bool>,
+    /// Highest hull integrity (0-100%) at the end of a run
+    #[serde(default)]
+    pub best_ship_integrity_pct: f32,
+    /// Most enemies killed in a single run
+    #[serde(default)]
+    pub most_enemies_killed: u32,
+    /// Most projectile damage dealt in a single run
+    #[serde(default)]
+    pub best_damage_dealt: f32,
+    /// Most repairs performed in a single run
+    #[serde(default)]
+    pub most_repairs: u32,
+    /// Most scrap gathered in a single run
+    #[serde(default)]
+    pub most_scrap_gathered: i32,
+    /// Fastest escapes, best first (at most MAX_RUN_RECORDS)
+    #[serde(default)]
+    pub run_records: Vec<RunRecord>,
+    /// Daily challenge results, one per date
+    #[serde(default)]
+    pub daily_scores: Vec<DailyRecord>,
+    /// Bests as they were before the last recorded run (for the end screen comparison)
+    #[serde(skip)]
+    pub previous_best: Option<PersonalBest>,
+}
+
+/// Snapshot of the personal best values
+#[derive(Debug, Clone, Copy)]
+pub struct PersonalBest {
+    pub time: Option<f32>,
+    pub ship_integrity_pct: f32,
+    pub enemies_killed: u32,
+    pub damage_dealt: f32,
+    pub repairs_performed: u32,
+    pub scrap_gathered: i32,
 }
 
 impl Default for PlayerProfile {
@@ -32,12 +111,32 @@ impl Default for PlayerProfile {
             permanent_upgrades: HashMap::new(),
             runs_completed: 0,
             best_time: None,
+            kill_counts: HashMap::new(),
+            achievements: HashMap::new(),
+            best_ship_integrity_pct: 0.0,
+            most_enemies_killed: 0,
+            best_damage_dealt: 0.0,
+            most_repairs: 0,
+            most_scrap_gathered: 0,
+            run_records: Vec::new(),
+            daily_scores: Vec::new(),
+            previous_best: None,
         }
     }
 }
 
 
 impl PlayerProfile {
+    /// Veteran level shown on the main menu; every completed escape adds one
+    pub fn veteran_level(&self) -> u32 {
+        self.runs_completed
+    }
+
+    /// Drop back to veteran level 0 (fresh-player enemy scaling)
+    pub fn reset_veteran_level(&mut self) {
+        self.runs_completed = 0;
+    }
+
     /// Load profile from disk, or create default if not found
     pub fn load() -> Self {
         match File::open(PROFILE_PATH) {
@@ -60,8 +159,10 @@ impl PlayerProfile {
         Ok(())
     }
 
-    /// Record a successful escape
-    pub fn record_victory(&mut self, credits_earned: i32, escape_time: f32) {
+    /// Record a successful escape. Ironman runs bank extra credits for the risk.
+    pub fn record_victory(&mut self, credits_earned: i32, escape_time: f32, integrity_pct: f32, enemies_killed: u32, stats: &RunStats, difficulty: Difficulty, ironman: bool) {
+        let credits_earned = if ironman { (credits_earned as f32 * IRONMAN_CREDIT_MULTIPLIER) as i32 } else { credits_earned };
+        self.update_bests(integrity_pct, enemies_killed, stats);
         self.lifetime_credits += credits_earned;
         self.banked_credits += credits_earned;
         self.runs_completed += 1;
@@ -69,6 +170,96 @@ impl PlayerProfile {
         if self.best_time.is_none() || escape_time < self.best_time.unwrap() {
             self.best_time = Some(escape_time);
         }
+
+        self.run_records.push(RunRecord {
+            time_survived: escape_time,
+            credits: credits_earned,
+            date: today(),
+            difficulty: difficulty.label().to_string(),
+            ironman,
+        });
+        self.run_records.sort_by(|a, b| a.time_survived.total_cmp(&b.time_survived));
+        self.run_records.truncate(MAX_RUN_RECORDS);
+    }
+
+    /// Daily challenge result for a date, if that day has been played
+    pub fn daily_record(&self, date: &str) -> Option<&DailyRecord> {
+        self.daily_scores.iter().find(|r| r.date == date)
+    }
+
+    /// Record the day's daily challenge result, replacing the one banked when the run started
+    pub fn record_daily(&mut self, date: &str, time_survived: f32, enemies_killed: u32) {
+        if let Some(record) = self.daily_scores.iter_mut().find(|r| r.date == date) {
+            record.time_survived = time_survived;
+            record.enemies_killed = enemies_killed;
+            return;
+        }
+        self.daily_scores.push(DailyRecord {
+            date: date.to_string(),
+            time_survived,
+            enemies_killed,
+        });
+    }
+
+    /// Record a run that ended with the ship destroyed
+    pub fn record_defeat(&mut self, integrity_pct: f32, enemies_killed: u32, stats: &RunStats) {
+        self.update_bests(integrity_pct, enemies_killed, stats);
+    }
+
+    /// Snapshot the current bests into `previous_best`, then raise them
+    fn update_bests(&mut self, integrity_pct: f32, enemies_killed: u32, stats: &RunStats) {
+        self.previous_best = Some(PersonalBest {
+            time: self.best_time,
+            ship_integrity_pct: self.best_ship_integrity_pct,
+            enemies_killed: self.most_enemies_killed,
+            damage_dealt: self.best_damage_dealt,
+            repairs_performed: self.most_repairs,
+            scrap_gathered: self.most_scrap_gathered,
+        });
+        self.best_ship_integrity_pct = self.best_ship_integrity_pct.max(integrity_pct);
+        self.most_enemies_killed = self.most_enemies_killed.max(enemies_killed);
+        self.best_damage_dealt = self.best_damage_dealt.max(stats.damage_dealt);
+        self.most_repairs = self.most_repairs.max(stats.repairs_performed);
+        self.most_scrap_gathered = self.most_scrap_gathered.max(stats.scrap_gathered);
+    }
+
+    /// Count a kill towards lifetime stats
+    pub fn record_kill(&mut self, enemy_type: &EnemyType) {
+        *self.kill_counts.entry(enemy_type.clone()).or_insert(0) += 1;
+    }
+
+    /// Lifetime kills of one enemy type, or of every type if None
+    pub fn kills(&self, enemy_type: Option<&EnemyType>) -> u32 {
+        match enemy_type {
+            Some(t) => self.kill_counts.get(t).copied().unwrap_or(0),
+            None => self.kill_counts.values().sum(),
+        }
+    }
+
+    pub fn is_unlocked(&self, id: &str) -> bool {
+        self.achievements.get(id).copied().unwrap_or(false)
+    }
+
+    /// Level of a permanent upgrade (0 if never bought)
+    pub fn permanent_level(&self, id: &str) -> u32 {
+        self.permanent_upgrades.get(id).copied().unwrap_or(0)
+    }
+
+    /// Cost of the next level of a permanent upgrade
+    pub fn permanent_cost(&self, template: &UpgradeTemplate) -> i32 {
+        let level = self.permanent_level(&template.id);
+        (template.base_cost as f32 * template.cost_multiplier.powi(level as i32)) as i32
+    }
+
+    /// Raise a permanent upgrade one level, paid from banked credits.
+    /// Returns false if it is maxed or unaffordable.
+    pub fn purchase_permanent_upgrade(&mut self, template: &UpgradeTemplate) -> bool {
+        let level = self.permanent_level(&template.id);
+        if level >= template.max_level { return false; }
+        if !self.spend_credits(self.permanent_cost(template)) { return false; }
+
+        self.permanent_upgrades.insert(template.id.clone(), level + 1);
+        true
     }
 
     /// Spend banked credits (returns true if affordable)
@@ -81,3 +272,30 @@ impl PlayerProfile {
         }
     }
 }
+
+/// Today's date as YYYY-MM-DD (UTC)
+#[cfg(not(target_arch = "wasm32"))]
+pub fn today() -> String {
+    let secs = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+
+    // Days since 1970-01-01 to a civil date (Howard Hinnant's algorithm)
+    let z = (secs / 86_400) as i64 + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+/// No clock access worth trusting in the browser build
+#[cfg(target_arch = "wasm32")]
+pub fn today() -> String {
+    String::new()
+}
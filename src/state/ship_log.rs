@@ -0,0 +1,18 @@
+//! Running log of notable events during a run, shown from the pause menu
+
+use serde::{Deserialize, Serialize};
+
+/// Severity of a log entry; decides its color in the ship log
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LogKind {
+    Info,
+    Warning,
+    Critical,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogEntry {
+    pub message: String,
+    pub time: f32, // time_survived when the entry was logged
+    pub kind: LogKind,
+}
@@ -1,6 +1,7 @@
 //! Data-driven tutorial system
 
 use serde::Deserialize;
+use crate::data::settings::Settings;
 
 /// Configuration for a single tutorial step (loaded from JSON)
 #[derive(Debug, Clone, Deserialize)]
@@ -37,10 +38,11 @@ pub struct TutorialState {
 }
 
 impl TutorialState {
-    pub fn new() -> Self {
+    /// Fresh tutorial, or an already-completed one if the player chose to skip it
+    pub fn new(settings: &Settings) -> Self {
         Self {
             current_index: 0,
-            completed: false,
+            completed: settings.skip_tutorial,
         }
     }
 
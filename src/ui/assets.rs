@@ -1,21 +1,61 @@
 use macroquad::prelude::*;
+use std::collections::HashMap;
+use crate::simulation::constants::{ENEMY_ANIMATION_FRAMES, ENEMY_ANIMATION_FRAME_DURATION};
 
-// Re-export Sprite from toolkit
-pub use macroquad_toolkit::sprite::Sprite;
+/// Frames of a looping animation, loaded from `<name>_f0.png`, `<name>_f1.png`, ...
+#[derive(Debug, Clone)]
+pub struct AnimationSet {
+    pub frames: Vec<Texture2D>,
+    pub frame_duration: f32,
+}
+
+impl AnimationSet {
+    /// Frame shown `elapsed` seconds into the loop, advancing every `frame_duration`
+    pub fn frame_at(&self, elapsed: f32) -> Option<&Texture2D> {
+        if self.frames.is_empty() || self.frame_duration <= 0.0 {
+            return self.frames.first();
+        }
+        let index = (elapsed / self.frame_duration) as usize;
+        self.frames.get(index % self.frames.len())
+    }
+}
+
+/// Region of the atlas texture holding one sprite
+#[derive(Debug, Clone, Copy, serde::Deserialize)]
+struct AtlasRect {
+    x: f32,
+    y: f32,
+    w: f32,
+    h: f32,
+}
+
+/// Atlas manifest: `{ "sprites": { "enemy_nanodrone": { "x": 0, "y": 0, "w": 32, "h": 32 } } }`
+#[derive(serde::Deserialize)]
+struct AtlasManifest {
+    sprites: HashMap<String, AtlasRect>,
+}
 
 // AssetManager wrapper that adds game-specific methods
 pub struct AssetManager {
     inner: macroquad_toolkit::assets::AssetManager,
+    animations: HashMap<String, AnimationSet>,
+    atlas: Option<Texture2D>,
+    atlas_rects: HashMap<String, Rect>,
 }
 
 impl AssetManager {
     pub fn new() -> Self {
         Self {
             inner: macroquad_toolkit::assets::AssetManager::new(),
+            animations: HashMap::new(),
+            atlas: None,
+            atlas_rects: HashMap::new(),
         }
     }
 
-    pub async fn load_assets(&mut self) {
+    /// Load all textures, calling `cb` with the loaded fraction (0.0 - 1.0) after each
+    /// successful texture. A frame is yielded after each callback so anything it draws is shown.
+    pub async fn load_assets_with_progress<F: Fn(f32)>(&mut self, cb: F) {
         let textures = vec![
             "enemy_nanodrone", "enemy_nanoguard", "enemy_leech", "enemy_siege_construct", "enemy_boss",
             "ship_hull_scavenger",
@@ -30,21 +70,79 @@ impl AssetManager {
             "prop_shield_emitter"
         ];
 
-        for name in textures {
+        let total_count = textures.len();
+        for (i, name) in textures.into_iter().enumerate() {
             let path = format!("assets/{}.png", name);
-            if let Err(e) = self.inner.load_texture(name, &path).await {
-                eprintln!("Failed to load texture: {}", e);
+            match self.inner.load_texture(name, &path).await {
+                Ok(_) => {
+                    let loaded_count = i + 1;
+                    cb(loaded_count as f32 / total_count as f32);
+                    next_frame().await;
+                }
+                Err(e) => eprintln!("Failed to load texture: {}", e),
             }
         }
+
+        let animated = [
+            "enemy_nanodrone", "enemy_nanoguard", "enemy_leech", "enemy_siege_construct", "enemy_boss",
+        ];
+
+        for name in animated {
+            let animation = Self::load_animation(name, ENEMY_ANIMATION_FRAMES, ENEMY_ANIMATION_FRAME_DURATION).await;
+            // Sprite sheets are optional: the single texture is used when no frames exist
+            if !animation.frames.is_empty() {
+                self.animations.insert(name.to_string(), animation);
+            }
+        }
+    }
+
+    /// Load a packed atlas texture and its sprite manifest. Sprites named in the
+    /// manifest are drawn from the atlas instead of their individual textures.
+    pub async fn load_atlas(&mut self, path: &str, manifest_path: &str) -> Result<(), String> {
+        let manifest = macroquad::file::load_string(manifest_path).await.map_err(|e| e.to_string())?;
+        let manifest: AtlasManifest = serde_json::from_str(&manifest).map_err(|e| e.to_string())?;
+        let texture = load_texture(path).await.map_err(|e| e.to_string())?;
+        texture.set_filter(FilterMode::Nearest);
+
+        self.atlas_rects = manifest.sprites.into_iter()
+            .map(|(name, r)| (name, Rect::new(r.x, r.y, r.w, r.h)))
+            .collect();
+        self.atlas = Some(texture);
+        Ok(())
+    }
+
+    /// Load `assets/<name>_f<i>.png` for each frame. Missing frames are skipped.
+    pub async fn load_animation(name: &str, frame_count: usize, duration: f32) -> AnimationSet {
+        let mut frames = Vec::with_capacity(frame_count);
+        for i in 0..frame_count {
+            let path = format!("assets/{}_f{}.png", name, i);
+            if let Ok(texture) = load_texture(&path).await {
+                frames.push(texture);
+            }
+        }
+
+        AnimationSet {
+            frames,
+            frame_duration: duration,
+        }
+    }
+
+    pub fn get_animation(&self, name: &str) -> Option<&AnimationSet> {
+        self.animations.get(name)
     }
 
     pub fn get_texture(&self, name: &str) -> Option<&Texture2D> {
         self.inner.get_texture(name)
     }
 
-    /// Create a sprite from a loaded texture.
-    pub fn create_sprite(&self, name: &str) -> Option<Sprite> {
-        self.get_texture(name).map(|t| Sprite::new().with_texture(t.clone()))
+    /// Texture and draw params for a sprite. In atlas mode the params carry the
+    /// sprite's `source` rect; otherwise the sprite's own texture is drawn whole.
+    pub fn create_sprite(&self, name: &str) -> Option<(&Texture2D, DrawTextureParams)> {
+        if let (Some(atlas), Some(rect)) = (&self.atlas, self.atlas_rects.get(name)) {
+            let params = DrawTextureParams { source: Some(*rect), ..Default::default() };
+            return Some((atlas, params));
+        }
+        self.get_texture(name).map(|t| (t, DrawTextureParams::default()))
     }
 }
 
@@ -0,0 +1,149 @@
+//! Developer console (debug builds only)
+//!
+//! Toggled with the backtick key. Commands tweak live game state so balance
+//! constants can be tried out without recompiling.
+
+use macroquad::prelude::*;
+use crate::state::{GameState, GamePhase, EngineState};
+use crate::enemy::ai::spawn_enemy;
+use crate::enemy::entities::EnemyType;
+use crate::ui::input_manager::InputManager;
+use crate::ui::renderer::Renderer;
+
+const HISTORY_LINES: usize = 10;
+
+#[derive(Debug, Default)]
+pub struct DebugConsole {
+    pub visible: bool,
+    pub input_buffer: String,
+    pub history: Vec<String>,
+}
+
+impl DebugConsole {
+    fn log(&mut self, line: String) {
+        self.history.push(line);
+        if self.history.len() > HISTORY_LINES {
+            self.history.remove(0);
+        }
+    }
+}
+
+/// Run one console command against the game state, returning the reply to log
+pub fn execute_command(cmd: &str, state: &mut GameState) -> String {
+    let args: Vec<&str> = cmd.split_whitespace().collect();
+    match args.as_slice() {
+        ["spawn", kind] => spawn(state, kind, 1),
+        ["spawn", kind, count] => match count.parse() {
+            Ok(count) => spawn(state, kind, count),
+            Err(_) => format!("Invalid count: {}", count),
+        },
+        ["set", "scrap", amount] => match amount.parse::<i32>() {
+            Ok(amount) => {
+                state.resources.scrap = amount.clamp(0, state.resources.max_scrap);
+                format!("Scrap set to {}", state.resources.scrap)
+            }
+            Err(_) => format!("Invalid amount: {}", amount),
+        },
+        ["set", "credits", amount] => match amount.parse::<i32>() {
+            Ok(amount) => {
+                state.resources.credits = amount.max(0);
+                format!("Credits set to {}", state.resources.credits)
+            }
+            Err(_) => format!("Invalid amount: {}", amount),
+        },
+        ["set_integrity", pct] => match pct.parse::<f32>() {
+            Ok(pct) => {
+                state.ship_integrity = state.ship_max_integrity * pct.clamp(0.0, 100.0) / 100.0;
+                format!("Hull integrity set to {:.0}", state.ship_integrity)
+            }
+            Err(_) => format!("Invalid percentage: {}", pct),
+        },
+        ["skip_round"] => {
+            if state.phase != GamePhase::Playing {
+                return "Not in a round".to_string();
+            }
+            // Escape completes on the next update
+            state.engine_state = EngineState::Charging;
+            state.escape_timer = 0.0;
+            "Skipping round".to_string()
+        }
+        ["help"] => "Commands: spawn <type> [n], set scrap|credits <n>, set_integrity <pct>, skip_round".to_string(),
+        [] => String::new(),
+        _ => format!("Unknown command: {}", cmd),
+    }
+}
+
+fn spawn(state: &mut GameState, kind: &str, count: u32) -> String {
+    let enemy_type = match kind {
+        "drone" => EnemyType::Nanodrone,
+        "guard" => EnemyType::Nanoguard,
+        "leech" => EnemyType::Leech,
+        "siege" => EnemyType::SiegeConstruct,
+        "carrier" => EnemyType::Carrier,
+        "boss" => EnemyType::Boss,
+        _ => return format!("Unknown enemy: {} (drone, guard, leech, siege, carrier, boss)", kind),
+    };
+    let hp_scale = state.enemy_scaling().hp;
+    for _ in 0..count {
+        spawn_enemy(&mut state.enemies, enemy_type.clone(), state.frame_count, hp_scale);
+    }
+    format!("Spawned {} x {:?}", count, enemy_type)
+}
+
+impl InputManager {
+    /// Toggle and feed the console. Returns true while it is open so other input is skipped.
+    pub fn handle_debug_console_input(&self, state: &mut GameState) -> bool {
+        if is_key_pressed(KeyCode::GraveAccent) {
+            state.debug_console.visible = !state.debug_console.visible;
+            state.debug_console.input_buffer.clear();
+            // Drain the backtick itself from the char queue
+            while get_char_pressed().is_some() {}
+            return true;
+        }
+        if !state.debug_console.visible {
+            return false;
+        }
+
+        while let Some(c) = get_char_pressed() {
+            if !c.is_control() && c != '`' {
+                state.debug_console.input_buffer.push(c);
+            }
+        }
+        if is_key_pressed(KeyCode::Backspace) {
+            state.debug_console.input_buffer.pop();
+        }
+        if is_key_pressed(KeyCode::Escape) {
+            state.debug_console.visible = false;
+        }
+        if is_key_pressed(KeyCode::Enter) {
+            let cmd = std::mem::take(&mut state.debug_console.input_buffer);
+            let reply = execute_command(&cmd, state);
+            state.debug_console.log(format!("> {}", cmd));
+            if !reply.is_empty() {
+                state.debug_console.log(reply);
+            }
+        }
+        true
+    }
+}
+
+impl Renderer {
+    pub fn draw_debug_console(&self, state: &GameState) {
+        let console = &state.debug_console;
+        if !console.visible { return; }
+
+        let line_h = 20.0;
+        let height = line_h * (HISTORY_LINES as f32 + 1.0) + 20.0;
+        let y = screen_height() - height;
+        draw_rectangle(0.0, y, screen_width(), height, color_u8!(0, 0, 0, 180));
+        draw_line(0.0, y, screen_width(), y, 1.0, GREEN);
+
+        for (i, line) in console.history.iter().enumerate() {
+            draw_text(line, 10.0, y + 20.0 + i as f32 * line_h, 18.0, LIGHTGRAY);
+        }
+
+        let cursor = if (get_time() * 2.0) as i32 % 2 == 0 { "_" } else { "" };
+        let prompt = format!("> {}{}", console.input_buffer, cursor);
+        draw_text(&prompt, 10.0, screen_height() - 12.0, 18.0, GREEN);
+    }
+}
@@ -0,0 +1,161 @@
+//! Game over and victory screens with the run recap
+
+use macroquad::prelude::*;
+use crate::state::{GameState, PlayerProfile};
+use crate::ui::renderer::Renderer;
+
+impl Renderer {
+    pub fn draw_game_over(&self, state: &GameState, profile: &PlayerProfile) {
+        draw_rectangle(0.0, 0.0, screen_width(), screen_height(), color_u8!(15, 5, 5, 255));
+        for i in 0..5 {
+            let alpha = 100 - i * 20;
+            let offset = i as f32 * 30.0;
+            draw_rectangle_lines(offset, offset, screen_width() - offset * 2.0, screen_height() - offset * 2.0, 3.0, color_u8!(80, 0, 0, alpha as u8));
+        }
+
+        let text = "CORE DESTROYED";
+        let size = measure_text(text, None, 64, 1.0);
+        draw_text(text, screen_width() / 2.0 - size.width / 2.0, screen_height() / 3.0, 64.0, RED);
+
+        let stats_y = screen_height() / 2.0;
+        let minutes = (state.time_survived / 60.0).floor() as i32;
+        let seconds = (state.time_survived % 60.0).floor() as i32;
+        let stats = [
+            format!("Scrap Collected: {}", state.resources.scrap + 100),
+            format!("Credits Earned: {}", state.resources.credits),
+            format!("Time Survived: {:02}:{:02}", minutes, seconds),
+        ];
+        
+        for (i, stat) in stats.iter().enumerate() {
+            let s = measure_text(stat, None, 24, 1.0);
+            draw_text(stat, screen_width() / 2.0 - s.width / 2.0, stats_y + i as f32 * 30.0, 24.0, GRAY);
+        }
+
+        self.draw_personal_best(state, profile, stats_y, false);
+        self.draw_run_recap(state, profile, stats_y);
+
+        // Placeholder until daily scores are submitted to a server
+        if state.daily_date.is_some() {
+            let rank = "Today's Global Rank: N/A";
+            let rank_w = measure_text(rank, None, 22, 1.0).width;
+            draw_text(rank, screen_width() / 2.0 - rank_w / 2.0, screen_height() - 140.0, 22.0, GOLD);
+            let note = format!("Daily seed {:016X} is the same for every player today", state.run_seed);
+            let note_w = measure_text(&note, None, 16, 1.0).width;
+            draw_text(&note, screen_width() / 2.0 - note_w / 2.0, screen_height() - 115.0, 16.0, GRAY);
+        }
+
+        let hint = "Press ENTER to return to menu";
+        let hint_size = measure_text(hint, None, 24, 1.0);
+        draw_text(hint, screen_width() / 2.0 - hint_size.width / 2.0, screen_height() - 80.0, 24.0, WHITE);
+    }
+
+    pub fn draw_victory(&self, state: &GameState, profile: &PlayerProfile) {
+        draw_rectangle(0.0, 0.0, screen_width(), screen_height(), color_u8!(10, 20, 30, 255));
+        for i in 0..8 {
+            let alpha = 60 - i * 7;
+            let offset = i as f32 * 20.0;
+            draw_rectangle_lines(offset, offset, screen_width() - offset * 2.0, screen_height() - offset * 2.0, 2.0, color_u8!(100, 200, 255, alpha as u8));
+        }
+
+        let text = "ESCAPE SUCCESSFUL!";
+        let size = measure_text(text, None, 64, 1.0);
+        draw_text(text, screen_width() / 2.0 - size.width / 2.0, screen_height() / 3.0, 64.0, GREEN);
+
+        let subtitle = "You made it off the planet!";
+        let sub_size = measure_text(subtitle, None, 28, 1.0);
+        draw_text(subtitle, screen_width() / 2.0 - sub_size.width / 2.0, screen_height() / 3.0 + 50.0, 28.0, color_u8!(150, 255, 150, 255));
+
+        let stats_y = screen_height() / 2.0;
+        let stats = [
+            format!("Total Credits: {}", state.resources.credits),
+            format!("Escape: {} Cr + Defense Bonus: {} Cr", state.run_stats.escape_credits, state.run_stats.defense_bonus),
+            format!("Core Health Remaining: {:.0}%", 
+                if let Some(pos) = state.ship.find_core() {
+                    if let Some(core) = &state.ship.grid[pos.0][pos.1] {
+                        (core.health / core.max_health) * 100.0
+                    } else { 0.0 }
+                } else { 0.0 }
+            ),
+        ];
+        
+        for (i, stat) in stats.iter().enumerate() {
+            let s = measure_text(stat, None, 24, 1.0);
+            draw_text(stat, screen_width() / 2.0 - s.width / 2.0, stats_y + i as f32 * 30.0, 24.0, WHITE);
+        }
+
+        self.draw_personal_best(state, profile, stats_y, true);
+        self.draw_run_recap(state, profile, stats_y);
+
+        let hint = "Press ENTER to continue to Upgrades";
+        let hint_size = measure_text(hint, None, 24, 1.0);
+        draw_text(hint, screen_width() / 2.0 - hint_size.width / 2.0, screen_height() - 80.0, 24.0, YELLOW);
+    }
+
+    /// "RUN RECAP" table left of the end screen stats; rows beating the previous best are green
+    pub fn draw_run_recap(&self, state: &GameState, profile: &PlayerProfile, stats_y: f32) {
+        let stats = &state.run_stats;
+        let x = screen_width() / 2.0 - 460.0;
+        let value_x = x + 220.0;
+        draw_text("RUN RECAP", x, stats_y - 35.0, 22.0, GOLD);
+        if state.ironman {
+            // Placeholder seal until there is art for it
+            let seal_x = x + 140.0;
+            let seal_y = stats_y - 55.0;
+            draw_rectangle(seal_x, seal_y, 90.0, 26.0, color_u8!(120, 20, 20, 255));
+            draw_rectangle_lines(seal_x, seal_y, 90.0, 26.0, 2.0, GOLD);
+            draw_text("IRONMAN", seal_x + 10.0, seal_y + 19.0, 18.0, GOLD);
+        }
+
+        // No comparison until there is an earlier run to compare against
+        let best = profile.previous_best.filter(|_| profile.runs_completed > 0);
+
+        let mut kills: Vec<_> = stats.kills_by_type.iter().collect();
+        kills.sort_by_key(|(t, _)| t.label());
+        let mut rows: Vec<(String, String, Color)> = kills.into_iter()
+            .map(|(t, n)| (format!("{} kills", t.label()), n.to_string(), LIGHTGRAY))
+            .collect();
+        rows.push(("Damage dealt".to_string(), format!("{:.0}", stats.damage_dealt),
+            if best.is_some_and(|b| stats.damage_dealt > b.damage_dealt) { GREEN } else { WHITE }));
+        rows.push(("Repairs performed".to_string(), stats.repairs_performed.to_string(),
+            if best.is_some_and(|b| stats.repairs_performed > b.repairs_performed) { GREEN } else { WHITE }));
+        rows.push(("Scrap gathered".to_string(), stats.scrap_gathered.to_string(),
+            if best.is_some_and(|b| stats.scrap_gathered > b.scrap_gathered) { GREEN } else { WHITE }));
+
+        for (i, (label, value, color)) in rows.iter().enumerate() {
+            let y = stats_y + i as f32 * 26.0;
+            draw_text(label, x, y, 20.0, *color);
+            let value_w = measure_text(value, None, 20, 1.0).width;
+            draw_text(value, value_x - value_w, y, 20.0, *color);
+        }
+    }
+
+    /// "PERSONAL BEST" column right of the end screen stats: this run vs previous bests
+    fn draw_personal_best(&self, state: &GameState, profile: &PlayerProfile, stats_y: f32, escaped: bool) {
+        if profile.runs_completed == 0 { return; }
+        let Some(best) = profile.previous_best else { return };
+
+        let x = screen_width() / 2.0 + 240.0;
+        draw_text("PERSONAL BEST", x, stats_y - 35.0, 22.0, GOLD);
+
+        let better = |improved: bool| if improved { GREEN } else { YELLOW };
+        let mut rows = Vec::new();
+
+        let kill_delta = state.enemies_killed as i64 - best.enemies_killed as i64;
+        rows.push((format!("{:+} enemies killed", kill_delta), better(kill_delta >= 0)));
+
+        let integrity_pct = (state.ship_integrity / state.ship_max_integrity * 100.0).clamp(0.0, 100.0);
+        let integrity_delta = integrity_pct - best.ship_integrity_pct;
+        rows.push((format!("{:+.0}% hull integrity", integrity_delta), better(integrity_delta >= 0.0)));
+
+        if escaped {
+            if let Some(best_time) = best.time {
+                let time_delta = state.time_survived - best_time;
+                rows.push((format!("{:+.0}s escape time", time_delta), better(time_delta <= 0.0)));
+            }
+        }
+
+        for (i, (text, color)) in rows.iter().enumerate() {
+            draw_text(text, x, stats_y + i as f32 * 30.0, 20.0, *color);
+        }
+    }
+}
@@ -0,0 +1,80 @@
+//! Gamepad polling
+//!
+//! Backed by gilrs on native builds. WebGL builds report no gamepad input.
+
+use macroquad::prelude::*;
+
+/// Stick magnitude below which input is ignored
+pub const GAMEPAD_DEADZONE: f32 = 0.2;
+
+/// Gamepad state sampled once per frame
+#[derive(Debug, Clone, Default)]
+pub struct GamepadFrame {
+    pub interact_pressed: bool, // A / South
+    pub interact_held: bool,    // A / South, held down
+    pub back_pressed: bool,     // B / East
+    pub tab_pressed: bool,      // Left bumper
+    pub sprint_held: bool,      // Right bumper, held down
+    pub move_dir: Vec2,         // Left stick, zero inside the deadzone
+}
+
+pub struct Gamepads {
+    #[cfg(not(target_arch = "wasm32"))]
+    gilrs: Option<gilrs::Gilrs>,
+}
+
+impl Gamepads {
+    pub fn new() -> Self {
+        Self {
+            #[cfg(not(target_arch = "wasm32"))]
+            gilrs: gilrs::Gilrs::new()
+                .map_err(|e| eprintln!("Warning: Gamepad support unavailable: {}", e))
+                .ok(),
+        }
+    }
+
+    /// Drain pending gamepad events and sample the active pad.
+    /// The first pad to send an event becomes active if `index` is None.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn poll(&mut self, index: &mut Option<usize>) -> GamepadFrame {
+        use gilrs::{Axis, Button, Event, EventType};
+
+        let mut frame = GamepadFrame::default();
+        let Some(gilrs) = self.gilrs.as_mut() else { return frame };
+
+        while let Some(Event { id, event, .. }) = gilrs.next_event() {
+            let idx = usize::from(id);
+            if index.is_none() {
+                *index = Some(idx);
+            }
+            if *index != Some(idx) { continue; }
+
+            match event {
+                EventType::ButtonPressed(Button::South, _) => frame.interact_pressed = true,
+                EventType::ButtonPressed(Button::East, _) => frame.back_pressed = true,
+                EventType::ButtonPressed(Button::LeftTrigger, _) => frame.tab_pressed = true,
+                EventType::Disconnected => *index = None,
+                _ => {}
+            }
+        }
+
+        if let Some(idx) = *index {
+            if let Some((_, pad)) = gilrs.gamepads().find(|(id, _)| usize::from(*id) == idx) {
+                frame.interact_held = pad.is_pressed(Button::South);
+                frame.sprint_held = pad.is_pressed(Button::RightTrigger);
+                // gilrs reports stick-up as positive Y; screen space is Y-down
+                let stick = vec2(pad.value(Axis::LeftStickX), -pad.value(Axis::LeftStickY));
+                if stick.length() >= GAMEPAD_DEADZONE {
+                    frame.move_dir = stick;
+                }
+            }
+        }
+
+        frame
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    pub fn poll(&mut self, _index: &mut Option<usize>) -> GamepadFrame {
+        GamepadFrame::default()
+    }
+}
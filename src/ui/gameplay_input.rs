@@ -1,11 +1,13 @@
 use macroquad::prelude::*;
-use crate::state::{GameState, ViewMode};
-use crate::simulation::events::{EventBus, UIEvent};
+use crate::state::{GameState, GamePhase, SlotSelectMode, ViewMode};
+use crate::simulation::events::{EventBus, GameEvent, UIEvent};
 use crate::simulation::constants::*;
-use crate::ship::interior::Room;
+use crate::ship::interior::{Room, RoomType};
 use crate::ship::ship::{ModuleState, ModuleType};
 use crate::ui::input_manager::{InputManager, InputState};
 use crate::ui::pause_menu::PauseMenuOption;
+use crate::data::settings::{BindAction, key_name};
+use crate::simulation::environment::EnvEventKind;
 
 impl InputManager {
     pub fn handle_gameplay_input(&mut self, input: &InputState, state: &mut GameState, events: &mut EventBus) {
@@ -21,6 +23,7 @@ impl InputManager {
                 ViewMode::Interior => ViewMode::Exterior,
                 ViewMode::Exterior => ViewMode::Interior,
             };
+            state.show_map = false;
         }
 
         // Escape opens pause menu
@@ -36,15 +39,56 @@ impl InputManager {
         }
 
         // View-specific input
-        if state.view_mode == ViewMode::Interior {
-            self.handle_interior_input(input, state, events);
+        match state.view_mode {
+            ViewMode::Interior => self.handle_interior_input(input, state, events),
+            ViewMode::Exterior => self.handle_grid_click(input, state, events),
+        }
+    }
+
+    /// Right-click an active module to mark it for sale, right-click it again to sell.
+    /// Shift+click overcharges it instead.
+    fn handle_grid_click(&mut self, input: &InputState, state: &mut GameState, events: &mut EventBus) {
+        if input.left_click && input.shift_held {
+            if let Some((x, y)) = input.mouse_world_pos {
+                events.push_ui(UIEvent::Overcharge(x, y));
+            }
+            return;
+        }
+
+        if !input.right_click { return; }
+        let Some((x, y)) = input.mouse_world_pos else { return };
+        let Some(module) = &state.ship.grid[x][y] else { return };
+        if module.state != ModuleState::Active || module.module_type == ModuleType::Core { return; }
+
+        if state.sell_confirm == Some((x, y)) {
+            state.sell_confirm = None;
+            events.push_ui(UIEvent::SellModule(x, y));
+        } else {
+            let refund = state.module_registry.get(module.module_type).base_cost / 2;
+            state.sell_confirm = Some((x, y));
+            state.sell_confirm_timer = SELL_CONFIRM_WINDOW;
+            state.show_toast(format!("Right-click again to sell for {} scrap", refund));
         }
     }
 
     fn handle_pause_menu_input(&mut self, input: &InputState, state: &mut GameState, events: &mut EventBus) {
-        let menu_options = PauseMenuOption::all();
+        let menu_options = PauseMenuOption::all(state.ironman);
         let option_count = menu_options.len();
 
+        // Key binding screen sits on top of the settings panel
+        if state.keybinds_open {
+            self.handle_keybinds_input(input, state);
+            return;
+        }
+
+        // Ship log is read-only; Esc returns to the pause menu
+        if state.ship_log_open {
+            if input.escape_pressed {
+                state.ship_log_open = false;
+            }
+            return;
+        }
+
         // ESC closes pause menu
         if input.escape_pressed {
             events.push_ui(UIEvent::Resume);
@@ -53,7 +97,7 @@ impl InputManager {
 
         // Calculate button bounds (must match pause_menu.rs layout)
         let box_w = 300.0;
-        let box_h = 320.0;
+        let box_h = 470.0;
         let box_x = (screen_width() - box_w) / 2.0;
         let box_y = (screen_height() - box_h) / 2.0;
         let btn_w = 200.0;
@@ -84,8 +128,10 @@ impl InputManager {
                             state.settings_open = true;
                             state.settings_selection = 0;
                         }
-                        PauseMenuOption::SaveGame => events.push_ui(UIEvent::SaveGame(0)),
-                        PauseMenuOption::LoadGame => events.push_ui(UIEvent::LoadGame(0)),
+                        PauseMenuOption::SaveGame => Self::open_slot_select(state, SlotSelectMode::Save),
+                        PauseMenuOption::LoadGame => Self::open_slot_select(state, SlotSelectMode::Load),
+                        PauseMenuOption::ShipLog => state.ship_log_open = true,
+                        PauseMenuOption::Codex => events.push_ui(UIEvent::OpenCodex),
                         PauseMenuOption::ReturnToMenu => events.push_ui(UIEvent::ReturnToMenu),
                         PauseMenuOption::ExitGame => events.push_ui(UIEvent::ExitGame),
                     }
@@ -117,16 +163,44 @@ impl InputManager {
                     state.settings_open = true;
                     state.settings_selection = 0;
                 }
-                PauseMenuOption::SaveGame => events.push_ui(UIEvent::SaveGame(0)),
-                PauseMenuOption::LoadGame => events.push_ui(UIEvent::LoadGame(0)),
+                PauseMenuOption::SaveGame => Self::open_slot_select(state, SlotSelectMode::Save),
+                PauseMenuOption::LoadGame => Self::open_slot_select(state, SlotSelectMode::Load),
+                PauseMenuOption::ShipLog => state.ship_log_open = true,
+                PauseMenuOption::Codex => events.push_ui(UIEvent::OpenCodex),
                 PauseMenuOption::ReturnToMenu => events.push_ui(UIEvent::ReturnToMenu),
                 PauseMenuOption::ExitGame => events.push_ui(UIEvent::ExitGame),
             }
         }
     }
 
+    /// Up/Down pages through codex entries, Esc returns to the pause menu
+    pub fn handle_codex_input(&mut self, input: &InputState, state: &mut GameState, selected_entry: usize) {
+        if input.escape_pressed {
+            state.phase = GamePhase::Playing;
+            return;
+        }
+
+        let entry_count = state.codex_entries.len();
+        if entry_count == 0 { return; }
+
+        let mut selected = selected_entry;
+        if is_key_pressed(KeyCode::Up) || is_key_pressed(KeyCode::W) {
+            selected = (selected + entry_count - 1) % entry_count;
+        }
+        if is_key_pressed(KeyCode::Down) || is_key_pressed(KeyCode::S) {
+            selected = (selected + 1) % entry_count;
+        }
+        state.phase = GamePhase::Codex { selected_entry: selected };
+    }
+
+    fn open_slot_select(state: &mut GameState, mode: SlotSelectMode) {
+        state.refresh_slot_summaries();
+        state.slot_selection = 0;
+        state.phase = GamePhase::SaveSlotSelect { mode };
+    }
+
     fn handle_settings_input(&mut self, input: &InputState, state: &mut GameState, events: &mut EventBus) {
-        const SETTING_COUNT: usize = 6; // 5 settings + Back
+        const SETTING_COUNT: usize = 11; // 8 settings + Colorblind + Key Bindings + Back
         
         // Up/Down navigation
         if is_key_pressed(KeyCode::Up) || is_key_pressed(KeyCode::W) {
@@ -150,6 +224,7 @@ impl InputManager {
                 0 => state.settings.master_volume = (state.settings.master_volume + delta).clamp(0.0, 1.0),
                 1 => state.settings.sfx_volume = (state.settings.sfx_volume + delta).clamp(0.0, 1.0),
                 2 => state.settings.music_volume = (state.settings.music_volume + delta).clamp(0.0, 1.0),
+                8 => state.settings.colorblind_mode = state.settings.colorblind_mode.cycle(delta > 0.0),
                 _ => {}
             }
         }
@@ -166,7 +241,15 @@ impl InputManager {
                     }
                 }
                 4 => state.settings.screen_shake = !state.settings.screen_shake,
-                5 => {
+                5 => state.settings.show_minimap = !state.settings.show_minimap,
+                6 => state.settings.gamepad_enabled = !state.settings.gamepad_enabled,
+                7 => state.settings.touch_mode = !state.settings.touch_mode,
+                8 => state.settings.colorblind_mode = state.settings.colorblind_mode.cycle(true),
+                9 => {
+                    state.keybinds_open = true;
+                    state.keybind_selection = 0;
+                }
+                10 => {
                     // Back - save and close
                     let _ = state.settings.save();
                     state.settings_open = false;
@@ -182,17 +265,183 @@ impl InputManager {
         }
     }
 
+    fn handle_keybinds_input(&mut self, input: &InputState, state: &mut GameState) {
+        let row_count = BindAction::ALL.len() + 1; // actions + Back
+
+        if input.escape_pressed {
+            state.keybinds_open = false;
+            return;
+        }
+
+        if is_key_pressed(KeyCode::Up) {
+            state.keybind_selection = (state.keybind_selection + row_count - 1) % row_count;
+        }
+        if is_key_pressed(KeyCode::Down) {
+            state.keybind_selection = (state.keybind_selection + 1) % row_count;
+        }
+
+        if input.enter_pressed {
+            match BindAction::ALL.get(state.keybind_selection) {
+                Some(action) => state.phase = GamePhase::KeybindCapture { action: *action },
+                None => state.keybinds_open = false,
+            }
+        }
+    }
+
+    /// "Press any key" mode: the next bindable key becomes the action's binding
+    pub fn handle_keybind_capture_input(&mut self, state: &mut GameState, action: BindAction) {
+        let Some(key) = get_last_key_pressed() else { return };
+
+        if key != KeyCode::Escape && key_name(key).is_some() {
+            state.settings.key_bindings.set(action, key);
+            let _ = state.settings.save();
+        }
+        state.phase = GamePhase::Playing;
+    }
+
     fn handle_interior_input(&mut self, input: &InputState, state: &mut GameState, events: &mut EventBus) {
-        self.handle_scrap_gathering(state, events);
+        self.handle_scrap_gathering(input, state, events);
+        self.handle_breach_sealing(input, state);
+        self.handle_repair_queue_input(state);
+
+        if is_key_pressed(KeyCode::M) {
+            state.show_map = !state.show_map;
+        }
         
         if input.interact_pressed {
             self.handle_interact(state, events);
         }
+        self.handle_partial_repair(input, state, events);
+
+        if input.door_pressed {
+            state.toggle_nearby_door();
+        }
+
+        // Crafting is only available from the Storage room
+        if input.craft_pressed {
+            let in_storage = state.interior.room_at(state.player.position)
+                .is_some_and(|r| r.room_type == RoomType::Storage);
+            if in_storage {
+                state.crafting_selection = 0;
+                state.phase = GamePhase::Crafting;
+            }
+        }
+    }
+
+    pub fn handle_crafting_input(&mut self, input: &InputState, state: &mut GameState) {
+        if input.escape_pressed || input.craft_pressed {
+            state.phase = GamePhase::Playing;
+            return;
+        }
+
+        let recipe_count = state.recipe_book.recipes.len();
+        if recipe_count == 0 { return; }
+
+        if is_key_pressed(KeyCode::Up) || is_key_pressed(KeyCode::W) {
+            state.crafting_selection = (state.crafting_selection + recipe_count - 1) % recipe_count;
+        }
+        if is_key_pressed(KeyCode::Down) || is_key_pressed(KeyCode::S) {
+            state.crafting_selection = (state.crafting_selection + 1) % recipe_count;
+        }
+
+        if input.enter_pressed {
+            let selected = state.crafting_selection;
+            if state.craft(selected) {
+                let name = state.recipe_book.recipes[selected].output.name();
+                state.show_toast(format!("Crafted {}", name));
+            } else {
+                state.show_toast("Not enough scrap".to_string());
+            }
+        }
+    }
+
+    pub fn handle_in_run_shop_input(&mut self, input: &InputState, state: &mut GameState) {
+        if input.escape_pressed || input.interact_pressed {
+            state.phase = GamePhase::Playing;
+            return;
+        }
+
+        let offer_count = state.shop_offers.len();
+        if offer_count == 0 { return; }
+
+        if is_key_pressed(KeyCode::Up) || is_key_pressed(KeyCode::W) {
+            state.shop_selection = (state.shop_selection + offer_count - 1) % offer_count;
+        }
+        if is_key_pressed(KeyCode::Down) || is_key_pressed(KeyCode::S) {
+            state.shop_selection = (state.shop_selection + 1) % offer_count;
+        }
+
+        if input.enter_pressed {
+            let id = state.shop_offers[state.shop_selection].clone();
+            let Some(cost) = state.in_run_shop_cost(&id) else { return };
+            if state.purchase_in_run_upgrade(&id, cost) {
+                let name = state.upgrade_templates.iter().find(|t| t.id == id).map_or(id.clone(), |t| t.name.clone());
+                state.show_toast(format!("Installed {}", name));
+            } else {
+                state.show_toast("Not enough scrap".to_string());
+            }
+        }
+    }
+
+    /// Holding interact on a repair point fills its progress; releasing or stepping off resets it
+    fn handle_partial_repair(&self, input: &InputState, state: &mut GameState, events: &mut EventBus) {
+        let holding = input.interact_held;
+        let target = state.interior.rooms.iter()
+            .position(|r| r.contains(state.player.position))
+            .and_then(|room_idx| {
+                state.interior.rooms[room_idx].repair_point_at(state.player.position).map(|point_idx| (room_idx, point_idx))
+            });
+        // With repairs queued, E runs the queue instead
+        let (Some((room_idx, point_idx)), true, true) = (target, holding, state.repair_queue.is_empty()) else {
+            state.cancel_partial_repair();
+            return;
+        };
+
+        if state.advance_partial_repair(room_idx, point_idx, get_frame_time(), events) {
+            Self::advance_repair_tutorial(state, room_idx);
+        }
+    }
+
+    /// Mouse wheel queues (up) or unqueues (down) the repair point under the player.
+    /// The queue only covers the current room and is dropped on leaving it.
+    fn handle_repair_queue_input(&self, state: &mut GameState) {
+        let current_room = state.interior.rooms.iter().position(|r| r.contains(state.player.position));
+        if state.repair_queue.front().is_some_and(|&(room_idx, _)| Some(room_idx) != current_room) {
+            state.repair_queue.clear();
+        }
+        state.prune_repair_queue();
+
+        let (_, wheel_y) = mouse_wheel();
+        if wheel_y == 0.0 { return; }
+        let Some(room_idx) = current_room else { return };
+        let room = &state.interior.rooms[room_idx];
+        let Some(point_idx) = room.repair_point_at(state.player.position) else { return };
+        if room.repair_points[point_idx].repaired { return; }
+
+        let entry = (room_idx, point_idx);
+        let queued = state.repair_queue.contains(&entry);
+        if wheel_y > 0.0 && !queued {
+            state.repair_queue.push_back(entry);
+        } else if wheel_y < 0.0 && queued {
+            state.repair_queue.retain(|e| *e != entry);
+        }
+    }
+
+    /// Holding interact inside a breached room keeps it sealed
+    fn handle_breach_sealing(&self, input: &InputState, state: &mut GameState) {
+        if !input.interact_held { return; }
+        let Some(room_id) = state.interior.room_at(state.player.position).map(|r| r.id) else { return };
+
+        for event in &mut state.environment_events {
+            if event.kind == EnvEventKind::HullBreach && event.room_id == room_id {
+                event.sealing = true;
+            }
+        }
     }
 
-    fn handle_scrap_gathering(&self, state: &mut GameState, events: &mut EventBus) {
-        // Cancel gathering if not holding E or moving
-        if !is_key_down(KeyCode::E) || state.player.velocity.length() >= 0.1 {
+    fn handle_scrap_gathering(&self, input: &InputState, state: &mut GameState, events: &mut EventBus) {
+        // Cancel gathering if not holding E, moving or sprinting
+        if !input.interact_held || state.player.velocity.length() >= 0.1 || state.player.sprinting {
             state.gathering_target = None;
             state.gathering_timer = 0.0;
             return;
@@ -216,8 +465,14 @@ impl InputManager {
         amount = (amount as f32 * (1.0 + bonus_pct)) as i32;
         
         state.resources.add_scrap(amount);
+        state.run_stats.scrap_gathered += amount;
         state.scrap_piles[target_idx].active = false;
-        events.push_ui(UIEvent::Toggle(0, 0));
+        events.push_game(GameEvent::ScrapGathered { amount });
+        if let Some(id) = state.scrap_piles[target_idx].contains_schematic.take() {
+            let position = state.scrap_piles[target_idx].position;
+            state.discovered_schematics.insert(id);
+            state.show_popup("Schematic Found!".to_string(), position, ViewMode::Interior);
+        }
         state.gathering_target = None;
         state.gathering_timer = 0.0;
     }
@@ -252,23 +507,45 @@ impl InputManager {
             }
         }
 
+        if let Some(room_idx) = state.collapsed_room_to_clear() {
+            if !state.clear_collapsed_room(room_idx) {
+                state.show_toast(format!("Need {} Scrap to clear the rubble", ROOM_COLLAPSE_REPAIR_COST));
+            }
+            return;
+        }
+
         // Find room player is in
         let Some(room_idx) = state.interior.rooms.iter()
             .position(|r: &Room| r.contains(state.player.position)) else { return };
         
         let room = &state.interior.rooms[room_idx];
         
-        // Find repair point at player position
-        let Some(point_idx) = room.repair_point_at(state.player.position) else { return };
-        
-        // Attempt repair
-        if !state.attempt_interior_repair(room_idx, point_idx, events) { return };
+        // Find repair point at player position; away from one, the Cockpit opens the shop
+        let Some(point_idx) = room.repair_point_at(state.player.position) else {
+            if room.room_type == RoomType::Cockpit {
+                state.shop_selection = 0;
+                state.phase = GamePhase::InRunShop;
+            }
+            return;
+        };
         
-        // Advance tutorial when player repairs ANY point in the target room
-        // This gives immediate positive feedback instead of requiring full room completion
+        // Work through queued repairs first; otherwise use a matching repair kit
+        // if carrying one. Paid repairs are held (see handle_partial_repair).
+        let repaired = if state.repair_queue.is_empty() {
+            state.use_repair_kit(room_idx, point_idx, events)
+        } else {
+            state.process_repair_queue(events) > 0
+        };
+        if repaired {
+            Self::advance_repair_tutorial(state, room_idx);
+        }
+    }
+
+    /// Advance tutorial when player repairs ANY point in the target room.
+    /// This gives immediate positive feedback instead of requiring full room completion
+    fn advance_repair_tutorial(state: &mut GameState, room_idx: usize) {
         let Some(target) = state.tutorial_state.target_room(&state.tutorial_config) else { return };
-        let room = &state.interior.rooms[room_idx];
-        if room.id == target {
+        if state.interior.rooms[room_idx].id == target {
             state.tutorial_state.advance(&state.tutorial_config);
         }
     }
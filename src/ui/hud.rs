@@ -0,0 +1,269 @@
+//! In-game HUD: stat panels, wave indicator, escape countdown and flavor popups
+
+use macroquad::prelude::*;
+use crate::state::{GameState, ViewMode};
+use crate::simulation::constants::*;
+use crate::ship::ship::ModuleType;
+use crate::ui::renderer::Renderer;
+use crate::ui::world_renderer::{enemy_color, health_color};
+use crate::enemy::entities::EnemyType;
+
+impl Renderer {
+    pub fn draw_hud(&self, state: &GameState) {
+        // HUD background bar at top
+        draw_rectangle(0.0, 0.0, screen_width(), 35.0, color_u8!(0, 0, 0, 180));
+        
+        // Power info
+        // Power info
+        let max_power: i32 = state.interior.rooms.iter()
+            .filter(|r| matches!(r.room_type, crate::ship::interior::RoomType::Module(ModuleType::Core)))
+            .map(|r| r.repair_points.len() as i32 * POWER_PER_CORE_POINT)
+            .sum();
+
+        let power_color = if state.used_power <= state.total_power { GREEN } else { RED };
+        let power_text = format!("Power: {}/{} [{}]", state.used_power, state.total_power, max_power);
+        draw_text(&power_text, 20.0, 24.0, 20.0, power_color);
+        let wave_text = format!("WAVE {}", state.wave_state.wave_number + 1);
+        draw_text(&wave_text, 20.0, 52.0, 20.0, LIGHTGRAY);
+
+        // The player's health bar is only drawn inside, so the exterior gets a heart readout
+        if state.view_mode == ViewMode::Exterior && state.player_max_health > 0.0 {
+            let pct = (state.player_health / state.player_max_health).clamp(0.0, 1.0);
+            draw_heart(130.0, 46.0, 6.0, health_color(pct));
+            draw_text(&format!("{:.0}%", pct * 100.0), 145.0, 52.0, 20.0, LIGHTGRAY);
+        }
+        
+        // Scrap
+        let scrap_text = format!("Scrap: {}", state.resources.scrap);
+        draw_text(&scrap_text, 180.0, 24.0, 20.0, ORANGE);
+        
+        // Credits
+        let credits_text = format!("Credits: {}", state.resources.credits);
+        draw_text(&credits_text, 320.0, 24.0, 20.0, YELLOW);
+        
+        // Ship integrity
+        let hp_pct = state.ship_integrity / state.ship_max_integrity;
+        let hp_color = health_color(hp_pct);
+        let hp_text = format!("Hull: {:.0}/{:.0}", state.ship_integrity, state.ship_max_integrity);
+        draw_text(&hp_text, 480.0, 24.0, 20.0, hp_color);
+
+        // Engine Status
+        let (stress_text, stress_color) = if state.engine_stress >= STRESS_THRESHOLD_CRITICAL {
+            ("ENGINE: CASCADE", RED)
+        } else if state.engine_stress >= STRESS_THRESHOLD_UNSTABLE {
+            ("ENGINE: UNSTABLE", ORANGE)
+        } else if state.engine_stress >= STRESS_THRESHOLD_STRAINED {
+            ("ENGINE: STRAINED", YELLOW)
+        } else {
+             if state.engine_stress > 0.0 {
+                 ("ENGINE: WARM", GREEN)
+             } else {
+                 ("ENGINE: STABLE", BLUE)
+             }
+        };
+        // Shake text if critical
+        let (dx, dy) = if state.engine_stress >= STRESS_THRESHOLD_CRITICAL { 
+             (macroquad::rand::gen_range(-2.0, 2.0), macroquad::rand::gen_range(-2.0, 2.0))
+        } else { (0.0, 0.0) };
+        draw_text(stress_text, 680.0 + dx, 24.0 + dy, 20.0, stress_color);
+        
+        // Nanite Alert
+        let alert_x = 900.0;
+        // Alert label flashes red while boarders are inside
+        let boarded = !state.interior_enemies.is_empty() && (state.frame_count / 20) % 2 == 0;
+        draw_text("Alert:", alert_x, 24.0, 20.0, if boarded { RED } else { WHITE });
+        // Both bars pulse once the engine tips into cascade
+        let critical = state.engine_stress >= STRESS_THRESHOLD_CRITICAL;
+        draw_threshold_bar(alert_x + 60.0, 10.0, 100.0, 14.0, state.nanite_alert, critical);
+
+        // Engine Stress
+        let stress_x = alert_x + 180.0;
+        draw_text("Stress:", stress_x, 24.0, 20.0, WHITE);
+        draw_threshold_bar(stress_x + 65.0, 10.0, 100.0, 14.0, state.engine_stress, critical);
+        
+        // Engine/Escape timer (if charging)
+        if state.engine_state == crate::state::EngineState::Charging {
+            let mins = (state.escape_timer / 60.0).floor() as i32;
+            let secs = (state.escape_timer % 60.0).floor() as i32;
+            let escape_text = format!("ESCAPE: {:02}:{:02}", mins, secs);
+            draw_text(&escape_text, screen_width() - 180.0, 48.0, 20.0, SKYBLUE);
+        }
+
+        if state.wave_state.boss_warning_timer > 0.0 {
+            let pulse = ((get_time() * 6.0).sin() * 0.5 + 0.5) as f32;
+            draw_rectangle(0.0, 60.0, screen_width(), 40.0, Color::new(0.8, 0.0, 0.0, 0.35 + 0.45 * pulse));
+            let text = format!("WARNING: BOSS INCOMING IN {:.0}s", state.wave_state.boss_warning_timer.ceil());
+            let dims = measure_text(&text, None, 28, 1.0);
+            draw_text(&text, (screen_width() - dims.width) / 2.0, 88.0, 28.0, WHITE);
+        }
+
+        self.draw_wave_indicator(state);
+    }
+
+    /// Escape progress traced clockwise around the screen edge from the top-left
+    /// corner, shifting blue to green and flashing once nearly done
+    pub fn draw_escape_countdown_ring(&self, state: &GameState) {
+        let progress = (1.0 - state.escape_timer / ENGINE_CHARGE_BASE_TIME).clamp(0.0, 1.0);
+        let inset = ESCAPE_RING_THICKNESS / 2.0;
+        let (w, h) = (screen_width() - inset, screen_height() - inset);
+        let corners = [vec2(inset, inset), vec2(w, inset), vec2(w, h), vec2(inset, h), vec2(inset, inset)];
+        let perimeter: f32 = corners.windows(2).map(|c| c[0].distance(c[1])).sum();
+
+        let mut color = Color::new(
+            BLUE.r + (GREEN.r - BLUE.r) * progress,
+            BLUE.g + (GREEN.g - BLUE.g) * progress,
+            BLUE.b + (GREEN.b - BLUE.b) * progress,
+            1.0,
+        );
+        if progress > ESCAPE_RING_FLASH_PROGRESS {
+            color.a = (get_time() * 10.0).sin() as f32 * 0.5 + 0.5;
+        }
+
+        let mut remaining = progress * perimeter;
+        for edge in corners.windows(2) {
+            let (from, to) = (edge[0], edge[1]);
+            let reach = remaining.min(from.distance(to));
+            if reach <= 0.0 {
+                break;
+            }
+            let end = from + (to - from).normalize_or_zero() * reach;
+            draw_line(from.x, from.y, end.x, end.y, ESCAPE_RING_THICKNESS, color);
+            remaining -= reach;
+        }
+    }
+
+    /// Quips for pickups, repairs and kills, rising and fading over their lifetime
+    pub fn draw_flavor_popups(&self, state: &GameState) {
+        for popup in state.flavor_popups.iter().filter(|p| p.view == state.view_mode) {
+            let screen = match popup.view {
+                ViewMode::Exterior => self.world_to_screen(popup.position),
+                ViewMode::Interior => popup.position + self.cam_offset,
+            };
+            let life = (popup.timer / FLAVOR_POPUP_DURATION).clamp(0.0, 1.0);
+            let y = screen.y - 20.0 - (1.0 - life) * FLAVOR_POPUP_DRIFT;
+            let w = measure_text(&popup.text, None, 20, 1.0).width;
+            draw_text(&popup.text, screen.x - w / 2.0, y, 20.0, Color::new(1.0, 0.9, 0.4, life));
+        }
+    }
+
+    /// Centered banner announcing a new wave tier, fading in and out over its timer
+    pub fn draw_wave_summary(&self) {
+        if self.wave_summary_timer <= 0.0 { return; }
+        let elapsed = WAVE_SUMMARY_DURATION - self.wave_summary_timer;
+        let alpha = (elapsed / WAVE_SUMMARY_FADE).min(self.wave_summary_timer / WAVE_SUMMARY_FADE).clamp(0.0, 1.0);
+
+        let banner_h = 90.0;
+        let y = (screen_height() - banner_h) / 2.0;
+        draw_rectangle(0.0, y, screen_width(), banner_h, Color::new(0.6, 0.0, 0.0, 0.5 * alpha));
+
+        let title = format!("WAVE TIER {} ACTIVE", self.wave_summary_tier);
+        let title_w = measure_text(&title, None, 36, 1.0).width;
+        draw_text(&title, (screen_width() - title_w) / 2.0, y + 40.0, 36.0, Color::new(1.0, 1.0, 1.0, alpha));
+        let subtitle = "Enemies are stronger!";
+        let subtitle_w = measure_text(subtitle, None, 22, 1.0).width;
+        draw_text(subtitle, (screen_width() - subtitle_w) / 2.0, y + 70.0, 22.0, Color::new(1.0, 0.8, 0.8, alpha));
+    }
+
+    /// Lower-left panel showing time until the next drone/guard spawn
+    fn draw_wave_indicator(&self, state: &GameState) {
+        let panel_w = 220.0;
+        let panel_h = 70.0;
+        let x = 10.0;
+        let y = screen_height() - panel_h - 10.0;
+
+        draw_rectangle(x, y, panel_w, panel_h, color_u8!(0, 0, 0, 180));
+        draw_rectangle_lines(x, y, panel_w, panel_h, 1.0, GRAY);
+
+        if state.engine_state == crate::state::EngineState::Charging {
+            let pulse = ((get_time() * 4.0).sin() * 0.5 + 0.5) as f32;
+            let color = Color::new(1.0, 0.2 * pulse, 0.2 * pulse, 0.6 + 0.4 * pulse);
+            let text = "BOSS INCOMING";
+            let dims = measure_text(text, None, 24, 1.0);
+            draw_text(text, x + (panel_w - dims.width) / 2.0, y + panel_h / 2.0 + 8.0, 24.0, color);
+            return;
+        }
+
+        draw_text("INCOMING", x + 8.0, y + 16.0, 16.0, LIGHTGRAY);
+
+        if state.total_power < WAVE_GRACE_POWER {
+            draw_text("No spawns (low power)", x + 8.0, y + 40.0, 16.0, GRAY);
+            return;
+        }
+
+        let (drone_interval, guard_interval) =
+            crate::enemy::ai::spawn_intervals(state.total_power, &state.upgrades, state.enemy_scaling().spawn_interval);
+
+        let mut rows = vec![(EnemyType::Nanodrone, drone_interval, state.wave_state.spawn_timer)];
+        if guard_interval < f32::MAX {
+            rows.push((EnemyType::Nanoguard, guard_interval, state.wave_state.guard_timer));
+        }
+
+        let bar_x = x + 28.0;
+        let bar_w = 140.0;
+        for (i, (enemy_type, interval, timer)) in rows.into_iter().enumerate() {
+            let row_y = y + 30.0 + i as f32 * 20.0;
+            let remaining = (interval - timer).max(0.0);
+            let fraction = (remaining / interval).clamp(0.0, 1.0);
+            let bar_color = if fraction < WAVE_WARNING_FRACTION { RED } else { enemy_color(&enemy_type) };
+
+            draw_circle(x + 16.0, row_y + 6.0, 6.0, enemy_color(&enemy_type));
+            draw_rectangle(bar_x, row_y, bar_w, 12.0, DARKGRAY);
+            draw_rectangle(bar_x, row_y, bar_w * fraction, 12.0, bar_color);
+            draw_text(&format!("{:.0}s", remaining.ceil()), bar_x + bar_w + 6.0, row_y + 11.0, 16.0, WHITE);
+        }
+    }
+}
+
+/// HUD bar on the engine stress scale: tick marks at each stress threshold and a
+/// fill that shifts green -> yellow -> orange -> red as it crosses them
+fn draw_threshold_bar(x: f32, y: f32, w: f32, h: f32, value: f32, pulse: bool) {
+    let pct = (value / HUD_THRESHOLD_BAR_MAX).clamp(0.0, 1.0);
+    let mut fill = threshold_color(value);
+    if pulse {
+        fill.a = 0.6 + 0.4 * (get_time() * 6.0).sin() as f32;
+    }
+    draw_rectangle(x, y, w, h, DARKGRAY);
+    draw_rectangle(x, y, w * pct, h, fill);
+
+    let ticks = [
+        (STRESS_THRESHOLD_STRAINED, "STR"),
+        (STRESS_THRESHOLD_UNSTABLE, "UNS"),
+        (STRESS_THRESHOLD_CRITICAL, "CRIT"),
+    ];
+    for (threshold, label) in ticks {
+        let tx = x + w * (threshold / HUD_THRESHOLD_BAR_MAX).min(1.0);
+        draw_line(tx, y - 2.0, tx, y + h + 2.0, 1.0, WHITE);
+        let label_w = measure_text(label, None, 10, 1.0).width;
+        draw_text(label, tx - label_w / 2.0, y + h + 10.0, 10.0, LIGHTGRAY);
+    }
+}
+
+/// Small heart centered on (x, y): two lobes over a downward triangle
+fn draw_heart(x: f32, y: f32, r: f32, color: Color) {
+    draw_circle(x - r * 0.5, y - r * 0.3, r * 0.6, color);
+    draw_circle(x + r * 0.5, y - r * 0.3, r * 0.6, color);
+    draw_triangle(vec2(x - r * 1.05, y - r * 0.1), vec2(x + r * 1.05, y - r * 0.1), vec2(x, y + r), color);
+}
+
+/// Stress color, lerped between the stop at each threshold
+fn threshold_color(value: f32) -> Color {
+    let stops = [
+        (0.0, GREEN),
+        (STRESS_THRESHOLD_STRAINED, YELLOW),
+        (STRESS_THRESHOLD_UNSTABLE, ORANGE),
+        (STRESS_THRESHOLD_CRITICAL, RED),
+    ];
+    for pair in stops.windows(2) {
+        let ((lo, from), (hi, to)) = (pair[0], pair[1]);
+        if value < hi {
+            let t = ((value - lo) / (hi - lo)).clamp(0.0, 1.0);
+            return Color::new(
+                from.r + (to.r - from.r) * t,
+                from.g + (to.g - from.g) * t,
+                from.b + (to.b - from.b) * t,
+                1.0,
+            );
+        }
+    }
+    RED
+}
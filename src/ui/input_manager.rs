@@ -1,6 +1,11 @@
 use macroquad::prelude::*;
-use crate::state::{GameState, GamePhase};
+use crate::state::{GameState, GamePhase, ViewMode};
+use crate::ship::layout::Layout;
 use crate::simulation::events::EventBus;
+use crate::ui::gamepad::{GamepadFrame, Gamepads};
+use crate::ui::touch::{TouchFrame, TouchTracker};
+use crate::data::settings::KeyBindings;
+use crate::simulation::constants::{ZOOM_MIN, ZOOM_MAX, ZOOM_STEP};
 
 /// Captures current input state for the frame
 #[derive(Debug, Clone)]
@@ -16,43 +21,115 @@ pub struct InputState {
     pub pause_pressed: bool,
     pub tab_pressed: bool,
     pub interact_pressed: bool,
+    pub interact_held: bool, // Interact key, gamepad A or virtual touch button held down
+    pub craft_pressed: bool,
+    pub door_pressed: bool,
+    pub shift_held: bool, // Either Shift key, the modifier for Shift+click and Shift+number
+    pub sprint_held: bool, // Sprint key or right bumper held down
+    pub gamepad_move: Vec2,
+    pub touch_move: Vec2,
 }
 
 impl InputState {
-    pub fn capture() -> Self {
+    pub fn capture(gamepad: Option<&GamepadFrame>, touch: &TouchFrame, bindings: &KeyBindings) -> Self {
+        let pad = gamepad.cloned().unwrap_or_default();
         Self {
-            mouse_pos: mouse_position().into(),
+            mouse_pos: touch.tap.unwrap_or_else(|| mouse_position().into()),
             mouse_world_pos: None,
-            left_click: is_mouse_button_pressed(MouseButton::Left),
+            left_click: is_mouse_button_pressed(MouseButton::Left) || touch.tap.is_some(),
             right_click: is_mouse_button_pressed(MouseButton::Right),
-            escape_pressed: is_key_pressed(KeyCode::Escape),
+            escape_pressed: is_key_pressed(KeyCode::Escape) || pad.back_pressed || touch.two_finger_tap,
             enter_pressed: is_key_pressed(KeyCode::Enter),
             space_pressed: is_key_pressed(KeyCode::Space),
-            pause_pressed: is_key_pressed(KeyCode::P),
-            tab_pressed: is_key_pressed(KeyCode::Tab),
-            interact_pressed: is_key_pressed(KeyCode::E),
+            pause_pressed: is_key_pressed(bindings.pause),
+            tab_pressed: is_key_pressed(bindings.toggle_view) || pad.tab_pressed,
+            interact_pressed: is_key_pressed(bindings.interact) || pad.interact_pressed
+                || touch.double_tap || touch.interact_pressed,
+            interact_held: is_key_down(bindings.interact) || pad.interact_held || touch.interact_held,
+            craft_pressed: is_key_pressed(bindings.craft),
+            door_pressed: is_key_pressed(bindings.door),
+            shift_held: is_key_down(KeyCode::LeftShift) || is_key_down(KeyCode::RightShift),
+            sprint_held: is_key_down(bindings.sprint) || pad.sprint_held,
+            gamepad_move: pad.move_dir,
+            touch_move: touch.move_dir,
         }
     }
 }
 
 pub struct InputManager {
-    // Current frame state
+    /// Active gamepad (first one to send input)
+    pub gamepad_index: Option<usize>,
+    /// Grid cell with a module under the mouse (exterior view only)
+    pub hovered_module: Option<(usize, usize)>,
+    gamepads: Gamepads,
+    touch: TouchTracker,
 }
 
 impl InputManager {
     pub fn new() -> Self {
-        Self {}
+        Self {
+            gamepad_index: None,
+            hovered_module: None,
+            gamepads: Gamepads::new(),
+            touch: TouchTracker::default(),
+        }
     }
 
     pub fn update(&mut self, state: &mut GameState, events: &mut EventBus) {
-        let input = InputState::capture();
+        #[cfg(debug_assertions)]
+        if self.handle_debug_console_input(state) {
+            return;
+        }
+
+        let gamepad = if state.settings.gamepad_enabled {
+            Some(self.gamepads.poll(&mut self.gamepad_index))
+        } else {
+            None
+        };
+        let touch = self.touch.poll(state.touch_buttons_visible());
+        let mut input = InputState::capture(gamepad.as_ref(), &touch, &state.settings.key_bindings);
+
+        // Mouse wheel zooms the exterior view; grid picking has to undo the zoom
+        if state.phase == GamePhase::Playing && state.view_mode == ViewMode::Exterior {
+            let (_, wheel_y) = mouse_wheel();
+            if wheel_y != 0.0 {
+                let zoom = (state.settings.zoom + wheel_y.signum() * ZOOM_STEP).clamp(ZOOM_MIN, ZOOM_MAX);
+                if zoom != state.settings.zoom {
+                    state.settings.zoom = zoom;
+                    let _ = state.settings.save();
+                }
+            }
+        }
+        let center = vec2(screen_width(), screen_height()) / 2.0;
+        let mouse_world = center + (input.mouse_pos - center) / state.settings.zoom.clamp(ZOOM_MIN, ZOOM_MAX);
+        input.mouse_world_pos = Layout::screen_to_grid(mouse_world);
+
+        self.hovered_module = match (state.phase, state.view_mode, input.mouse_world_pos) {
+            (GamePhase::Playing, ViewMode::Exterior, Some((x, y))) if state.ship.grid[x][y].is_some() => Some((x, y)),
+            _ => None,
+        };
+        // Stick input wins over touch; either feeds Player::update's analog movement
+        state.gamepad_move = [input.gamepad_move, input.touch_move].into_iter().find(|v| *v != Vec2::ZERO);
+        state.sprint_held = input.sprint_held;
 
         match state.phase {
-            GamePhase::Menu => self.handle_menu_input(&input, events),
+            GamePhase::Menu => self.handle_menu_input(&input, state, events),
             GamePhase::Playing => self.handle_gameplay_input(&input, state, events),
             GamePhase::GameOver => self.handle_game_over_input(&input, events),
             GamePhase::Victory => self.handle_victory_input(&input, events),
+            GamePhase::VictoryAnimation => self.handle_victory_animation_input(&input, state),
             GamePhase::InterRound => self.handle_upgrade_input(&input, state, events),
+            GamePhase::SaveSlotSelect { mode } => self.handle_slot_select_input(&input, state, mode, events),
+            GamePhase::DifficultySelect => self.handle_difficulty_select_input(&input, state, events),
+            GamePhase::ShipSelect { selected } => self.handle_ship_select_input(&input, state, selected, events),
+            GamePhase::Achievements => self.handle_achievements_input(&input, state),
+            GamePhase::HighScores => self.handle_high_scores_input(&input, state),
+            GamePhase::SeedEntry => self.handle_seed_entry_input(&input, state),
+            GamePhase::DailyChallenge => self.handle_daily_challenge_input(&input, state, events),
+            GamePhase::KeybindCapture { action } => self.handle_keybind_capture_input(state, action),
+            GamePhase::Crafting => self.handle_crafting_input(&input, state),
+            GamePhase::InRunShop => self.handle_in_run_shop_input(&input, state),
+            GamePhase::Codex { selected_entry } => self.handle_codex_input(&input, state, selected_entry),
         }
     }
 }
@@ -0,0 +1,661 @@
+//! Interior view: rooms, player, boarders, scrap piles and repair prompts
+
+use macroquad::prelude::*;
+use crate::state::GameState;
+use crate::simulation::constants::*;
+use crate::ship::ship::ModuleType;
+use crate::ship::interior::{REPAIR_POINT_SIZE, Room, RoomType};
+use crate::ui::renderer::Renderer;
+use crate::ui::touch::{virtual_dpad, virtual_interact_button};
+use crate::simulation::environment::{EnvEventKind, EnvironmentEvent};
+
+impl Renderer {
+    pub fn draw_interior(&self, state: &GameState) {
+        let interior = &state.interior;
+        
+        // Camera offset eased toward the player in Renderer::update
+        let (cam_x, cam_y) = (self.cam_offset.x, self.cam_offset.y);
+        
+        // Background (void)
+        draw_rectangle(0.0, 0.0, screen_width(), screen_height(), color_u8!(10, 10, 15, 255));
+        
+        // Draw all interior elements
+        self.draw_rooms(state, cam_x, cam_y);
+        self.draw_player(state, cam_x, cam_y);
+        self.draw_interior_enemies(state, cam_x, cam_y);
+        self.draw_scrap_piles(state, cam_x, cam_y);
+        self.draw_repair_prompt(state, cam_x, cam_y);
+        self.draw_collapsed_room_prompt(state, cam_x, cam_y);
+        if let Some(room) = interior.room_at(state.player.position) {
+            self.draw_room_repair_overview(state, room, cam_x, cam_y);
+        }
+
+        if state.show_map {
+            let scale = INTERIOR_MAP_SCALE;
+            let map_x = (screen_width() - interior.width * scale) / 2.0;
+            let map_y = (screen_height() - interior.height * scale) / 2.0;
+            self.draw_rooms_minimap(state, map_x, map_y, scale);
+        }
+
+        if state.touch_buttons_visible() {
+            self.draw_touch_controls();
+        }
+    }
+
+    /// Semi-transparent on-screen D-pad and interact button for touch screens
+    fn draw_touch_controls(&self) {
+        let fill = color_u8!(255, 255, 255, 50);
+        let outline = color_u8!(255, 255, 255, 120);
+        for (rect, dir) in virtual_dpad() {
+            draw_rectangle(rect.x, rect.y, rect.w, rect.h, fill);
+            draw_rectangle_lines(rect.x, rect.y, rect.w, rect.h, 2.0, outline);
+            // Arrow pointing in the button's direction
+            let c = rect.center();
+            let side = vec2(-dir.y, dir.x);
+            draw_triangle(c + dir * 14.0, c - dir * 8.0 + side * 12.0, c - dir * 8.0 - side * 12.0, outline);
+        }
+
+        let (center, radius) = virtual_interact_button();
+        draw_circle(center.x, center.y, radius, fill);
+        draw_circle_lines(center.x, center.y, radius, 2.0, outline);
+        let label_w = measure_text("E", None, 32, 1.0).width;
+        draw_text("E", center.x - label_w / 2.0, center.y + 11.0, 32.0, outline);
+    }
+
+    /// Scaled-down overview of every room with repair progress and the player's position.
+    /// Enemies are left off: from inside the ship the player isn't tracking the exterior.
+    fn draw_rooms_minimap(&self, state: &GameState, origin_x: f32, origin_y: f32, scale: f32) {
+        let interior = &state.interior;
+        let pad = 12.0;
+        let (map_w, map_h) = (interior.width * scale, interior.height * scale);
+        draw_rectangle(origin_x - pad, origin_y - pad, map_w + pad * 2.0, map_h + pad * 2.0, color_u8!(0, 0, 0, 204));
+        draw_rectangle_lines(origin_x - pad, origin_y - pad, map_w + pad * 2.0, map_h + pad * 2.0, 1.0, color_u8!(80, 80, 120, 204));
+
+        for room in &interior.rooms {
+            if room.room_type == RoomType::Empty { continue; }
+            let (rx, ry) = (origin_x + room.x * scale, origin_y + room.y * scale);
+            let (rw, rh) = (room.width * scale, room.height * scale);
+            let mut color = room.color();
+            color.a = 0.8;
+            draw_rectangle(rx, ry, rw, rh, color);
+            draw_rectangle_lines(rx, ry, rw, rh, 1.0, color_u8!(120, 120, 140, 204));
+
+            let name = room.name();
+            if !name.is_empty() {
+                draw_text(name, rx + 2.0, ry + 10.0, 10.0, WHITE);
+            }
+            if !room.repair_points.is_empty() {
+                let pct = room.repaired_count() * 100 / room.repair_points.len();
+                let pct_color = if pct == 100 { GREEN } else { YELLOW };
+                draw_text(&format!("{}%", pct), rx + 2.0, ry + 20.0, 10.0, pct_color);
+            }
+        }
+
+        let player = state.player.position;
+        draw_circle(origin_x + player.x * scale, origin_y + player.y * scale, 3.0, SKYBLUE);
+
+        draw_text("SHIP MAP [M]", origin_x, origin_y - pad - 4.0, 16.0, LIGHTGRAY);
+    }
+
+    fn draw_rooms(&self, state: &GameState, cam_x: f32, cam_y: f32) {
+        let tile_size = 64.0; // Tile size
+        
+        for room in &state.interior.rooms {
+            let rx = cam_x + room.x;
+            let ry = cam_y + room.y;
+            
+            self.draw_room_floor(state, room, rx, ry, tile_size);
+
+            if state.settings.colorblind_mode.is_enabled() {
+                draw_room_hatch(rx, ry, room.width, room.height, room.room_type);
+            }
+
+            draw_room_damage_overlays(room, rx, ry, state.frame_count);
+            
+            // Draw walls (top edge) using tile_wall_tech if room above is empty? 
+            // Simplified: Just draw walls on the boundaries if desired, but for top-down, usually walls are just drawn.
+            // Let's draw `tile_wall_tech` along the top edge of the room.
+            if let Some(wall_tex) = state.assets.get_texture("tile_wall_tech") {
+                 let cols = (room.width / tile_size).ceil() as i32;
+                 for c in 0..cols {
+                     // Draw wall "above" the room or at the top of the room?
+                     // Usually walls take up space. Here rooms are packed. 
+                     // Let's overlay at the top edge for visual flair.
+                     draw_texture_ex(wall_tex, rx + c as f32 * tile_size, ry - 10.0, WHITE, 
+                        DrawTextureParams {
+                            dest_size: Some(vec2(tile_size, 20.0)), // Squashed wall
+                            ..Default::default()
+                        }
+                    );
+                 }
+            }
+            
+            // Tutorial highlight
+            let is_target = state.tutorial_state.should_highlight(&state.tutorial_config, room.id);
+            if is_target && !room.is_fully_repaired() {
+                let pulse = ((state.frame_count as f32 * 0.1).sin() * 0.5 + 0.5) * 155.0 + 100.0;
+                draw_rectangle_lines(rx - 2.0, ry - 2.0, room.width + 4.0, room.height + 4.0, 4.0, 
+                    Color::new(1.0, 1.0, 0.0, pulse / 255.0));
+            } else {
+                draw_rectangle_lines(rx, ry, room.width, room.height, 2.0, color_u8!(70, 70, 80, 255));
+            }
+            
+            self.draw_repair_props(state, room, rx, ry, is_target);
+            
+            // Room name
+            let name = room.name();
+            if !name.is_empty() {
+                let text_size = 18.0;
+                let text_w = measure_text(name, None, text_size as u16, 1.0).width;
+                 draw_text(name, rx + (room.width - text_w) / 2.0, ry + 24.0, text_size, WHITE);
+            }
+
+            if let Some(event) = state.environment_events.iter().find(|e| e.room_id == room.id) {
+                draw_environment_warning(rx + room.width - 28.0, ry + 10.0, event, state.frame_count);
+            }
+        }
+
+        self.draw_doors(state, cam_x, cam_y);
+    }
+
+    /// Tiled floor texture for the room type, or a flat fill when the texture is missing
+    fn draw_room_floor(&self, state: &GameState, room: &Room, rx: f32, ry: f32, tile_size: f32) {
+        let floor_tex_name = match room.room_type {
+            RoomType::Module(ModuleType::Core) => "tile_floor_core",
+            RoomType::Module(ModuleType::Weapon) => "tile_floor_weapon",
+            RoomType::Module(ModuleType::Defense) => "tile_floor_defense",
+            RoomType::Module(ModuleType::Engine) => "tile_floor_engine",
+            RoomType::Module(ModuleType::Utility) => "tile_floor_utility",
+            RoomType::Medbay => "tile_floor_medbay",
+            RoomType::Cockpit => "tile_floor_cockpit",
+            RoomType::Storage => "tile_floor_storage",
+            RoomType::Corridor => "tile_floor_corridor",
+            _ => "tile_floor_corridor",
+        };
+
+        if let Some(tex) = state.assets.get_texture(floor_tex_name) {
+            // Tiling: draw texture across the room
+            let cols = (room.width / tile_size).ceil() as i32;
+            let rows = (room.height / tile_size).ceil() as i32;
+        
+            for r in 0..rows {
+                for c in 0..cols {
+                   draw_texture(tex, rx + c as f32 * tile_size, ry + r as f32 * tile_size, WHITE);
+                }
+            }
+        } else {
+             draw_rectangle(rx, ry, room.width, room.height, room.color());
+        }
+    }
+
+    /// Prop sprite for each repair point, red-tinted with sparks and hold progress while broken
+    fn draw_repair_props(&self, state: &GameState, room: &Room, rx: f32, ry: f32, is_target: bool) {
+        for (i, point) in room.repair_points.iter().enumerate() {
+            let px = rx + point.x;
+            let py = ry + point.y;
+            let half = REPAIR_POINT_SIZE / 2.0;
+            
+            // Determine prop type based on room
+            let prop_names = match room.room_type {
+                RoomType::Module(ModuleType::Core) => vec!["prop_generator_coil", "prop_console_desk"],
+                RoomType::Module(ModuleType::Weapon) => vec!["prop_ammo_loader", "prop_capacitor_bank"],
+                RoomType::Module(ModuleType::Defense) => vec!["prop_shield_emitter", "prop_console_wall"],
+                RoomType::Module(ModuleType::Engine) => vec!["prop_engine_valve", "prop_pipe_burst"],
+                RoomType::Module(ModuleType::Utility) => vec!["prop_server_rack", "prop_console_wall"],
+                RoomType::Medbay => vec!["prop_med_scanner", "prop_console_wall"], // Removed crypto_pod as it is tall
+                _ => vec!["prop_console_wall"],
+            };
+            
+            // Pick stable random prop
+            let prop_name = prop_names[(point.id + i) % prop_names.len()];
+            
+            if let Some((tex, params)) = state.assets.create_sprite(prop_name) {
+                let color = if point.repaired { WHITE } else { color_u8!(255, 150, 150, 255) }; // Red tint if broken
+                let size = params.source.map_or(tex.size(), |r| r.size());
+                let (w, h) = (size.x, size.y);
+                
+                // Center the prop
+                draw_texture_ex(tex, px - w/2.0, py - h/2.0, color, params);
+            } else {
+                // Fallback
+                if point.repaired {
+                    draw_rectangle(px - half, py - half, half * 2.0, half * 2.0, color_u8!(30, 100, 30, 255));
+                } else {
+                    draw_rectangle(px - half, py - half, half * 2.0, half * 2.0, color_u8!(100, 40, 30, 255));
+                }
+            }
+
+            if !point.repaired {
+                draw_repair_sparks(px, py, state.frame_count, is_target);
+                if point.repair_progress > 0.0 {
+                    draw_progress_arc(px, py, half + 6.0, point.repair_progress, YELLOW);
+                }
+            }
+        }
+    }
+
+    /// Doors go over both rooms' borders: a gray slab when closed, a gap in the wall when open
+    fn draw_doors(&self, state: &GameState, cam_x: f32, cam_y: f32) {
+        for door in &state.interior.doors {
+            let x = cam_x + door.position.x - door.size.x / 2.0;
+            let y = cam_y + door.position.y - door.size.y / 2.0;
+            if door.open {
+                let (gap_w, gap_h) = if door.size.x < door.size.y {
+                    (door.size.x, door.size.y - 8.0)
+                } else {
+                    (door.size.x - 8.0, door.size.y)
+                };
+                let gx = cam_x + door.position.x - gap_w / 2.0;
+                let gy = cam_y + door.position.y - gap_h / 2.0;
+                draw_rectangle(gx, gy, gap_w, gap_h, color_u8!(40, 40, 45, 255));
+            } else {
+                draw_rectangle(x, y, door.size.x, door.size.y, GRAY);
+                draw_rectangle_lines(x, y, door.size.x, door.size.y, 1.0, DARKGRAY);
+            }
+        }
+    }
+
+    fn draw_player(&self, state: &GameState, cam_x: f32, cam_y: f32) {
+        let player_screen_x = cam_x + state.player.position.x;
+        let player_screen_y = cam_y + state.player.position.y;
+        
+        draw_circle(player_screen_x, player_screen_y, state.player.size, color_u8!(100, 200, 255, 255));
+        draw_circle_lines(player_screen_x, player_screen_y, state.player.size, 2.0, WHITE);
+        
+        let facing_end = vec2(player_screen_x, player_screen_y) + state.player.facing * state.player.size;
+        draw_line(player_screen_x, player_screen_y, facing_end.x, facing_end.y, 2.0, WHITE);
+        
+        // Health bar above the player (only once hurt)
+        if state.player_health < state.player_max_health {
+            let pct = (state.player_health / state.player_max_health).clamp(0.0, 1.0);
+            let bar_w = 24.0;
+            let px = player_screen_x - bar_w / 2.0;
+            let py = player_screen_y - state.player.size - 7.0;
+            draw_rectangle(px, py, bar_w, 3.0, RED);
+            draw_rectangle(px, py, bar_w * pct, 3.0, GREEN);
+        }
+
+        // Stamina bar below the player (only once drained), orange while winded
+        let player = &state.player;
+        if player.stamina < player.max_stamina {
+            let pct = (player.stamina / player.max_stamina).clamp(0.0, 1.0);
+            let bar_w = 24.0;
+            let px = player_screen_x - bar_w / 2.0;
+            let py = player_screen_y + player.size + 4.0;
+            draw_rectangle(px, py, bar_w, 3.0, DARKGRAY);
+            draw_rectangle(px, py, bar_w * pct, 3.0, if player.winded { ORANGE } else { SKYBLUE });
+        }
+
+        // Repair combo counter, pulsing and fading as the window runs out
+        let combo_left = COMBO_REPAIR_WINDOW - (state.time_survived - state.last_repair_time);
+        if state.repair_combo > 0 && combo_left > 0.0 {
+            let text = format!("x{} COMBO", state.repair_combo + 1);
+            let size = 20.0 + 4.0 * (get_time() as f32 * 8.0).sin().abs();
+            let mut color = GOLD;
+            color.a = (combo_left / COMBO_REPAIR_WINDOW).clamp(0.3, 1.0);
+            let text_w = measure_text(&text, None, size as u16, 1.0).width;
+            draw_text(&text, player_screen_x - text_w / 2.0, player_screen_y - state.player.size - 16.0, size, color);
+        }
+
+        // Gathering progress bar
+        if state.gathering_target.is_some() && state.gathering_timer > 0.0 {
+            let progress = (state.gathering_timer / GATHERING_TIME_SECONDS).clamp(0.0, 1.0);
+            let bar_w = 40.0;
+            let bar_h = 6.0;
+            let px = player_screen_x - bar_w / 2.0;
+            let py = player_screen_y - 30.0;
+            
+            draw_rectangle(px, py, bar_w, bar_h, BLACK);
+            draw_rectangle(px, py, bar_w * progress, bar_h, GREEN);
+        }
+    }
+
+    fn draw_interior_enemies(&self, state: &GameState, cam_x: f32, cam_y: f32) {
+        for invader in &state.interior_enemies {
+            let x = cam_x + invader.position.x;
+            let y = cam_y + invader.position.y;
+            draw_circle(x, y, INVADER_SIZE, color_u8!(220, 40, 40, 255));
+            draw_circle_lines(x, y, INVADER_SIZE, 2.0, color_u8!(255, 150, 150, 255));
+
+            if invader.health < INVADER_HP {
+                let pct = (invader.health / INVADER_HP).clamp(0.0, 1.0);
+                let bar_w = 20.0;
+                draw_rectangle(x - bar_w / 2.0, y - INVADER_SIZE - 6.0, bar_w, 3.0, DARKGRAY);
+                draw_rectangle(x - bar_w / 2.0, y - INVADER_SIZE - 6.0, bar_w * pct, 3.0, RED);
+            }
+        }
+    }
+
+    fn draw_scrap_piles(&self, state: &GameState, cam_x: f32, cam_y: f32) {
+        for pile in &state.scrap_piles {
+            if !pile.active { continue; }
+            let screen_pos_x = cam_x + pile.position.x;
+            let screen_pos_y = cam_y + pile.position.y;
+            
+            draw_circle(screen_pos_x, screen_pos_y, 8.0, BROWN);
+            draw_circle(screen_pos_x, screen_pos_y, 6.0, DARKBROWN);
+            if pile.contains_schematic.is_some() {
+                draw_circle(screen_pos_x + 4.0, screen_pos_y - 4.0, 2.5, SKYBLUE);
+            }
+            
+            if pile.position.distance(state.player.position) < INTERACTION_RANGE {
+                draw_circle_lines(screen_pos_x, screen_pos_y, 12.0, 2.0, YELLOW);
+                if state.gathering_target.is_none() {
+                    draw_text("[Hold E] Scavenge", screen_pos_x - 40.0, screen_pos_y - 15.0, 16.0, WHITE);
+                }
+            }
+        }
+    }
+
+    /// Cost to clear the rubble when E would clear a collapsed room
+    fn draw_collapsed_room_prompt(&self, state: &GameState, cam_x: f32, cam_y: f32) {
+        if state.collapsed_room_to_clear().is_none() { return; }
+
+        let can_afford = state.resources.scrap >= ROOM_COLLAPSE_REPAIR_COST;
+        let (label, color) = if can_afford {
+            (format!("[E] Clear rubble ({} Scrap)", ROOM_COLLAPSE_REPAIR_COST), YELLOW)
+        } else {
+            (format!("Need {} Scrap to clear rubble", ROOM_COLLAPSE_REPAIR_COST), RED)
+        };
+        draw_text(&label, cam_x + state.player.position.x - 60.0, cam_y + state.player.position.y - 36.0, 16.0, color);
+    }
+
+    fn draw_repair_prompt(&self, state: &GameState, cam_x: f32, cam_y: f32) {
+        let interior = &state.interior;
+        let Some(room) = interior.room_at(state.player.position) else { return };
+        let Some(point_idx) = room.repair_point_at(state.player.position) else { return };
+        if room.repair_points[point_idx].repaired { return; }
+        
+        let Some(room_idx) = interior.rooms.iter().position(|r| r.id == room.id) else { return };
+        let Some((scrap_cost, power_cost)) = state.get_repair_cost(room_idx, point_idx) else { return };
+        
+        let player_screen_x = cam_x + state.player.position.x;
+        let player_screen_y = cam_y + state.player.position.y;
+        
+        let is_reactor = power_cost == 0;
+        let can_afford_scrap = state.resources.scrap >= scrap_cost;
+        let can_afford_power = is_reactor || (state.used_power + power_cost <= state.total_power);
+        
+        let cost_text = if is_reactor {
+            format!("{scrap_cost} Scrap")
+        } else {
+            format!("{scrap_cost} Scrap + {power_cost} Power")
+        };
+        
+        let label = if can_afford_scrap && can_afford_power {
+            format!("[Hold E] Repair ({})", cost_text)
+        } else if !can_afford_scrap {
+            format!("Need {scrap_cost} Scrap")
+        } else {
+            format!("Need {power_cost} Power (Repair Reactor)")
+        };
+        
+        let color = if can_afford_scrap && can_afford_power { YELLOW } else { RED };
+        draw_text(&label, player_screen_x - 60.0, player_screen_y - 20.0, 16.0, color);
+
+        // Projected total for the mouse-wheel repair queue
+        if !state.repair_queue.is_empty() {
+            let (queue_scrap, queue_power) = state.repair_queue_cost();
+            let affordable = state.resources.scrap >= queue_scrap
+                && state.used_power + queue_power <= state.total_power;
+            let queue_text = format!("Queue x{}: {queue_scrap} Scrap + {queue_power} Power", state.repair_queue.len());
+            draw_text(&queue_text, player_screen_x - 60.0, player_screen_y - 4.0, 16.0, if affordable { YELLOW } else { RED });
+        }
+    }
+
+    /// Cost summary for every repair point in the room the player is standing in
+    fn draw_room_repair_overview(&self, state: &GameState, room: &Room, cam_x: f32, cam_y: f32) {
+        if room.repair_points.is_empty() { return; }
+        let Some(room_idx) = state.interior.rooms.iter().position(|r| r.id == room.id) else { return };
+
+        let row_h = 16.0;
+        let panel_w = 150.0;
+        let panel_h = 8.0 + room.repair_points.len() as f32 * row_h;
+        let px = cam_x + room.x + 4.0;
+        let py = cam_y + room.y + 4.0;
+        draw_rectangle(px, py, panel_w, panel_h, color_u8!(0, 0, 0, 170));
+        draw_rectangle_lines(px, py, panel_w, panel_h, 1.0, color_u8!(80, 80, 120, 255));
+
+        for (point_idx, point) in room.repair_points.iter().enumerate() {
+            let y = py + 4.0 + point_idx as f32 * row_h;
+            let text_y = y + 12.0;
+            let queued = state.repair_queue.contains(&(room_idx, point_idx));
+            draw_text(&format!("#{}", point_idx + 1), px + 6.0, text_y, 14.0, if queued { ORANGE } else { LIGHTGRAY });
+
+            if point.repaired {
+                // Checkmark
+                let cx = px + 40.0;
+                draw_line(cx, y + 8.0, cx + 4.0, y + 12.0, 2.0, GREEN);
+                draw_line(cx + 4.0, y + 12.0, cx + 11.0, y + 3.0, 2.0, GREEN);
+                continue;
+            }
+
+            let Some((scrap_cost, power_cost)) = state.get_repair_cost(room_idx, point_idx) else { continue };
+            let scrap_color = if state.resources.scrap >= scrap_cost { YELLOW } else { RED };
+            let power_color = if power_cost == 0 || state.used_power + power_cost <= state.total_power {
+                SKYBLUE
+            } else {
+                RED
+            };
+            draw_text(&format!("{} scrap", scrap_cost), px + 36.0, text_y, 14.0, scrap_color);
+            draw_text(&format!("{} pwr", power_cost), px + 100.0, text_y, 14.0, power_color);
+        }
+    }
+}
+
+/// Flashing warning triangle in a room's corner for an active power surge or hull breach
+fn draw_environment_warning(x: f32, y: f32, event: &EnvironmentEvent, frame_count: u64) {
+    let color = match event.kind {
+        EnvEventKind::PowerSurge => YELLOW,
+        EnvEventKind::HullBreach => RED,
+    };
+    let alpha = if (frame_count / 15) % 2 == 0 { 1.0 } else { 0.5 };
+    let size = 18.0;
+    draw_triangle(
+        vec2(x + size / 2.0, y),
+        vec2(x, y + size),
+        vec2(x + size, y + size),
+        Color::new(color.r, color.g, color.b, alpha),
+    );
+    draw_text("!", x + size / 2.0 - 3.0, y + size - 3.0, 18.0, BLACK);
+
+    if event.kind == EnvEventKind::HullBreach && event.seal_progress > 0.0 {
+        let pct = (event.seal_progress / ENV_BREACH_SEAL_TIME).clamp(0.0, 1.0);
+        draw_rectangle(x - 6.0, y + size + 4.0, 30.0, 3.0, DARKGRAY);
+        draw_rectangle(x - 6.0, y + size + 4.0, 30.0 * pct, 3.0, SKYBLUE);
+    }
+}
+
+/// Fire tint, structural damage darkening and the collapsed label over a room
+fn draw_room_damage_overlays(room: &Room, rx: f32, ry: f32, frame_count: u64) {
+    // Fire tint
+    if room.is_burning() {
+        let flicker = (frame_count as f32 * 0.3).sin() * 0.05;
+        draw_rectangle(rx, ry, room.width, room.height,
+            Color::new(1.0, 0.3, 0.05, (room.fire_level * 0.5 + flicker).clamp(0.0, 0.6)));
+    }
+
+    // Structural damage darkens the room until it collapses
+    if room.room_damage > 0.0 {
+        draw_rectangle(rx, ry, room.width, room.height, Color::new(0.0, 0.0, 0.0, room.room_damage * ROOM_DAMAGE_MAX_DARKEN));
+    }
+    if room.is_collapsed() {
+        let label = "COLLAPSED";
+        let label_w = measure_text(label, None, 20, 1.0).width;
+        draw_text(label, rx + (room.width - label_w) / 2.0, ry + room.height / 2.0, 20.0, RED);
+    }
+}
+
+/// Clockwise arc from 12 o'clock covering `progress` (0-1) of a circle, built from line segments
+fn draw_progress_arc(x: f32, y: f32, radius: f32, progress: f32, color: Color) {
+    const SEGMENTS: usize = 32;
+    let start = -std::f32::consts::FRAC_PI_2;
+    let filled = (progress.clamp(0.0, 1.0) * SEGMENTS as f32).ceil() as usize;
+    let step = std::f32::consts::TAU * progress.clamp(0.0, 1.0) / filled.max(1) as f32;
+    for i in 0..filled {
+        let a0 = start + i as f32 * step;
+        let a1 = a0 + step;
+        draw_line(x + a0.cos() * radius, y + a0.sin() * radius, x + a1.cos() * radius, y + a1.sin() * radius, 3.0, color);
+    }
+}
+
+/// Sparks bursting out of a broken repair point. Derived purely from the frame
+/// count so the renderer stays immutable; tutorial targets burst bigger and faster.
+fn draw_repair_sparks(x: f32, y: f32, frame_count: u64, highlighted: bool) {
+    let (period, reach, count) = if highlighted { (15, 14.0, 6) } else { (30, 6.0, 3) }; // 30 frames = 0.5s
+    let t = (frame_count % period) as f32 / period as f32;
+    let burst = (frame_count / period) as f32;
+    let color = Color::new(1.0, 0.6, 0.1, 1.0 - t);
+
+    for i in 0..count {
+        let angle = burst * 0.3 + i as f32 * std::f32::consts::TAU / count as f32;
+        let offset = vec2(angle.cos(), angle.sin()) * reach * (0.3 + t);
+        draw_circle(x + offset.x, y + offset.y, 2.0, color);
+    }
+}
+
+/// Interior camera offset: center the interior if it fits on screen (with
+/// `CAMERA_MARGIN` either side), otherwise follow `focus` and stop the margin
+/// past each outer wall
+pub(crate) fn compute_camera_offset(screen: Vec2, interior_size: Vec2, focus: Vec2) -> Vec2 {
+    let axis = |screen: f32, size: f32, focus: f32| {
+        if size + CAMERA_MARGIN * 2.0 <= screen {
+            (screen - size) / 2.0
+        } else {
+            (screen / 2.0 - focus).clamp(screen - size - CAMERA_MARGIN, CAMERA_MARGIN)
+        }
+    };
+    vec2(
+        axis(screen.x, interior_size.x, focus.x),
+        axis(screen.y, interior_size.y, focus.y),
+    )
+}
+
+/// Thin line pattern per room type so rooms differ by more than floor color
+fn draw_room_hatch(x: f32, y: f32, w: f32, h: f32, room_type: RoomType) {
+    let color = color_u8!(255, 255, 255, 40);
+    let spacing = 16.0;
+
+    // Stroke sets: lines across, lines down, "/" and "\" diagonals, "+" marks and dots
+    const HORIZONTAL: u8 = 1;
+    const VERTICAL: u8 = 2;
+    const DIAGONAL: u8 = 4;
+    const ANTI_DIAGONAL: u8 = 8;
+    const PLUS: u8 = 16;
+    const DOTS: u8 = 32;
+    let strokes = match room_type {
+        RoomType::Module(ModuleType::Core) => HORIZONTAL | VERTICAL,
+        RoomType::Module(ModuleType::Weapon) => DIAGONAL,
+        RoomType::Module(ModuleType::Defense) => HORIZONTAL,
+        RoomType::Module(ModuleType::Engine) => VERTICAL,
+        RoomType::Module(ModuleType::Utility) => HORIZONTAL | DIAGONAL,
+        RoomType::Module(ModuleType::DroneBay) => VERTICAL | DIAGONAL,
+        RoomType::Cockpit => ANTI_DIAGONAL,
+        RoomType::Medbay => PLUS,
+        RoomType::Storage => DIAGONAL | ANTI_DIAGONAL,
+        RoomType::Corridor => DOTS,
+        RoomType::Laboratory => HORIZONTAL | ANTI_DIAGONAL,
+        RoomType::Module(ModuleType::Empty) | RoomType::Empty => return,
+    };
+    let (horizontal, vertical) = (strokes & HORIZONTAL != 0, strokes & VERTICAL != 0);
+    let (diagonal, anti_diagonal) = (strokes & DIAGONAL != 0, strokes & ANTI_DIAGONAL != 0);
+
+    if horizontal {
+        let mut ly = y + spacing;
+        while ly < y + h {
+            draw_line(x, ly, x + w, ly, 1.0, color);
+            ly += spacing;
+        }
+    }
+    if vertical {
+        let mut lx = x + spacing;
+        while lx < x + w {
+            draw_line(lx, y, lx, y + h, 1.0, color);
+            lx += spacing;
+        }
+    }
+    if diagonal {
+        // "/" strokes clipped to the room rectangle
+        let mut d = spacing;
+        while d < w + h {
+            let (x0, y0) = (x + (d - h).max(0.0), y + d.min(h));
+            let (x1, y1) = (x + d.min(w), y + (d - w).max(0.0));
+            draw_line(x0, y0, x1, y1, 1.0, color);
+            d += spacing;
+        }
+    }
+    if anti_diagonal {
+        // "\" strokes: the "/" set mirrored left to right
+        let mut d = spacing;
+        while d < w + h {
+            let (x0, y0) = (x + w - (d - h).max(0.0), y + d.min(h));
+            let (x1, y1) = (x + w - d.min(w), y + (d - w).max(0.0));
+            draw_line(x0, y0, x1, y1, 1.0, color);
+            d += spacing;
+        }
+    }
+    if strokes & (PLUS | DOTS) != 0 {
+        // One mark per grid cell, offset half a cell so none sit on the walls
+        let mut my = y + spacing / 2.0;
+        while my < y + h {
+            let mut mx = x + spacing / 2.0;
+            while mx < x + w {
+                if strokes & PLUS != 0 {
+                    draw_line(mx - 3.0, my, mx + 3.0, my, 1.0, color);
+                    draw_line(mx, my - 3.0, mx, my + 3.0, 1.0, color);
+                } else {
+                    draw_circle(mx, my, 1.0, color);
+                }
+                mx += spacing;
+            }
+            my += spacing;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SCREEN: Vec2 = Vec2::new(800.0, 600.0);
+
+    #[test]
+    fn small_ship_is_centered_whatever_the_focus() {
+        let size = vec2(400.0, 200.0);
+        for focus in [Vec2::ZERO, size / 2.0, size] {
+            assert_eq!(compute_camera_offset(SCREEN, size, focus), vec2(200.0, 200.0));
+        }
+    }
+
+    #[test]
+    fn large_ship_follows_the_focus() {
+        let size = vec2(2000.0, 1500.0);
+        let focus = vec2(1000.0, 750.0);
+        assert_eq!(compute_camera_offset(SCREEN, size, focus), SCREEN / 2.0 - focus);
+    }
+
+    #[test]
+    fn large_ship_stops_the_margin_past_each_wall() {
+        let size = vec2(2000.0, 1500.0);
+        let top_left = compute_camera_offset(SCREEN, size, Vec2::ZERO);
+        assert_eq!(top_left, vec2(CAMERA_MARGIN, CAMERA_MARGIN));
+
+        let bottom_right = compute_camera_offset(SCREEN, size, size);
+        assert_eq!(bottom_right, SCREEN - size - vec2(CAMERA_MARGIN, CAMERA_MARGIN));
+    }
+
+    #[test]
+    fn axes_are_clamped_independently() {
+        // Wide but short: follows on x, centered on y
+        let size = vec2(2000.0, 200.0);
+        let offset = compute_camera_offset(SCREEN, size, vec2(1000.0, 0.0));
+        assert_eq!(offset, vec2(SCREEN.x / 2.0 - 1000.0, 200.0));
+    }
+
+    #[test]
+    fn ship_that_only_fits_without_margin_still_follows() {
+        // Exactly screen-sized leaves no room for the margin either side
+        let offset = compute_camera_offset(SCREEN, SCREEN, Vec2::ZERO);
+        assert_eq!(offset, vec2(CAMERA_MARGIN, CAMERA_MARGIN));
+    }
+}
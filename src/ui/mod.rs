@@ -1,9 +1,17 @@
 pub mod assets;
 pub mod renderer;
 pub mod ui_renderer;
+pub mod upgrade_screen;
+pub mod end_screen;
 pub mod world_renderer;
+pub mod interior_renderer;
+pub mod hud;
 pub mod input_manager;
+pub mod gamepad;
+pub mod touch;
 pub mod ui_input;
 pub mod gameplay_input;
 pub mod pause_menu;
 pub mod sound_manager;
+#[cfg(debug_assertions)]
+pub mod debug_console;
@@ -1,8 +1,10 @@
 //! Pause menu overlay UI
 
 use macroquad::prelude::*;
-use crate::state::GameState;
+use crate::state::{GameState, LogKind};
+use crate::simulation::constants::{SHIP_LOG_RECENT_WINDOW, SHIP_LOG_ROWS};
 use crate::ui::renderer::Renderer;
+use crate::data::settings::{BindAction, key_name};
 
 /// Pause menu state
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -11,20 +13,28 @@ pub enum PauseMenuOption {
     Settings,
     SaveGame,
     LoadGame,
+    ShipLog,
+    Codex,
     ReturnToMenu,
     ExitGame,
 }
 
 impl PauseMenuOption {
-    pub fn all() -> [PauseMenuOption; 6] {
+    /// Options shown in the pause menu; ironman runs can't be saved
+    pub fn all(ironman: bool) -> Vec<PauseMenuOption> {
         [
             PauseMenuOption::Resume,
             PauseMenuOption::Settings,
             PauseMenuOption::SaveGame,
             PauseMenuOption::LoadGame,
+            PauseMenuOption::ShipLog,
+            PauseMenuOption::Codex,
             PauseMenuOption::ReturnToMenu,
             PauseMenuOption::ExitGame,
         ]
+        .into_iter()
+        .filter(|option| !(ironman && *option == PauseMenuOption::SaveGame))
+        .collect()
     }
 
     pub fn label(&self) -> &'static str {
@@ -33,6 +43,8 @@ impl PauseMenuOption {
             PauseMenuOption::Settings => "Settings",
             PauseMenuOption::SaveGame => "Save Game",
             PauseMenuOption::LoadGame => "Load Game",
+            PauseMenuOption::ShipLog => "Ship Log",
+            PauseMenuOption::Codex => "Codex",
             PauseMenuOption::ReturnToMenu => "Return to Menu",
             PauseMenuOption::ExitGame => "Exit Game",
         }
@@ -40,13 +52,18 @@ impl PauseMenuOption {
 }
 
 impl Renderer {
-    pub fn draw_pause_menu(&self, _state: &GameState, selected: usize) {
+    pub fn draw_pause_menu(&self, state: &GameState, selected: usize) {
         // Dim background
         draw_rectangle(0.0, 0.0, screen_width(), screen_height(), color_u8!(0, 0, 0, 180));
 
-        // Menu box
+        let options = PauseMenuOption::all(state.ironman);
+        let btn_w = 200.0;
+        let btn_h = 40.0;
+        let spacing = 50.0;
+
+        // Menu box, sized to the options shown (ironman hides Save Game)
         let box_w = 300.0;
-        let box_h = 370.0;  // Increased for 6 options
+        let box_h = 70.0 + options.len() as f32 * spacing;
         let box_x = (screen_width() - box_w) / 2.0;
         let box_y = (screen_height() - box_h) / 2.0;
 
@@ -59,12 +76,8 @@ impl Renderer {
         draw_text(title, box_x + (box_w - title_w) / 2.0, box_y + 40.0, 32.0, WHITE);
 
         // Menu options
-        let options = PauseMenuOption::all();
-        let btn_w = 200.0;
-        let btn_h = 40.0;
         let btn_x = box_x + (box_w - btn_w) / 2.0;
         let start_y = box_y + 70.0;
-        let spacing = 50.0;
 
         for (i, option) in options.iter().enumerate() {
             let y = start_y + i as f32 * spacing;
@@ -92,13 +105,119 @@ impl Renderer {
         draw_text(hint, box_x + (box_w - hint_w) / 2.0, box_y + box_h - 15.0, 14.0, GRAY);
     }
 
+    /// Key binding list; `capturing` highlights the action waiting for a key press
+    pub fn draw_keybind_screen(&self, state: &GameState, capturing: Option<BindAction>) {
+        draw_rectangle(0.0, 0.0, screen_width(), screen_height(), color_u8!(0, 0, 0, 200));
+
+        let box_w = 400.0;
+        let box_h = 530.0;
+        let box_x = (screen_width() - box_w) / 2.0;
+        let box_y = (screen_height() - box_h) / 2.0;
+
+        draw_rectangle(box_x, box_y, box_w, box_h, color_u8!(25, 25, 35, 255));
+        draw_rectangle_lines(box_x, box_y, box_w, box_h, 3.0, color_u8!(80, 80, 120, 255));
+
+        let title = "KEY BINDINGS";
+        let title_w = measure_text(title, None, 32, 1.0).width;
+        draw_text(title, box_x + (box_w - title_w) / 2.0, box_y + 40.0, 32.0, WHITE);
+
+        let bindings = &state.settings.key_bindings;
+        let selected = state.keybind_selection;
+        let row_height = 40.0;
+        let start_y = box_y + 75.0;
+        let label_x = box_x + 30.0;
+        let key_x = box_x + 250.0;
+
+        for (i, action) in BindAction::ALL.iter().enumerate() {
+            let y = start_y + i as f32 * row_height;
+            let is_selected = i == selected;
+
+            if is_selected {
+                draw_rectangle(box_x + 10.0, y - 5.0, box_w - 20.0, row_height - 10.0, color_u8!(50, 50, 70, 255));
+            }
+
+            let text_color = if is_selected { YELLOW } else { WHITE };
+            draw_text(action.label(), label_x, y + 20.0, 20.0, text_color);
+
+            if capturing == Some(*action) {
+                draw_text("Press a key...", key_x, y + 20.0, 20.0, ORANGE);
+            } else {
+                let key = key_name(bindings.get(*action)).unwrap_or("?");
+                draw_text(key, key_x, y + 20.0, 20.0, SKYBLUE);
+            }
+        }
+
+        // Back button
+        let back_y = start_y + BindAction::ALL.len() as f32 * row_height;
+        let is_back_selected = selected == BindAction::ALL.len();
+        if is_back_selected {
+            draw_rectangle(box_x + 10.0, back_y - 5.0, box_w - 20.0, row_height - 10.0, color_u8!(50, 50, 70, 255));
+        }
+        let back_color = if is_back_selected { YELLOW } else { WHITE };
+        draw_text("< Back", label_x, back_y + 20.0, 20.0, back_color);
+
+        let hint = if capturing.is_some() {
+            "Press any key to bind | Esc: Cancel"
+        } else {
+            "Up/Down: Select | Enter: Rebind | Esc: Back"
+        };
+        let hint_w = measure_text(hint, None, 14, 1.0).width;
+        draw_text(hint, box_x + (box_w - hint_w) / 2.0, box_y + box_h - 15.0, 14.0, GRAY);
+    }
+
+    /// Most recent ship log entries, newest first; older entries are dimmed
+    pub fn draw_ship_log(&self, state: &GameState) {
+        draw_rectangle(0.0, 0.0, screen_width(), screen_height(), color_u8!(0, 0, 0, 200));
+
+        let box_w = 520.0;
+        let box_h = 560.0;
+        let box_x = (screen_width() - box_w) / 2.0;
+        let box_y = (screen_height() - box_h) / 2.0;
+
+        draw_rectangle(box_x, box_y, box_w, box_h, color_u8!(25, 25, 35, 255));
+        draw_rectangle_lines(box_x, box_y, box_w, box_h, 3.0, color_u8!(80, 80, 120, 255));
+
+        let title = "SHIP LOG";
+        let title_w = measure_text(title, None, 32, 1.0).width;
+        draw_text(title, box_x + (box_w - title_w) / 2.0, box_y + 40.0, 32.0, WHITE);
+
+        let row_height = 22.0;
+        let start_y = box_y + 75.0;
+
+        if state.ship_log.is_empty() {
+            draw_text("Nothing logged yet", box_x + 30.0, start_y + 16.0, 18.0, GRAY);
+        }
+
+        let recent_since = state.time_survived - SHIP_LOG_RECENT_WINDOW;
+        for (i, entry) in state.ship_log.iter().rev().take(SHIP_LOG_ROWS).enumerate() {
+            let y = start_y + i as f32 * row_height + 16.0;
+            let mut color = match entry.kind {
+                LogKind::Info => WHITE,
+                LogKind::Warning => YELLOW,
+                LogKind::Critical => RED,
+            };
+            if entry.time < recent_since {
+                color.a = 0.45;
+            }
+
+            let minutes = (entry.time / 60.0) as u32;
+            let seconds = (entry.time % 60.0) as u32;
+            draw_text(&format!("{:02}:{:02}", minutes, seconds), box_x + 30.0, y, 18.0, GRAY);
+            draw_text(&entry.message, box_x + 100.0, y, 18.0, color);
+        }
+
+        let hint = "Esc: Back";
+        let hint_w = measure_text(hint, None, 14, 1.0).width;
+        draw_text(hint, box_x + (box_w - hint_w) / 2.0, box_y + box_h - 15.0, 14.0, GRAY);
+    }
+
     pub fn draw_settings_panel(&self, state: &GameState) {
         // Dim background
         draw_rectangle(0.0, 0.0, screen_width(), screen_height(), color_u8!(0, 0, 0, 200));
 
         // Settings box
         let box_w = 400.0;
-        let box_h = 350.0;
+        let box_h = 590.0;
         let box_x = (screen_width() - box_w) / 2.0;
         let box_y = (screen_height() - box_h) / 2.0;
 
@@ -112,8 +231,8 @@ impl Renderer {
 
         let settings = &state.settings;
         let selected = state.settings_selection;
-        let row_height = 50.0;
-        let start_y = box_y + 80.0;
+        let row_height = 45.0;
+        let start_y = box_y + 75.0;
         let label_x = box_x + 30.0;
         let slider_x = box_x + 180.0;
         let slider_w = 180.0;
@@ -125,6 +244,9 @@ impl Renderer {
             ("Music Volume", settings.music_volume, true),
             ("Fullscreen", if settings.fullscreen { 1.0 } else { 0.0 }, false),
             ("Screen Shake", if settings.screen_shake { 1.0 } else { 0.0 }, false),
+            ("Minimap", if settings.show_minimap { 1.0 } else { 0.0 }, false),
+            ("Gamepad", if settings.gamepad_enabled { 1.0 } else { 0.0 }, false),
+            ("Touch Controls", if settings.touch_mode { 1.0 } else { 0.0 }, false),
         ];
 
         for (i, (label, value, is_slider)) in options.iter().enumerate() {
@@ -158,9 +280,30 @@ impl Renderer {
             }
         }
 
+        // Colorblind mode row (cycles through modes)
+        let colorblind_y = start_y + options.len() as f32 * row_height;
+        let is_colorblind_selected = selected == options.len();
+        if is_colorblind_selected {
+            draw_rectangle(box_x + 10.0, colorblind_y - 5.0, box_w - 20.0, row_height - 10.0, color_u8!(50, 50, 70, 255));
+        }
+        let colorblind_color = if is_colorblind_selected { YELLOW } else { WHITE };
+        draw_text("Colorblind", label_x, colorblind_y + 20.0, 20.0, colorblind_color);
+        let mode = settings.colorblind_mode;
+        draw_text(&format!("< {} >", mode.label()), slider_x, colorblind_y + 20.0, 20.0, if mode.is_enabled() { GREEN } else { RED });
+
+        // Key Bindings row (opens the rebind screen)
+        let keybind_y = colorblind_y + row_height;
+        let is_keybind_selected = selected == options.len() + 1;
+        if is_keybind_selected {
+            draw_rectangle(box_x + 10.0, keybind_y - 5.0, box_w - 20.0, row_height - 10.0, color_u8!(50, 50, 70, 255));
+        }
+        let keybind_color = if is_keybind_selected { YELLOW } else { WHITE };
+        draw_text("Key Bindings", label_x, keybind_y + 20.0, 20.0, keybind_color);
+        draw_text(">", slider_x, keybind_y + 20.0, 20.0, keybind_color);
+
         // Back button
-        let back_y = start_y + 5.0 * row_height;
-        let is_back_selected = selected == 5;
+        let back_y = keybind_y + row_height;
+        let is_back_selected = selected == options.len() + 2;
         if is_back_selected {
             draw_rectangle(box_x + 10.0, back_y - 5.0, box_w - 20.0, row_height - 10.0, color_u8!(50, 50, 70, 255));
         }
@@ -1,10 +1,30 @@
 use macroquad::prelude::*;
 use crate::state::{GameState, GamePhase};
 use crate::simulation::constants::*;
+use crate::simulation::events::{EventBus, GameEvent};
+use crate::ui::interior_renderer::compute_camera_offset;
 
 pub struct Renderer {
     pub trauma: f32,
     pub shake_intensity: f32,
+    /// Shake oscillations per second; high values jitter, low values roll
+    pub shake_frequency: f32,
+    /// Phase of the low-frequency shake, advanced by shake_frequency
+    pub noise_offset: f32,
+    /// Grid cell under the mouse in exterior view (for the module tooltip)
+    pub hovered_module: Option<(usize, usize)>,
+    /// Window size seen last frame, used to detect resizes
+    pub window_size: Vec2,
+    /// Exterior view zoom around the screen center (1.0 = unscaled)
+    pub zoom: f32,
+    /// Interior camera offset, eased toward the player each update
+    pub cam_offset: Vec2,
+    /// What the interior camera follows and the interior's size, set each frame
+    pub camera_focus: Vec2,
+    pub interior_size: Vec2,
+    /// Seconds left on the wave tier banner, and the tier it announces
+    pub wave_summary_timer: f32,
+    pub wave_summary_tier: u8,
 }
 
 impl Renderer {
@@ -12,18 +32,94 @@ impl Renderer {
         Self {
             trauma: 0.0,
             shake_intensity: SHAKE_INTENSITY,
+            shake_frequency: SHAKE_FREQ_HIGH,
+            noise_offset: 0.0,
+            hovered_module: None,
+            window_size: vec2(screen_width(), screen_height()),
+            zoom: 1.0,
+            cam_offset: Vec2::ZERO,
+            camera_focus: Vec2::ZERO,
+            interior_size: Vec2::ZERO,
+            wave_summary_timer: 0.0,
+            wave_summary_tier: 0,
         }
     }
 
+    pub fn set_hover(&mut self, cell: Option<(usize, usize)>) {
+        self.hovered_module = cell;
+    }
+
+    pub fn set_zoom(&mut self, zoom: f32) {
+        self.zoom = zoom.clamp(ZOOM_MIN, ZOOM_MAX);
+    }
+
+    pub fn set_interior_focus(&mut self, focus: Vec2, interior_size: Vec2) {
+        self.camera_focus = focus;
+        self.interior_size = interior_size;
+    }
+
+    /// Flash the wave tier banner for the newly reached tier
+    pub fn show_wave_summary(&mut self, tier: u8) {
+        self.wave_summary_tier = tier;
+        self.wave_summary_timer = WAVE_SUMMARY_DURATION;
+    }
+
+    /// Camera that scales the exterior world around the screen center.
+    /// World coordinates are the unzoomed screen coordinates the simulation uses.
+    pub fn exterior_camera(&self) -> Camera2D {
+        let size = vec2(screen_width(), screen_height());
+        Camera2D {
+            target: size / 2.0,
+            zoom: vec2(2.0 * self.zoom / size.x, -2.0 * self.zoom / size.y),
+            ..Default::default()
+        }
+    }
+
+    /// Where a world position ends up on screen under the exterior zoom
+    pub fn world_to_screen(&self, pos: Vec2) -> Vec2 {
+        let center = vec2(screen_width(), screen_height()) / 2.0;
+        center + (pos - center) * self.zoom
+    }
+
     /// Add trauma for screen shake (clamped to 1.0)
     pub fn add_trauma(&mut self, amount: f32) {
-        self.trauma = (self.trauma + amount).clamp(0.0, 1.0);
+        self.add_trauma_with_frequency(amount, SHAKE_FREQ_HIGH);
     }
 
-    /// Update trauma decay
-    pub fn update(&mut self, dt: f32) {
+    /// Add trauma with a shake frequency. Overlapping shakes blend their
+    /// frequencies, weighted by how much trauma each contributes.
+    pub fn add_trauma_with_frequency(&mut self, amount: f32, frequency: f32) {
+        let total = self.trauma + amount;
+        if total > 0.0 {
+            self.shake_frequency = (self.shake_frequency * self.trauma + frequency * amount) / total;
+        }
+        self.trauma = total.clamp(0.0, 1.0);
+    }
+
+    /// Update trauma decay, ease the interior camera and watch for window resizes
+    pub fn update(&mut self, dt: f32, events: &mut EventBus) {
+        let screen = vec2(screen_width(), screen_height());
+        let ideal = compute_camera_offset(screen, self.interior_size, self.camera_focus);
+        let eased = self.cam_offset.lerp(ideal, (CAMERA_LERP_SPEED * dt).min(1.0));
+        // Easing between two in-bounds offsets stays in bounds, but a resize can move the bounds
+        let min = screen - self.interior_size - Vec2::splat(CAMERA_MARGIN);
+        self.cam_offset = vec2(
+            if min.x < CAMERA_MARGIN { eased.x.clamp(min.x, CAMERA_MARGIN) } else { ideal.x },
+            if min.y < CAMERA_MARGIN { eased.y.clamp(min.y, CAMERA_MARGIN) } else { ideal.y },
+        );
+
+        self.wave_summary_timer = (self.wave_summary_timer - dt).max(0.0);
+
         if self.trauma > 0.0 {
             self.trauma = (self.trauma - dt * TRAUMA_DECAY_RATE).max(0.0);
+            self.noise_offset = (self.noise_offset + dt * self.shake_frequency * std::f32::consts::TAU)
+                % (std::f32::consts::TAU * 100.0);
+        }
+
+        let size = vec2(screen_width(), screen_height());
+        if size != self.window_size {
+            events.push_game(GameEvent::LayoutChanged { old_size: self.window_size, new_size: size });
+            self.window_size = size;
         }
     }
 
@@ -34,29 +130,84 @@ impl Renderer {
         }
 
         let shake = self.trauma * self.trauma;
-        let offset_x = macroquad::rand::gen_range(-1.0, 1.0) * self.shake_intensity * shake;
-        let offset_y = macroquad::rand::gen_range(-1.0, 1.0) * self.shake_intensity * shake;
+        let (dir_x, dir_y) = if self.shake_frequency >= SHAKE_JITTER_FREQUENCY {
+            (macroquad::rand::gen_range(-1.0, 1.0), macroquad::rand::gen_range(-1.0, 1.0))
+        } else {
+            // Out-of-step sines so the roll doesn't trace a straight line
+            (self.noise_offset.sin(), (self.noise_offset * 0.7 + 1.3).sin())
+        };
 
-        vec2(offset_x, offset_y)
+        vec2(dir_x, dir_y) * self.shake_intensity * shake
     }
 
     pub fn draw(&self, state: &GameState) {
         match state.phase {
-            GamePhase::Menu => self.draw_menu(),
+            GamePhase::Menu => self.draw_menu(state),
             GamePhase::Playing => {
                 self.draw_gameplay(state);
                 // Draw pause menu overlay if paused
                 if state.paused {
-                    if state.settings_open {
+                    if state.keybinds_open {
+                        self.draw_keybind_screen(state, None);
+                    } else if state.ship_log_open {
+                        self.draw_ship_log(state);
+                    } else if state.settings_open {
                         self.draw_settings_panel(state);
                     } else {
                         self.draw_pause_menu(state, state.pause_menu_selection);
                     }
                 }
             }
-            GamePhase::GameOver => self.draw_game_over(state),
-            GamePhase::Victory => self.draw_victory(state),
+            GamePhase::GameOver => self.draw_game_over(state, &state.profile),
+            GamePhase::Victory => self.draw_victory(state, &state.profile),
+            GamePhase::VictoryAnimation => self.draw_victory_animation(state),
             GamePhase::InterRound => self.draw_upgrade_screen(state),
+            GamePhase::SaveSlotSelect { mode } => {
+                self.draw_gameplay(state);
+                self.draw_slot_select(state, mode);
+            }
+            GamePhase::DifficultySelect => {
+                self.draw_menu(state);
+                self.draw_difficulty_select(state);
+            }
+            GamePhase::ShipSelect { selected } => {
+                self.draw_menu(state);
+                self.draw_ship_select(state, selected);
+            }
+            GamePhase::Achievements => self.draw_achievements_screen(state),
+            GamePhase::HighScores => self.draw_high_scores(state),
+            GamePhase::SeedEntry => {
+                self.draw_menu(state);
+                self.draw_seed_entry(state);
+            }
+            GamePhase::DailyChallenge => {
+                self.draw_menu(state);
+                self.draw_daily_challenge(state);
+            }
+            GamePhase::Codex { selected_entry } => {
+                self.draw_gameplay(state);
+                self.draw_codex(state, selected_entry);
+            }
+            GamePhase::Crafting => {
+                self.draw_gameplay(state);
+                self.draw_crafting_menu(state);
+            }
+            GamePhase::InRunShop => {
+                self.draw_gameplay(state);
+                self.draw_in_run_shop(state);
+            }
+            GamePhase::KeybindCapture { action } => {
+                self.draw_gameplay(state);
+                self.draw_keybind_screen(state, Some(action));
+            }
+        }
+
+        if let Some(message) = &state.toast {
+            self.draw_toast(message, state.toast_timer);
         }
+        self.draw_achievement_notifications(state);
+
+        #[cfg(debug_assertions)]
+        self.draw_debug_console(state);
     }
 }
@@ -1,9 +1,11 @@
 //! Sound Manager for game audio
 //!
-//! Handles loading and playing sound effects with volume control from settings.
+//! Handles loading and playing sound effects and layered music with volume control from settings.
 
-use macroquad::audio::{Sound, PlaySoundParams, play_sound, load_sound};
-use std::collections::HashMap;
+use macroquad::audio::{Sound, PlaySoundParams, play_sound, stop_sound, load_sound, set_sound_volume};
+use macroquad::file::load_string;
+use std::collections::{HashMap, HashSet};
+use crate::simulation::constants::{SCREEN_WIDTH, SCREEN_HEIGHT, SPATIAL_FALLOFF, SPATIAL_PAN_ATTENUATION, STRESS_THRESHOLD_CRITICAL, TURRET_SFX_MIN_INTERVAL};
 
 /// Sound effect identifiers
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -18,21 +20,46 @@ pub enum SoundEffect {
     EngineCharge,
     Victory,
     GameOver,
+    HullBreach,
+    Fire,
+    FormationWarning,
+    CarrierDrop,
+    EnemyRetreat,
 }
 
 pub struct SoundManager {
     sounds: HashMap<SoundEffect, Sound>,
+    volume_scale: HashMap<SoundEffect, f32>, // Per-effect mix levels from sounds/mix.json
+    looping: HashSet<SoundEffect>,
     enabled: bool,
+    music_base_sound: Option<Sound>,    // Ambient loop, always playing
+    music_tension_sound: Option<Sound>, // Mixed in with engine stress and nanite alert
+    music_playing: bool,
+    turret_fired_this_frame: bool,
+    turret_cooldown: f32, // Seconds until the next turret shot may be heard
 }
 
 impl SoundManager {
     pub fn new() -> Self {
         Self {
             sounds: HashMap::new(),
+            volume_scale: HashMap::new(),
+            looping: HashSet::new(),
             enabled: true,
+            music_base_sound: None,
+            music_tension_sound: None,
+            music_playing: false,
+            turret_fired_this_frame: false,
+            turret_cooldown: 0.0,
         }
     }
 
+    /// Reset per-frame rate limits. Call once per frame before playing event sounds.
+    pub fn begin_frame(&mut self, dt: f32) {
+        self.turret_fired_this_frame = false;
+        self.turret_cooldown = (self.turret_cooldown - dt).max(0.0);
+    }
+
     /// Load all sound effects asynchronously
     pub async fn load_sounds(&mut self) {
         // Map of sound effects to their file paths
@@ -47,9 +74,26 @@ impl SoundManager {
             (SoundEffect::EngineCharge, "assets/sounds/engine.wav"),
             (SoundEffect::Victory, "assets/sounds/victory.wav"),
             (SoundEffect::GameOver, "assets/sounds/gameover.wav"),
+            (SoundEffect::HullBreach, "assets/sounds/hull_breach.wav"),
+            (SoundEffect::Fire, "assets/sounds/fire.wav"),
+            (SoundEffect::FormationWarning, "assets/sounds/formation_warning.wav"),
+            (SoundEffect::CarrierDrop, "assets/sounds/carrier_drop.wav"),
+            (SoundEffect::EnemyRetreat, "assets/sounds/enemy_retreat.wav"),
         ];
 
+        // Mix levels are keyed by effect name, e.g. "ModuleDestroyed": 0.5. Missing entries play at 1.0.
+        let mix: HashMap<String, f32> = match load_string("assets/sounds/mix.json").await {
+            Ok(json) => serde_json::from_str(&json).unwrap_or_else(|e| {
+                eprintln!("Warning: Failed to parse sounds/mix.json: {}. Using unscaled volumes.", e);
+                HashMap::new()
+            }),
+            Err(_) => HashMap::new(),
+        };
+
         for (effect, path) in sound_paths {
+            if let Some(scale) = mix.get(&format!("{:?}", effect)) {
+                self.volume_scale.insert(effect, scale.max(0.0));
+            }
             match load_sound(path).await {
                 Ok(sound) => {
                     self.sounds.insert(effect, sound);
@@ -60,6 +104,9 @@ impl SoundManager {
                 }
             }
         }
+
+        self.music_base_sound = load_sound("assets/sounds/music_base.wav").await.ok();
+        self.music_tension_sound = load_sound("assets/sounds/music_tension.wav").await.ok();
     }
 
     /// Play a sound effect with the given volume (0.0 - 1.0)
@@ -79,13 +126,87 @@ impl SoundManager {
 
     /// Play a sound using settings-based volume
     pub fn play_sfx(&self, effect: SoundEffect, settings: &crate::data::settings::Settings) {
-        self.play(effect, settings.effective_sfx_volume());
+        self.play(effect, settings.effective_sfx_volume() * self.mix_level(effect));
+    }
+
+    /// Normalization multiplier for an effect's loudness (1.0 if untuned)
+    fn mix_level(&self, effect: SoundEffect) -> f32 {
+        self.volume_scale.get(&effect).copied().unwrap_or(1.0)
+    }
+
+    /// Play a sound that originates at a screen position.
+    /// Macroquad has no stereo pan, so off-center sounds are made quieter instead.
+    pub fn play_sfx_at(&self, effect: SoundEffect, world_x: f32, world_y: f32, settings: &crate::data::settings::Settings) {
+        let pan = ((world_x / SCREEN_WIDTH - 0.5) * 2.0).clamp(-1.0, 1.0);
+        let pan_scale = 1.0 - pan.abs() * SPATIAL_PAN_ATTENUATION;
+
+        let (cx, cy) = (SCREEN_WIDTH / 2.0, SCREEN_HEIGHT / 2.0);
+        let dist = ((world_x - cx).powi(2) + (world_y - cy).powi(2)).sqrt();
+        let max_dist = (cx * cx + cy * cy).sqrt();
+        let falloff = 1.0 - (dist / max_dist).clamp(0.0, 1.0) * SPATIAL_FALLOFF;
+
+        let normalized = settings.effective_sfx_volume() * self.mix_level(effect);
+        self.play(effect, normalized * pan_scale * falloff);
+    }
+
+    /// Turret shot sound, played at most once per frame and once per `TURRET_SFX_MIN_INTERVAL`
+    /// so a full battery of weapons doesn't stack dozens of copies
+    pub fn play_turret_fire(&mut self, world_x: f32, world_y: f32, settings: &crate::data::settings::Settings) {
+        if self.turret_fired_this_frame || self.turret_cooldown > 0.0 { return; }
+        self.turret_fired_this_frame = true;
+        self.turret_cooldown = TURRET_SFX_MIN_INTERVAL;
+        self.play_sfx_at(SoundEffect::TurretFire, world_x, world_y, settings);
     }
 
-    /// Play background music (placeholder for future implementation)
-    pub fn play_music(&self, settings: &crate::data::settings::Settings) {
-        let _vol = settings.effective_music_volume();
-        // TODO: Implement background music
+    /// Start or stop a looping sound effect. Safe to call every frame.
+    pub fn set_looping(&mut self, effect: SoundEffect, active: bool, settings: &crate::data::settings::Settings) {
+        let should_play = active && self.enabled;
+        let is_playing = self.looping.contains(&effect);
+        if should_play == is_playing { return; }
+
+        let Some(sound) = self.sounds.get(&effect) else { return };
+        if should_play {
+            play_sound(
+                sound,
+                PlaySoundParams {
+                    looped: true,
+                    volume: (settings.effective_sfx_volume() * self.mix_level(effect)).clamp(0.0, 1.0),
+                }
+            );
+            self.looping.insert(effect);
+        } else {
+            stop_sound(sound);
+            self.looping.remove(&effect);
+        }
+    }
+
+    /// Start both music layers looping. The tension layer starts silent and is raised by `update`.
+    pub fn play_music(&mut self, settings: &crate::data::settings::Settings) {
+        if self.music_playing { return; }
+
+        if let Some(base) = &self.music_base_sound {
+            play_sound(base, PlaySoundParams { looped: true, volume: settings.effective_music_volume().clamp(0.0, 1.0) });
+        }
+        if let Some(tension) = &self.music_tension_sound {
+            play_sound(tension, PlaySoundParams { looped: true, volume: 0.0 });
+        }
+        self.music_playing = true;
+    }
+
+    /// Mix the tension layer in as the threat rises. Call once per frame.
+    pub fn update(&mut self, engine_stress: f32, nanite_alert: f32, settings: &crate::data::settings::Settings) {
+        if !self.music_playing { return; }
+
+        // Same scales as the HUD's engine indicator and alert bar
+        let tension = (engine_stress / STRESS_THRESHOLD_CRITICAL + nanite_alert / 50.0).clamp(0.0, 1.0);
+        let music_volume = if self.enabled { settings.effective_music_volume().clamp(0.0, 1.0) } else { 0.0 };
+
+        if let Some(base) = &self.music_base_sound {
+            set_sound_volume(base, music_volume);
+        }
+        if let Some(tension_sound) = &self.music_tension_sound {
+            set_sound_volume(tension_sound, music_volume * tension);
+        }
     }
 
     /// Enable or disable all sounds
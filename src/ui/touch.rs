@@ -0,0 +1,123 @@
+//! Touch input for tablet and phone browsers
+//!
+//! A tap stands in for a left click, a double tap interacts, a two-finger tap backs out
+//! and dragging a finger moves the player. The interior view also gets on-screen
+//! buttons (see `virtual_dpad` / `virtual_interact_button`).
+
+use macroquad::prelude::*;
+
+/// Seconds between taps that still count as a double tap
+pub const DOUBLE_TAP_WINDOW: f64 = 0.3;
+/// Finger travel (px) past which a touch becomes a swipe instead of a tap
+pub const SWIPE_MIN_TRAVEL: f32 = 30.0;
+
+const DPAD_BUTTON_SIZE: f32 = 64.0;
+const DPAD_MARGIN: f32 = 30.0;
+const INTERACT_BUTTON_RADIUS: f32 = 48.0;
+
+/// Touch state sampled once per frame
+#[derive(Debug, Clone, Default)]
+pub struct TouchFrame {
+    pub tap: Option<Vec2>,   // Released without moving; acts as a left click there
+    pub double_tap: bool,    // Second tap within DOUBLE_TAP_WINDOW
+    pub two_finger_tap: bool,
+    pub move_dir: Vec2,      // Swipe direction or held D-pad button, zero when idle
+    pub interact_held: bool, // Finger resting on the virtual interact button
+    pub interact_pressed: bool,
+}
+
+/// Tracks the gesture in progress across frames
+#[derive(Default)]
+pub struct TouchTracker {
+    primary: Option<(u64, Vec2)>, // Finger that started the gesture and where
+    max_fingers: usize,           // Most fingers down at once during the gesture
+    swiping: bool,
+    last_tap_time: f64,
+}
+
+impl TouchTracker {
+    /// Sample this frame's touches. With `buttons` on, touches on the virtual
+    /// D-pad and interact button drive those instead of gestures.
+    pub fn poll(&mut self, buttons: bool) -> TouchFrame {
+        let mut frame = TouchFrame::default();
+        let mut gesture_touches = Vec::new();
+
+        for touch in touches() {
+            if buttons {
+                if let Some(dir) = virtual_dpad().iter().find(|(rect, _)| rect.contains(touch.position)).map(|(_, dir)| *dir) {
+                    frame.move_dir = dir;
+                    continue;
+                }
+                let (center, radius) = virtual_interact_button();
+                if touch.position.distance(center) <= radius {
+                    frame.interact_held = true;
+                    frame.interact_pressed |= touch.phase == TouchPhase::Started;
+                    continue;
+                }
+            }
+            gesture_touches.push(touch);
+        }
+
+        let down = gesture_touches.iter()
+            .filter(|t| !matches!(t.phase, TouchPhase::Ended | TouchPhase::Cancelled))
+            .count();
+        if self.primary.is_none() {
+            if let Some(touch) = gesture_touches.iter().find(|t| t.phase == TouchPhase::Started) {
+                self.primary = Some((touch.id, touch.position));
+                self.max_fingers = 0;
+                self.swiping = false;
+            }
+        }
+        self.max_fingers = self.max_fingers.max(down);
+
+        let Some((id, start)) = self.primary else { return frame };
+        let Some(touch) = gesture_touches.iter().find(|t| t.id == id) else {
+            // Lost track of the finger (e.g. it slid onto a virtual button)
+            self.primary = None;
+            return frame;
+        };
+
+        let travel = touch.position - start;
+        if travel.length() >= SWIPE_MIN_TRAVEL {
+            self.swiping = true;
+        }
+        match touch.phase {
+            TouchPhase::Ended | TouchPhase::Cancelled => {
+                if !self.swiping && touch.phase == TouchPhase::Ended {
+                    if self.max_fingers >= 2 {
+                        frame.two_finger_tap = true;
+                    } else if get_time() - self.last_tap_time <= DOUBLE_TAP_WINDOW {
+                        frame.double_tap = true;
+                        self.last_tap_time = 0.0;
+                    } else {
+                        frame.tap = Some(touch.position);
+                        self.last_tap_time = get_time();
+                    }
+                }
+                self.primary = None;
+            }
+            _ if self.swiping && frame.move_dir == Vec2::ZERO => frame.move_dir = travel.normalize_or_zero(),
+            _ => {}
+        }
+        frame
+    }
+}
+
+/// On-screen D-pad in the bottom-left corner: (button, move direction)
+pub fn virtual_dpad() -> [(Rect, Vec2); 4] {
+    let s = DPAD_BUTTON_SIZE;
+    let x = DPAD_MARGIN + s;
+    let y = screen_height() - DPAD_MARGIN - s * 2.0;
+    [
+        (Rect::new(x, y - s, s, s), vec2(0.0, -1.0)),
+        (Rect::new(x, y + s, s, s), vec2(0.0, 1.0)),
+        (Rect::new(x - s, y, s, s), vec2(-1.0, 0.0)),
+        (Rect::new(x + s, y, s, s), vec2(1.0, 0.0)),
+    ]
+}
+
+/// On-screen interact button in the bottom-right corner: (center, radius)
+pub fn virtual_interact_button() -> (Vec2, f32) {
+    let r = INTERACT_BUTTON_RADIUS;
+    (vec2(screen_width() - DPAD_MARGIN - r, screen_height() - DPAD_MARGIN - r), r)
+}
@@ -1,13 +1,54 @@
 use macroquad::prelude::*;
-use crate::state::GameState;
+use crate::state::{GameState, GamePhase, SlotSelectMode};
+use crate::simulation::constants::{AUTO_SAVE_SLOT, MAX_SAVE_SLOTS};
 use crate::simulation::events::{EventBus, UIEvent};
+use crate::data::settings::Difficulty;
+use crate::state::profile::today;
 use crate::ui::input_manager::{InputManager, InputState};
 use crate::ui::renderer::Renderer;
 
 impl InputManager {
-    pub fn handle_menu_input(&self, input: &InputState, events: &mut EventBus) {
+    pub fn handle_menu_input(&self, input: &InputState, state: &mut GameState, events: &mut EventBus) {
         if input.enter_pressed || input.space_pressed {
-            events.push_ui(UIEvent::StartGame);
+            state.phase = GamePhase::DifficultySelect;
+            return;
+        }
+
+        if is_key_pressed(KeyCode::H) {
+            state.phase = GamePhase::HighScores;
+            return;
+        }
+
+        if is_key_pressed(KeyCode::C) {
+            macroquad::miniquad::window::clipboard_set(&format!("{:016X}", state.run_seed));
+            state.show_toast("Seed copied to clipboard".to_string());
+        }
+
+        if is_key_pressed(KeyCode::E) {
+            state.seed_buffer.clear();
+            // Drain the E itself from the char queue
+            while get_char_pressed().is_some() {}
+            state.phase = GamePhase::SeedEntry;
+            return;
+        }
+
+        // T toggles the tutorial for players who have finished a run
+        if is_key_pressed(KeyCode::T) && state.profile.runs_completed > 0 {
+            state.settings.skip_tutorial = !state.settings.skip_tutorial;
+            let _ = state.settings.save();
+        }
+
+        if is_key_pressed(KeyCode::I) {
+            state.settings.ironman_mode = !state.settings.ironman_mode;
+            let _ = state.settings.save();
+        }
+
+        if is_key_pressed(KeyCode::D) {
+            if today().is_empty() {
+                state.show_toast("Daily challenge needs the system date".to_string());
+            } else {
+                state.phase = GamePhase::DailyChallenge;
+            }
             return;
         }
 
@@ -25,15 +66,184 @@ impl InputManager {
                 }
             }
 
-            // Check New Game button click
+            // Check auto-save Continue button click (if auto-save exists)
+            if let Some((btn_x, btn_y, btn_w, btn_h)) = renderer.get_auto_continue_bounds() {
+                if input.mouse_pos.x >= btn_x && input.mouse_pos.x <= btn_x + btn_w &&
+                   input.mouse_pos.y >= btn_y && input.mouse_pos.y <= btn_y + btn_h {
+                    events.push_ui(UIEvent::LoadGame(AUTO_SAVE_SLOT));
+                    return;
+                }
+            }
+
+            // Check New Game button click; Shift+click twice resets the veteran level instead
             let (btn_x, btn_y, btn_w, btn_h) = new_game_bounds;
             if input.mouse_pos.x >= btn_x && input.mouse_pos.x <= btn_x + btn_w &&
                input.mouse_pos.y >= btn_y && input.mouse_pos.y <= btn_y + btn_h {
-                events.push_ui(UIEvent::StartGame);
+                if input.shift_held && state.profile.veteran_level() > 0 {
+                    if state.veteran_reset_confirm {
+                        state.veteran_reset_confirm = false;
+                        state.profile.reset_veteran_level();
+                        let _ = state.profile.save();
+                        state.show_toast("Veteran level reset".to_string());
+                    } else {
+                        state.veteran_reset_confirm = true;
+                        state.show_toast("Shift+click NEW GAME again to reset veteran level".to_string());
+                    }
+                    return;
+                }
+                state.veteran_reset_confirm = false;
+                state.phase = GamePhase::DifficultySelect;
+                return;
+            }
+            state.veteran_reset_confirm = false;
+
+            // Check Achievements button click
+            let (btn_x, btn_y, btn_w, btn_h) = renderer.get_achievements_button_bounds();
+            if input.mouse_pos.x >= btn_x && input.mouse_pos.x <= btn_x + btn_w &&
+               input.mouse_pos.y >= btn_y && input.mouse_pos.y <= btn_y + btn_h {
+                state.phase = GamePhase::Achievements;
             }
         }
     }
 
+    pub fn handle_difficulty_select_input(&self, input: &InputState, state: &mut GameState, events: &mut EventBus) {
+        // ESC returns to the main menu
+        if input.escape_pressed {
+            state.phase = GamePhase::Menu;
+            return;
+        }
+
+        let count = Difficulty::ALL.len();
+        let current = Difficulty::ALL.iter().position(|d| *d == state.settings.difficulty).unwrap_or(1);
+        if is_key_pressed(KeyCode::Up) || is_key_pressed(KeyCode::W) {
+            state.settings.difficulty = Difficulty::ALL[(current + count - 1) % count];
+        }
+        if is_key_pressed(KeyCode::Down) || is_key_pressed(KeyCode::S) {
+            state.settings.difficulty = Difficulty::ALL[(current + 1) % count];
+        }
+
+        if input.left_click {
+            let renderer = Renderer::new();
+            for (i, (x, y, w, h)) in renderer.get_difficulty_card_bounds().into_iter().enumerate() {
+                if input.mouse_pos.x >= x && input.mouse_pos.x <= x + w &&
+                   input.mouse_pos.y >= y && input.mouse_pos.y <= y + h {
+                    state.settings.difficulty = Difficulty::ALL[i];
+                    let _ = state.settings.save();
+                    Self::enter_ship_select(state, events);
+                    return;
+                }
+            }
+        }
+
+        if input.enter_pressed || input.space_pressed {
+            let _ = state.settings.save();
+            Self::enter_ship_select(state, events);
+        }
+    }
+
+    /// Move on to hull selection, skipping straight to the run if there is nothing to choose
+    fn enter_ship_select(state: &mut GameState, events: &mut EventBus) {
+        if state.ship_manifest.ships.len() > 1 {
+            let selected = state.ship_manifest.ships.iter()
+                .position(|s| s.path == state.selected_ship_path)
+                .unwrap_or(0);
+            state.phase = GamePhase::ShipSelect { selected };
+        } else {
+            events.push_ui(UIEvent::StartGame);
+        }
+    }
+
+    pub fn handle_ship_select_input(&self, input: &InputState, state: &mut GameState, selected: usize, events: &mut EventBus) {
+        // ESC returns to difficulty selection
+        if input.escape_pressed {
+            state.phase = GamePhase::DifficultySelect;
+            return;
+        }
+
+        let count = state.ship_manifest.ships.len();
+        if count == 0 {
+            events.push_ui(UIEvent::StartGame);
+            return;
+        }
+        let mut selected = selected.min(count - 1);
+        if is_key_pressed(KeyCode::Up) || is_key_pressed(KeyCode::W) {
+            selected = (selected + count - 1) % count;
+        }
+        if is_key_pressed(KeyCode::Down) || is_key_pressed(KeyCode::S) {
+            selected = (selected + 1) % count;
+        }
+        state.phase = GamePhase::ShipSelect { selected };
+
+        let mut confirmed = input.enter_pressed || input.space_pressed;
+        if input.left_click {
+            let renderer = Renderer::new();
+            for (i, (x, y, w, h)) in renderer.get_ship_card_bounds(count).into_iter().enumerate() {
+                if input.mouse_pos.x >= x && input.mouse_pos.x <= x + w &&
+                   input.mouse_pos.y >= y && input.mouse_pos.y <= y + h {
+                    selected = i;
+                    confirmed = true;
+                }
+            }
+        }
+
+        if confirmed {
+            state.selected_ship_path = state.ship_manifest.ships[selected].path.clone();
+            events.push_ui(UIEvent::StartGame);
+        }
+    }
+
+    pub fn handle_achievements_input(&self, input: &InputState, state: &mut GameState) {
+        if input.escape_pressed || input.enter_pressed || input.space_pressed {
+            state.phase = GamePhase::Menu;
+        }
+    }
+
+    /// Type a hex seed; Enter continues to difficulty select with that seed queued
+    pub fn handle_seed_entry_input(&self, input: &InputState, state: &mut GameState) {
+        if input.escape_pressed {
+            state.phase = GamePhase::Menu;
+            return;
+        }
+
+        while let Some(c) = get_char_pressed() {
+            if c.is_ascii_hexdigit() && state.seed_buffer.len() < 16 {
+                state.seed_buffer.push(c.to_ascii_uppercase());
+            }
+        }
+        if is_key_pressed(KeyCode::Backspace) {
+            state.seed_buffer.pop();
+        }
+
+        if input.enter_pressed {
+            match u64::from_str_radix(&state.seed_buffer, 16) {
+                Ok(seed) => {
+                    state.pending_seed = Some(seed);
+                    state.phase = GamePhase::DifficultySelect;
+                }
+                Err(_) => state.show_toast("Enter a seed of 1-16 hex digits".to_string()),
+            }
+        }
+    }
+
+    /// Enter starts today's run unless it has already been played; Esc goes back
+    pub fn handle_daily_challenge_input(&self, input: &InputState, state: &mut GameState, events: &mut EventBus) {
+        if input.escape_pressed {
+            state.phase = GamePhase::Menu;
+            return;
+        }
+
+        let played = state.profile.daily_record(&today()).is_some();
+        if (input.enter_pressed || input.space_pressed) && !played {
+            events.push_ui(UIEvent::StartDailyChallenge);
+        }
+    }
+
+    pub fn handle_high_scores_input(&self, input: &InputState, state: &mut GameState) {
+        if input.escape_pressed || input.enter_pressed || input.space_pressed || is_key_pressed(KeyCode::H) {
+            state.phase = GamePhase::Menu;
+        }
+    }
+
     pub fn handle_game_over_input(&self, input: &InputState, events: &mut EventBus) {
         if input.enter_pressed || input.space_pressed {
             events.push_ui(UIEvent::ReturnToMenu);
@@ -46,6 +256,13 @@ impl InputManager {
         }
     }
 
+    /// Space skips the departure animation
+    pub fn handle_victory_animation_input(&self, input: &InputState, state: &mut GameState) {
+        if input.space_pressed {
+            state.victory_anim_timer = 0.0;
+        }
+    }
+
     pub fn handle_upgrade_input(&self, input: &InputState, state: &GameState, events: &mut EventBus) {
         if input.enter_pressed {
             events.push_ui(UIEvent::NextRound);
@@ -63,12 +280,54 @@ impl InputManager {
             KeyCode::Key6, KeyCode::Key7, KeyCode::Key8, KeyCode::Key9,
         ];
 
+        // Shift + number buys permanent upgrades instead
         for (i, key) in keys.iter().enumerate() {
             if is_key_pressed(*key) {
-                if let Some(template) = state.upgrade_templates.get(i) {
+                if input.shift_held {
+                    if let Some(template) = state.permanent_upgrade_templates.get(i) {
+                        events.push_ui(UIEvent::PurchasePermanentUpgrade(template.id.clone()));
+                    }
+                } else if let Some(template) = state.upgrade_templates.get(i) {
                     events.push_ui(UIEvent::PurchaseUpgrade(template.id.clone()));
                 }
             }
         }
     }
+
+    pub fn handle_slot_select_input(&self, input: &InputState, state: &mut GameState, mode: SlotSelectMode, events: &mut EventBus) {
+        // ESC returns to the pause menu
+        if input.escape_pressed {
+            state.phase = GamePhase::Playing;
+            return;
+        }
+
+        if is_key_pressed(KeyCode::Up) || is_key_pressed(KeyCode::W) {
+            state.slot_selection = if state.slot_selection == 0 {
+                MAX_SAVE_SLOTS - 1
+            } else {
+                state.slot_selection - 1
+            };
+        }
+        if is_key_pressed(KeyCode::Down) || is_key_pressed(KeyCode::S) {
+            state.slot_selection = (state.slot_selection + 1) % MAX_SAVE_SLOTS;
+        }
+
+        if input.enter_pressed {
+            let slot = state.slot_selection;
+            match mode {
+                SlotSelectMode::Save => {
+                    state.phase = GamePhase::Playing;
+                    events.push_ui(UIEvent::SaveGame(slot));
+                }
+                SlotSelectMode::Load => {
+                    // Nothing to load from an empty slot
+                    let has_save = state.slot_summaries.get(slot).is_some_and(|s| s.is_some());
+                    if has_save {
+                        state.phase = GamePhase::Playing;
+                        events.push_ui(UIEvent::LoadGame(slot));
+                    }
+                }
+            }
+        }
+    }
 }
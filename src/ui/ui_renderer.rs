@@ -1,6 +1,12 @@
 use macroquad::prelude::*;
-use crate::state::GameState;
+use crate::state::{GameState, SlotSelectMode};
+use crate::simulation::constants::{AUTO_SAVE_SLOT, HIGH_SCORE_ROWS, MAX_SAVE_SLOTS};
 use crate::ui::renderer::Renderer;
+use crate::data::settings::Difficulty;
+use crate::state::codex::CodexCategory;
+use crate::state::profile::today;
+use crate::simulation::rng::daily_seed;
+use crate::ship::interior::{RoomType, ShipInterior};
 
 impl Renderer {
     pub fn draw_tutorial(&self, state: &GameState) {
@@ -36,7 +42,7 @@ impl Renderer {
         }
     }
 
-    pub fn draw_menu(&self) {
+    pub fn draw_menu(&self, state: &GameState) {
         draw_rectangle(0.0, 0.0, screen_width(), screen_height(), color_u8!(15, 15, 25, 255));
         let title = "SCRAPYARD PLANET";
         let title_size = measure_text(title, None, 64, 1.0);
@@ -46,33 +52,83 @@ impl Renderer {
         let sub_size = measure_text(subtitle, None, 24, 1.0);
         draw_text(subtitle, screen_width() / 2.0 - sub_size.width / 2.0, screen_height() / 3.0 + 50.0, 24.0, GRAY);
 
-        let btn_width = 200.0;
-        let btn_height = 50.0;
-        let btn_x = screen_width() / 2.0 - btn_width / 2.0;
-        
-        // Check if save file exists
-        let has_save = std::path::Path::new("save_slot_0.json").exists();
+        let (continue_bounds, auto_bounds, (btn_x, btn_y, btn_width, btn_height)) = self.menu_layout();
+        let has_save = continue_bounds.is_some();
         
         // Continue button (only if save exists)
-        let mut next_y = screen_height() / 2.0 + 20.0;
-        if has_save {
-            let btn_y = next_y;
-            draw_rectangle(btn_x, btn_y, btn_width, btn_height, color_u8!(40, 80, 60, 255));
-            draw_rectangle_lines(btn_x, btn_y, btn_width, btn_height, 2.0, color_u8!(100, 180, 140, 255));
+        if let Some((cx, cy, cw, ch)) = continue_bounds {
+            draw_rectangle(cx, cy, cw, ch, color_u8!(40, 80, 60, 255));
+            draw_rectangle_lines(cx, cy, cw, ch, 2.0, color_u8!(100, 180, 140, 255));
             let continue_text = "CONTINUE";
             let continue_size = measure_text(continue_text, None, 28, 1.0);
-            draw_text(continue_text, btn_x + btn_width / 2.0 - continue_size.width / 2.0, btn_y + btn_height / 2.0 + 8.0, 28.0, WHITE);
-            next_y += btn_height + 15.0;
+            draw_text(continue_text, cx + cw / 2.0 - continue_size.width / 2.0, cy + ch / 2.0 + 8.0, 28.0, WHITE);
+        }
+
+        // Auto-save continue (only if an auto-save exists)
+        if let Some((ax, ay, aw, ah)) = auto_bounds {
+            draw_rectangle(ax, ay, aw, ah, color_u8!(35, 60, 50, 255));
+            draw_rectangle_lines(ax, ay, aw, ah, 2.0, color_u8!(80, 140, 110, 255));
+            let auto_text = "CONTINUE (auto)";
+            let auto_size = measure_text(auto_text, None, 22, 1.0);
+            draw_text(auto_text, ax + aw / 2.0 - auto_size.width / 2.0, ay + ah / 2.0 + 7.0, 22.0, LIGHTGRAY);
         }
 
         // New Game button
-        let btn_y = next_y;
         draw_rectangle(btn_x, btn_y, btn_width, btn_height, color_u8!(60, 60, 80, 255));
         draw_rectangle_lines(btn_x, btn_y, btn_width, btn_height, 2.0, color_u8!(100, 100, 140, 255));
         let start_text = "NEW GAME";
         let start_size = measure_text(start_text, None, 28, 1.0);
         draw_text(start_text, btn_x + btn_width / 2.0 - start_size.width / 2.0, btn_y + btn_height / 2.0 + 8.0, 28.0, WHITE);
 
+        // Achievements button
+        let (ach_x, ach_y, ach_w, ach_h) = self.get_achievements_button_bounds();
+        draw_rectangle(ach_x, ach_y, ach_w, ach_h, color_u8!(40, 40, 55, 255));
+        draw_rectangle_lines(ach_x, ach_y, ach_w, ach_h, 2.0, color_u8!(90, 90, 120, 255));
+        let ach_text = "ACHIEVEMENTS";
+        let ach_size = measure_text(ach_text, None, 24, 1.0);
+        draw_text(ach_text, ach_x + ach_w / 2.0 - ach_size.width / 2.0, ach_y + ach_h / 2.0 + 7.0, 24.0, LIGHTGRAY);
+
+        // Tutorial toggle (only offered after the first completed run)
+        if state.profile.runs_completed > 0 {
+            let (tutorial_text, tutorial_color) = if state.settings.skip_tutorial {
+                ("[T] Toggle Tutorial: SKIPPED", GREEN)
+            } else {
+                ("[T] Toggle Tutorial: ON", GRAY)
+            };
+            let tutorial_size = measure_text(tutorial_text, None, 18, 1.0);
+            draw_text(tutorial_text, screen_width() / 2.0 - tutorial_size.width / 2.0, ach_y + ach_h + 25.0, 18.0, tutorial_color);
+        }
+
+        let scores_text = "[H] High Scores";
+        let scores_size = measure_text(scores_text, None, 18, 1.0);
+        draw_text(scores_text, screen_width() / 2.0 - scores_size.width / 2.0, ach_y + ach_h + 50.0, 18.0, GRAY);
+
+        let (ironman_text, ironman_color) = if state.settings.ironman_mode {
+            ("[I] Ironman: ON (no saves, +50% credits)", RED)
+        } else {
+            ("[I] Ironman: OFF", GRAY)
+        };
+        let ironman_size = measure_text(ironman_text, None, 18, 1.0);
+        draw_text(ironman_text, screen_width() / 2.0 - ironman_size.width / 2.0, ach_y + ach_h + 75.0, 18.0, ironman_color);
+
+        let daily_text = "[D] Daily Challenge";
+        let daily_size = measure_text(daily_text, None, 18, 1.0);
+        draw_text(daily_text, screen_width() / 2.0 - daily_size.width / 2.0, ach_y + ach_h + 125.0, 18.0, GRAY);
+
+        let veteran_level = state.profile.veteran_level();
+        if veteran_level > 0 {
+            let (veteran_text, veteran_color) = if state.veteran_reset_confirm {
+                ("Shift+click NEW GAME again to reset".to_string(), ORANGE)
+            } else {
+                (format!("Veteran Level: {}  (Shift+click NEW GAME to reset)", veteran_level), GOLD)
+            };
+            let veteran_size = measure_text(&veteran_text, None, 18, 1.0);
+            draw_text(&veteran_text, screen_width() / 2.0 - veteran_size.width / 2.0, ach_y + ach_h + 100.0, 18.0, veteran_color);
+        }
+
+        let seed_text = format!("Seed {:016X}  [C] Copy  [E] Enter seed", state.run_seed);
+        draw_text(&seed_text, 10.0, screen_height() - 15.0, 16.0, DARKGRAY);
+
         let hint = if has_save { "Click CONTINUE to load or NEW GAME to start fresh" } 
                    else { "Click NEW GAME or press ENTER to begin" };
         let hint_size = measure_text(hint, None, 18, 1.0);
@@ -80,144 +136,535 @@ impl Renderer {
     }
 
     pub fn get_menu_button_bounds(&self) -> (Option<(f32, f32, f32, f32)>, (f32, f32, f32, f32)) {
+        let (continue_bounds, _, new_game_bounds) = self.menu_layout();
+        (continue_bounds, new_game_bounds)
+    }
+
+    pub fn get_auto_continue_bounds(&self) -> Option<(f32, f32, f32, f32)> {
+        self.menu_layout().1
+    }
+
+    /// Menu button rects: (continue, continue from auto-save, new game)
+    fn menu_layout(&self) -> (Option<(f32, f32, f32, f32)>, Option<(f32, f32, f32, f32)>, (f32, f32, f32, f32)) {
         let btn_width = 200.0;
         let btn_height = 50.0;
         let btn_x = screen_width() / 2.0 - btn_width / 2.0;
-        let has_save = std::path::Path::new("save_slot_0.json").exists();
         
         let mut next_y = screen_height() / 2.0 + 20.0;
-        let continue_bounds = if has_save {
+        let continue_bounds = if GameState::slot_exists(0) {
             let bounds = (btn_x, next_y, btn_width, btn_height);
             next_y += btn_height + 15.0;
             Some(bounds)
         } else {
             None
         };
+
+        let auto_height = 36.0;
+        let auto_bounds = if GameState::slot_exists(AUTO_SAVE_SLOT) {
+            let bounds = (btn_x, next_y, btn_width, auto_height);
+            next_y += auto_height + 15.0;
+            Some(bounds)
+        } else {
+            None
+        };
         
         let new_game_bounds = (btn_x, next_y, btn_width, btn_height);
-        (continue_bounds, new_game_bounds)
+        (continue_bounds, auto_bounds, new_game_bounds)
+    }
+
+    pub fn get_achievements_button_bounds(&self) -> (f32, f32, f32, f32) {
+        let (_, (btn_x, btn_y, btn_w, btn_h)) = self.get_menu_button_bounds();
+        (btn_x, btn_y + btn_h + 15.0, btn_w, btn_h)
     }
 
-    
     pub fn get_start_button_bounds(&self) -> (f32, f32, f32, f32) {
         let (_, new_game) = self.get_menu_button_bounds();
         new_game
     }
 
-    pub fn draw_game_over(&self, state: &GameState) {
-        draw_rectangle(0.0, 0.0, screen_width(), screen_height(), color_u8!(15, 5, 5, 255));
-        for i in 0..5 {
-            let alpha = 100 - i * 20;
-            let offset = i as f32 * 30.0;
-            draw_rectangle_lines(offset, offset, screen_width() - offset * 2.0, screen_height() - offset * 2.0, 3.0, color_u8!(80, 0, 0, alpha as u8));
+    pub fn draw_slot_select(&self, state: &GameState, mode: SlotSelectMode) {
+        draw_rectangle(0.0, 0.0, screen_width(), screen_height(), color_u8!(0, 0, 0, 200));
+
+        let title = match mode {
+            SlotSelectMode::Save => "SAVE GAME",
+            SlotSelectMode::Load => "LOAD GAME",
+        };
+        let title_w = measure_text(title, None, 40, 1.0).width;
+        draw_text(title, (screen_width() - title_w) / 2.0, 80.0, 40.0, WHITE);
+
+        let card_w = 400.0;
+        let card_h = 70.0;
+        let spacing = 15.0;
+        let card_x = (screen_width() - card_w) / 2.0;
+        let start_y = 120.0;
+
+        for slot in 0..MAX_SAVE_SLOTS {
+            let y = start_y + slot as f32 * (card_h + spacing);
+            let is_selected = slot == state.slot_selection;
+            let summary = state.slot_summaries.get(slot).and_then(|s| s.as_ref());
+
+            let bg_color = if is_selected { color_u8!(60, 60, 90, 255) } else { color_u8!(35, 35, 45, 255) };
+            draw_rectangle(card_x, y, card_w, card_h, bg_color);
+            draw_rectangle_lines(card_x, y, card_w, card_h, 2.0, if is_selected { YELLOW } else { GRAY });
+
+            draw_text(&format!("SLOT {}", slot + 1), card_x + 15.0, y + 30.0, 24.0, WHITE);
+
+            if let Some(summary) = summary {
+                let minutes = (summary.time_survived / 60.0).floor() as i32;
+                let seconds = (summary.time_survived % 60.0).floor() as i32;
+                draw_text(&format!("Time: {:02}:{:02}", minutes, seconds), card_x + 15.0, y + 55.0, 18.0, LIGHTGRAY);
+                draw_text(&format!("Phase: {:?}", summary.phase), card_x + 200.0, y + 55.0, 18.0, LIGHTGRAY);
+                if summary.is_legacy() {
+                    let badge = "Legacy save - upgraded";
+                    let badge_w = measure_text(badge, None, 16, 1.0).width;
+                    draw_text(badge, card_x + card_w - badge_w - 15.0, y + 28.0, 16.0, ORANGE);
+                }
+            } else {
+                draw_text("EMPTY", card_x + card_w - 90.0, y + 42.0, 22.0, DARKGRAY);
+            }
+        }
+
+        let hint = "Up/Down: Select | Enter: Confirm | Esc: Back";
+        let hint_w = measure_text(hint, None, 18, 1.0).width;
+        draw_text(hint, (screen_width() - hint_w) / 2.0, screen_height() - 40.0, 18.0, GRAY);
+    }
+
+    /// Card rectangles (x, y, w, h) for each entry in Difficulty::ALL
+    pub fn get_difficulty_card_bounds(&self) -> Vec<(f32, f32, f32, f32)> {
+        let card_w = 400.0;
+        let card_h = 70.0;
+        let spacing = 15.0;
+        let card_x = (screen_width() - card_w) / 2.0;
+        let start_y = 160.0;
+        (0..Difficulty::ALL.len())
+            .map(|i| (card_x, start_y + i as f32 * (card_h + spacing), card_w, card_h))
+            .collect()
+    }
+
+    pub fn draw_difficulty_select(&self, state: &GameState) {
+        draw_rectangle(0.0, 0.0, screen_width(), screen_height(), color_u8!(0, 0, 0, 200));
+
+        let title = "SELECT DIFFICULTY";
+        let title_w = measure_text(title, None, 40, 1.0).width;
+        draw_text(title, (screen_width() - title_w) / 2.0, 110.0, 40.0, WHITE);
+        if state.settings.ironman_mode {
+            draw_text("[IRONMAN]", (screen_width() + title_w) / 2.0 + 15.0, 110.0, 24.0, RED);
         }
 
-        let text = "CORE DESTROYED";
-        let size = measure_text(text, None, 64, 1.0);
-        draw_text(text, screen_width() / 2.0 - size.width / 2.0, screen_height() / 3.0, 64.0, RED);
+        for (difficulty, (x, y, w, h)) in Difficulty::ALL.iter().zip(self.get_difficulty_card_bounds()) {
+            let is_selected = *difficulty == state.settings.difficulty;
 
-        let stats_y = screen_height() / 2.0;
-        let minutes = (state.time_survived / 60.0).floor() as i32;
-        let seconds = (state.time_survived % 60.0).floor() as i32;
-        let stats = [
-            format!("Scrap Collected: {}", state.resources.scrap + 100),
-            format!("Credits Earned: {}", state.resources.credits),
-            format!("Time Survived: {:02}:{:02}", minutes, seconds),
-        ];
-        
-        for (i, stat) in stats.iter().enumerate() {
-            let s = measure_text(stat, None, 24, 1.0);
-            draw_text(stat, screen_width() / 2.0 - s.width / 2.0, stats_y + i as f32 * 30.0, 24.0, GRAY);
-        }
-
-        let hint = "Press ENTER to return to menu";
-        let hint_size = measure_text(hint, None, 24, 1.0);
-        draw_text(hint, screen_width() / 2.0 - hint_size.width / 2.0, screen_height() - 80.0, 24.0, WHITE);
-    }
-
-    pub fn draw_victory(&self, state: &GameState) {
-        draw_rectangle(0.0, 0.0, screen_width(), screen_height(), color_u8!(10, 20, 30, 255));
-        for i in 0..8 {
-            let alpha = 60 - i * 7;
-            let offset = i as f32 * 20.0;
-            draw_rectangle_lines(offset, offset, screen_width() - offset * 2.0, screen_height() - offset * 2.0, 2.0, color_u8!(100, 200, 255, alpha as u8));
-        }
-
-        let text = "ESCAPE SUCCESSFUL!";
-        let size = measure_text(text, None, 64, 1.0);
-        draw_text(text, screen_width() / 2.0 - size.width / 2.0, screen_height() / 3.0, 64.0, GREEN);
-
-        let subtitle = "You made it off the planet!";
-        let sub_size = measure_text(subtitle, None, 28, 1.0);
-        draw_text(subtitle, screen_width() / 2.0 - sub_size.width / 2.0, screen_height() / 3.0 + 50.0, 28.0, color_u8!(150, 255, 150, 255));
-
-        let stats_y = screen_height() / 2.0;
-        let stats = [
-            format!("Total Credits: {}", state.resources.credits),
-            format!("Core Health Remaining: {:.0}%", 
-                if let Some(pos) = state.ship.find_core() {
-                    if let Some(core) = &state.ship.grid[pos.0][pos.1] {
-                        (core.health / core.max_health) * 100.0
-                    } else { 0.0 }
-                } else { 0.0 }
-            ),
-        ];
-        
-        for (i, stat) in stats.iter().enumerate() {
-            let s = measure_text(stat, None, 24, 1.0);
-            draw_text(stat, screen_width() / 2.0 - s.width / 2.0, stats_y + i as f32 * 30.0, 24.0, WHITE);
+            let bg_color = if is_selected { color_u8!(60, 60, 90, 255) } else { color_u8!(35, 35, 45, 255) };
+            draw_rectangle(x, y, w, h, bg_color);
+            draw_rectangle_lines(x, y, w, h, 2.0, if is_selected { YELLOW } else { GRAY });
+
+            draw_text(difficulty.label(), x + 15.0, y + 30.0, 24.0, WHITE);
+            draw_text(difficulty.description(), x + 15.0, y + 55.0, 18.0, LIGHTGRAY);
         }
 
-        let hint = "Press ENTER to continue to Upgrades";
-        let hint_size = measure_text(hint, None, 24, 1.0);
-        draw_text(hint, screen_width() / 2.0 - hint_size.width / 2.0, screen_height() - 80.0, 24.0, YELLOW);
+        let hint = "Up/Down: Select | Enter: Start | Esc: Back";
+        let hint_w = measure_text(hint, None, 18, 1.0).width;
+        draw_text(hint, (screen_width() - hint_w) / 2.0, screen_height() - 40.0, 18.0, GRAY);
     }
 
-    pub fn draw_upgrade_screen(&self, state: &GameState) {
-        draw_rectangle(0.0, 0.0, screen_width(), screen_height(), color_u8!(15, 20, 30, 255));
-        let title = "SHIP IMPROVEMENTS";
-        let title_w = measure_text(title, None, 48, 1.0).width;
-        draw_text(title, (screen_width() - title_w) / 2.0, 60.0, 48.0, WHITE);
-        
-        let credits_text = format!("AVAILABLE CREDITS: {}", state.resources.credits);
-        let cred_w = measure_text(&credits_text, None, 24, 1.0).width;
-        draw_text(&credits_text, (screen_width() - cred_w) / 2.0, 100.0, 24.0, GREEN);
-
-        let start_y = 150.0;
-        let card_w = 600.0;
-        let card_h = 80.0;
-        let spacing = 20.0;
+    pub fn get_ship_card_bounds(&self, count: usize) -> Vec<(f32, f32, f32, f32)> {
+        let card_w = 560.0;
+        let card_h = 110.0;
+        let spacing = 15.0;
+        let card_x = (screen_width() - card_w) / 2.0;
+        let start_y = 160.0;
+        (0..count)
+            .map(|i| (card_x, start_y + i as f32 * (card_h + spacing), card_w, card_h))
+            .collect()
+    }
+
+    pub fn draw_ship_select(&self, state: &GameState, selected: usize) {
+        draw_rectangle(0.0, 0.0, screen_width(), screen_height(), color_u8!(0, 0, 0, 200));
+
+        let title = "SELECT HULL";
+        let title_w = measure_text(title, None, 40, 1.0).width;
+        draw_text(title, (screen_width() - title_w) / 2.0, 110.0, 40.0, WHITE);
+
+        let ships = &state.ship_manifest.ships;
+        for (i, (entry, (x, y, w, h))) in ships.iter().zip(self.get_ship_card_bounds(ships.len())).enumerate() {
+            let is_selected = i == selected;
+
+            let bg_color = if is_selected { color_u8!(60, 60, 90, 255) } else { color_u8!(35, 35, 45, 255) };
+            draw_rectangle(x, y, w, h, bg_color);
+            draw_rectangle_lines(x, y, w, h, 2.0, if is_selected { YELLOW } else { GRAY });
+
+            // Preview on the right, text on the left
+            let preview_w = 150.0;
+            let preview_h = h - 20.0;
+            let text_w = w - preview_w - 45.0;
+
+            draw_text(&entry.name, x + 15.0, y + 30.0, 24.0, WHITE);
+            let mut line_y = y + 52.0;
+            for line in wrap_text(&entry.description, text_w, 16).into_iter().take(2) {
+                draw_text(&line, x + 15.0, line_y, 16.0, LIGHTGRAY);
+                line_y += 18.0;
+            }
+
+            if let Some(interior) = state.ship_previews.get(i) {
+                let room_count = interior.rooms.iter().filter(|r| r.room_type != RoomType::Empty).count();
+                draw_text(&format!("{} rooms", room_count), x + 15.0, y + h - 12.0, 18.0, GOLD);
+                self.draw_ship_preview(interior, x + w - preview_w - 10.0, y + 10.0, preview_w, preview_h);
+            }
+        }
+
+        let hint = "Up/Down: Select | Enter: Launch | Esc: Back";
+        let hint_w = measure_text(hint, None, 18, 1.0).width;
+        draw_text(hint, (screen_width() - hint_w) / 2.0, screen_height() - 40.0, 18.0, GRAY);
+    }
+
+    /// Draw a ship's room layout scaled to fit the given box
+    fn draw_ship_preview(&self, interior: &ShipInterior, x: f32, y: f32, w: f32, h: f32) {
+        draw_rectangle(x, y, w, h, color_u8!(15, 15, 20, 255));
+        if interior.width <= 0.0 || interior.height <= 0.0 {
+            return;
+        }
+
+        let scale = (w / interior.width).min(h / interior.height);
+        let offset_x = x + (w - interior.width * scale) / 2.0;
+        let offset_y = y + (h - interior.height * scale) / 2.0;
+        for room in interior.rooms.iter().filter(|r| r.room_type != RoomType::Empty) {
+            let rx = offset_x + room.x * scale;
+            let ry = offset_y + room.y * scale;
+            draw_rectangle(rx, ry, room.width * scale, room.height * scale, room.color());
+            draw_rectangle_lines(rx, ry, room.width * scale, room.height * scale, 1.0, color_u8!(90, 90, 110, 255));
+        }
+    }
+
+    pub fn draw_achievements_screen(&self, state: &GameState) {
+        draw_rectangle(0.0, 0.0, screen_width(), screen_height(), color_u8!(15, 15, 25, 255));
+
+        let title = "ACHIEVEMENTS";
+        let title_w = measure_text(title, None, 40, 1.0).width;
+        draw_text(title, (screen_width() - title_w) / 2.0, 80.0, 40.0, WHITE);
+
+        let achievements = &state.achievement_config.achievements;
+        let unlocked = achievements.iter().filter(|a| state.profile.is_unlocked(&a.id)).count();
+        let progress = format!("{} / {} unlocked", unlocked, achievements.len());
+        let progress_w = measure_text(&progress, None, 20, 1.0).width;
+        draw_text(&progress, (screen_width() - progress_w) / 2.0, 110.0, 20.0, GRAY);
+
+        let card_w = 500.0;
+        let card_h = 60.0;
+        let spacing = 10.0;
         let card_x = (screen_width() - card_w) / 2.0;
+        let start_y = 135.0;
 
-        for (i, template) in state.upgrade_templates.iter().enumerate() {
+        for (i, achievement) in achievements.iter().enumerate() {
             let y = start_y + i as f32 * (card_h + spacing);
-            let current_level = state.upgrades.get_level(&template.id);
-            let is_max = current_level >= template.max_level;
-            let cost = state.upgrades.get_cost(template);
-            let can_afford = state.resources.credits >= cost && !is_max;
+            let is_unlocked = state.profile.is_unlocked(&achievement.id);
 
-            let bg_color = if is_max { color_u8!(40, 50, 40, 255) } else if can_afford { color_u8!(40, 40, 60, 255) } else { color_u8!(30, 30, 35, 255) };
+            let (bg_color, border, text_color) = if is_unlocked {
+                (color_u8!(40, 70, 50, 255), GOLD, WHITE)
+            } else {
+                (color_u8!(35, 35, 45, 255), DARKGRAY, GRAY)
+            };
             draw_rectangle(card_x, y, card_w, card_h, bg_color);
-            draw_rectangle_lines(card_x, y, card_w, card_h, 2.0, if can_afford { YELLOW } else { GRAY });
+            draw_rectangle_lines(card_x, y, card_w, card_h, 2.0, border);
 
-            draw_text(&format!("{} (Level {}/{})", template.name, current_level, template.max_level), card_x + 15.0, y + 30.0, 24.0, WHITE);
-            draw_text(&template.description, card_x + 15.0, y + 55.0, 16.0, GRAY);
+            draw_text(&achievement.name, card_x + 15.0, y + 26.0, 22.0, text_color);
+            draw_text(&achievement.description, card_x + 15.0, y + 48.0, 16.0, LIGHTGRAY);
 
-            if is_max {
-                draw_text("MAX LEVEL", card_x + card_w - 120.0, y + 45.0, 20.0, GREEN);
-            } else {
-                let cost_color = if can_afford { WHITE } else { RED };
-                draw_text(&format!("Cost: {} Cr", cost), card_x + card_w - 150.0, y + 35.0, 20.0, cost_color);
-                if can_afford {
-                    draw_text(&format!("[{}] Buy", i + 1), card_x + card_w - 150.0, y + 60.0, 20.0, YELLOW);
+            let status = if is_unlocked { "UNLOCKED" } else { "LOCKED" };
+            let status_w = measure_text(status, None, 18, 1.0).width;
+            draw_text(status, card_x + card_w - status_w - 15.0, y + 36.0, 18.0, border);
+        }
+
+        let hint = "Esc / Enter: Back";
+        let hint_w = measure_text(hint, None, 18, 1.0).width;
+        draw_text(hint, (screen_width() - hint_w) / 2.0, screen_height() - 40.0, 18.0, GRAY);
+    }
+
+    /// Hex seed input box over the main menu
+    pub fn draw_seed_entry(&self, state: &GameState) {
+        draw_rectangle(0.0, 0.0, screen_width(), screen_height(), color_u8!(0, 0, 0, 200));
+
+        let box_w = 420.0;
+        let box_h = 150.0;
+        let box_x = (screen_width() - box_w) / 2.0;
+        let box_y = (screen_height() - box_h) / 2.0;
+        draw_rectangle(box_x, box_y, box_w, box_h, color_u8!(25, 25, 35, 255));
+        draw_rectangle_lines(box_x, box_y, box_w, box_h, 2.0, color_u8!(80, 80, 120, 255));
+
+        draw_text("ENTER RUN SEED", box_x + 20.0, box_y + 35.0, 24.0, WHITE);
+
+        let field_y = box_y + 55.0;
+        draw_rectangle(box_x + 20.0, field_y, box_w - 40.0, 36.0, color_u8!(35, 35, 45, 255));
+        draw_rectangle_lines(box_x + 20.0, field_y, box_w - 40.0, 36.0, 1.0, YELLOW);
+        let caret = if (get_time() * 2.0) as i64 % 2 == 0 { "_" } else { "" };
+        draw_text(&format!("{}{}", state.seed_buffer, caret), box_x + 30.0, field_y + 25.0, 24.0, YELLOW);
+
+        draw_text("Hex digits  |  Enter: Start  |  Esc: Cancel", box_x + 20.0, box_y + box_h - 20.0, 16.0, GRAY);
+    }
+
+    /// Today's date and seed, with a start button that is grayed out once the day is played
+    pub fn draw_daily_challenge(&self, state: &GameState) {
+        draw_rectangle(0.0, 0.0, screen_width(), screen_height(), color_u8!(0, 0, 0, 200));
+
+        let box_w = 420.0;
+        let box_h = 250.0;
+        let box_x = (screen_width() - box_w) / 2.0;
+        let box_y = (screen_height() - box_h) / 2.0;
+        draw_rectangle(box_x, box_y, box_w, box_h, color_u8!(25, 25, 35, 255));
+        draw_rectangle_lines(box_x, box_y, box_w, box_h, 2.0, color_u8!(80, 80, 120, 255));
+
+        let date = today();
+        draw_text("DAILY CHALLENGE", box_x + 20.0, box_y + 35.0, 24.0, WHITE);
+        draw_text(&date, box_x + box_w - 20.0 - measure_text(&date, None, 18, 1.0).width, box_y + 35.0, 18.0, GRAY);
+        draw_text(&format!("Seed {:016X}", daily_seed(&date)), box_x + 20.0, box_y + 65.0, 18.0, LIGHTGRAY);
+        draw_text("Difficulty: NORMAL (fixed)  |  One attempt per day", box_x + 20.0, box_y + 88.0, 16.0, GRAY);
+        draw_text("Starter ship, veteran level 0, no permanent upgrades", box_x + 20.0, box_y + 108.0, 16.0, GRAY);
+
+        let btn_w = 220.0;
+        let btn_h = 44.0;
+        let btn_x = box_x + (box_w - btn_w) / 2.0;
+        let btn_y = box_y + 128.0;
+        match state.profile.daily_record(&date) {
+            Some(record) => {
+                draw_rectangle(btn_x, btn_y, btn_w, btn_h, color_u8!(40, 40, 45, 255));
+                draw_rectangle_lines(btn_x, btn_y, btn_w, btn_h, 2.0, DARKGRAY);
+                let text = "Already played today";
+                let text_w = measure_text(text, None, 20, 1.0).width;
+                draw_text(text, btn_x + (btn_w - text_w) / 2.0, btn_y + 28.0, 20.0, GRAY);
+
+                let minutes = (record.time_survived / 60.0).floor() as i32;
+                let seconds = (record.time_survived % 60.0).floor() as i32;
+                let score = format!("Your result: {:02}:{:02} survived, {} kills", minutes, seconds, record.enemies_killed);
+                let score_w = measure_text(&score, None, 18, 1.0).width;
+                draw_text(&score, box_x + (box_w - score_w) / 2.0, btn_y + btn_h + 28.0, 18.0, YELLOW);
+            }
+            None => {
+                draw_rectangle(btn_x, btn_y, btn_w, btn_h, color_u8!(60, 60, 80, 255));
+                draw_rectangle_lines(btn_x, btn_y, btn_w, btn_h, 2.0, color_u8!(100, 100, 140, 255));
+                let text = "START";
+                let text_w = measure_text(text, None, 28, 1.0).width;
+                draw_text(text, btn_x + (btn_w - text_w) / 2.0, btn_y + 31.0, 28.0, WHITE);
+            }
+        }
+
+        draw_text("Enter: Start  |  Esc: Back", box_x + 20.0, box_y + box_h - 15.0, 16.0, GRAY);
+    }
+
+    /// Fastest escapes from the profile as a ranked table
+    pub fn draw_high_scores(&self, state: &GameState) {
+        draw_rectangle(0.0, 0.0, screen_width(), screen_height(), color_u8!(15, 15, 25, 255));
+
+        let title = "HIGH SCORES";
+        let title_w = measure_text(title, None, 40, 1.0).width;
+        draw_text(title, (screen_width() - title_w) / 2.0, 80.0, 40.0, WHITE);
+
+        let table_w = 560.0;
+        let x = (screen_width() - table_w) / 2.0;
+        let columns = [x + 15.0, x + 90.0, x + 220.0, x + 340.0, x + 450.0];
+        let header_y = 140.0;
+        for (label, cx) in ["RANK", "TIME", "CREDITS", "DIFFICULTY", "DATE"].iter().zip(columns) {
+            draw_text(label, cx, header_y, 18.0, GOLD);
+        }
+        draw_line(x, header_y + 10.0, x + table_w, header_y + 10.0, 1.0, DARKGRAY);
+
+        let records = &state.profile.run_records;
+        if records.is_empty() {
+            let empty = "No escapes yet";
+            let empty_w = measure_text(empty, None, 22, 1.0).width;
+            draw_text(empty, (screen_width() - empty_w) / 2.0, header_y + 60.0, 22.0, GRAY);
+        }
+
+        let row_h = 45.0;
+        for (i, record) in records.iter().take(HIGH_SCORE_ROWS).enumerate() {
+            let y = header_y + 20.0 + i as f32 * row_h;
+            let bg = if i % 2 == 0 { color_u8!(35, 35, 45, 255) } else { color_u8!(28, 28, 36, 255) };
+            draw_rectangle(x, y, table_w, row_h - 5.0, bg);
+
+            let minutes = (record.time_survived / 60.0).floor() as i32;
+            let seconds = (record.time_survived % 60.0).floor() as i32;
+            let text_y = y + 27.0;
+            let color = if i == 0 { GOLD } else { WHITE };
+            draw_text(&format!("#{}", i + 1), columns[0], text_y, 22.0, color);
+            draw_text(&format!("{:02}:{:02}", minutes, seconds), columns[1], text_y, 22.0, color);
+            draw_text(&record.credits.to_string(), columns[2], text_y, 22.0, LIGHTGRAY);
+            let difficulty_color = if record.ironman { RED } else { LIGHTGRAY }; // Ironman runs stand out
+            draw_text(&record.difficulty, columns[3], text_y, 20.0, difficulty_color);
+            draw_text(&record.date, columns[4], text_y, 18.0, GRAY);
+        }
+
+        let hint = "Esc / Enter: Back";
+        let hint_w = measure_text(hint, None, 18, 1.0).width;
+        draw_text(hint, (screen_width() - hint_w) / 2.0, screen_height() - 40.0, 18.0, GRAY);
+    }
+
+    /// Codex overlay: entry list on the left, stats and lore for the selected entry on the right
+    pub fn draw_codex(&self, state: &GameState, selected_entry: usize) {
+        draw_rectangle(0.0, 0.0, screen_width(), screen_height(), color_u8!(0, 0, 0, 200));
+
+        let box_w = 800.0;
+        let box_h = 500.0;
+        let box_x = (screen_width() - box_w) / 2.0;
+        let box_y = (screen_height() - box_h) / 2.0;
+
+        draw_rectangle(box_x, box_y, box_w, box_h, color_u8!(25, 25, 35, 255));
+        draw_rectangle_lines(box_x, box_y, box_w, box_h, 3.0, color_u8!(80, 80, 120, 255));
+
+        let title = "CODEX";
+        let title_w = measure_text(title, None, 32, 1.0).width;
+        draw_text(title, box_x + (box_w - title_w) / 2.0, box_y + 40.0, 32.0, WHITE);
+
+        // Entry list
+        let list_x = box_x + 20.0;
+        let list_w = 220.0;
+        let row_h = 36.0;
+        let start_y = box_y + 65.0;
+        for (i, entry) in state.codex_entries.iter().enumerate() {
+            let y = start_y + i as f32 * row_h;
+            let is_selected = i == selected_entry;
+            if is_selected {
+                draw_rectangle(list_x, y, list_w, row_h - 6.0, color_u8!(50, 50, 70, 255));
+            }
+            let marker_color = match entry.category {
+                CodexCategory::Enemy(_) => RED,
+                CodexCategory::Module(_) => SKYBLUE,
+            };
+            draw_rectangle(list_x, y, 4.0, row_h - 6.0, marker_color);
+            draw_text(&entry.title, list_x + 12.0, y + 21.0, 20.0, if is_selected { YELLOW } else { WHITE });
+        }
+
+        // Selected entry details
+        let Some(entry) = state.codex_entries.get(selected_entry) else { return };
+        let detail_x = list_x + list_w + 30.0;
+        let detail_w = box_x + box_w - detail_x - 20.0;
+        let mut y = box_y + 85.0;
+
+        draw_text(&entry.title, detail_x, y, 28.0, WHITE);
+        y += 30.0;
+
+        let stat_lines = match entry.category {
+            CodexCategory::Enemy(enemy_type) => {
+                let (hp, speed, damage) = enemy_type.base_stats();
+                vec![
+                    "Enemy".to_string(),
+                    format!("HP: {:.0}", hp),
+                    format!("Speed: {:.0}", speed),
+                    format!("Damage: {:.0}/s", damage),
+                ]
+            }
+            CodexCategory::Module(module_type) => {
+                let stats = state.module_registry.get(module_type);
+                let power = if stats.power_consumption >= 0 {
+                    format!("Power: +{}", stats.power_consumption)
                 } else {
-                    draw_text("Insufficient Funds", card_x + card_w - 150.0, y + 60.0, 16.0, RED);
+                    format!("Power: {}", stats.power_consumption)
+                };
+                let mut lines = vec![
+                    format!("Module - {}", stats.name),
+                    format!("Health: {:.0}", stats.max_health),
+                    power,
+                ];
+                if stats.damage > 0.0 {
+                    lines.push(format!("Damage: {:.0}  Range: {:.0}  Rate: {:.1}/s", stats.damage, stats.range, stats.fire_rate));
                 }
+                lines
             }
+        };
+        for line in &stat_lines {
+            draw_text(line, detail_x, y, 18.0, LIGHTGRAY);
+            y += 22.0;
+        }
+
+        y += 15.0;
+        for line in wrap_text(&entry.body, detail_w, 18) {
+            draw_text(&line, detail_x, y, 18.0, WHITE);
+            y += 22.0;
+        }
+
+        let hint = "Up/Down: Browse | Esc: Back";
+        let hint_w = measure_text(hint, None, 14, 1.0).width;
+        draw_text(hint, box_x + (box_w - hint_w) / 2.0, box_y + box_h - 15.0, 14.0, GRAY);
+    }
+
+    /// "Achievement unlocked" popups stacked in the top-right corner
+    pub fn draw_achievement_notifications(&self, state: &GameState) {
+        let box_w = 260.0;
+        let box_h = 50.0;
+        let box_x = screen_width() - box_w - 10.0;
+
+        for (i, notification) in state.achievement_notifications.iter().enumerate() {
+            let alpha = (notification.timer / 0.5).clamp(0.0, 1.0);
+            let y = 10.0 + i as f32 * (box_h + 8.0);
+
+            draw_rectangle(box_x, y, box_w, box_h, Color::new(0.1, 0.1, 0.15, 0.9 * alpha));
+            draw_rectangle_lines(box_x, y, box_w, box_h, 2.0, Color::new(GOLD.r, GOLD.g, GOLD.b, alpha));
+            draw_text("ACHIEVEMENT UNLOCKED", box_x + 10.0, y + 18.0, 14.0, Color::new(GOLD.r, GOLD.g, GOLD.b, alpha));
+            draw_text(&notification.name, box_x + 10.0, y + 40.0, 22.0, Color::new(1.0, 1.0, 1.0, alpha));
         }
+    }
+
+    /// Status message banner (save/load results), fades out over its last half second
+    pub fn draw_toast(&self, message: &str, timer: f32) {
+        let alpha = (timer / 0.5).clamp(0.0, 1.0);
+        let text_w = measure_text(message, None, 20, 1.0).width;
+        let box_w = text_w + 40.0;
+        let box_x = (screen_width() - box_w) / 2.0;
+        let box_y = 60.0;
+
+        draw_rectangle(box_x, box_y, box_w, 36.0, Color::new(0.0, 0.0, 0.0, 0.75 * alpha));
+        draw_rectangle_lines(box_x, box_y, box_w, 36.0, 2.0, Color::new(1.0, 1.0, 1.0, alpha));
+        draw_text(message, box_x + 20.0, box_y + 24.0, 20.0, Color::new(1.0, 1.0, 1.0, alpha));
+    }
+}
+
+/// Gameplay tips shown while assets load
+pub fn load_tips() -> Vec<&'static str> {
+    const TIPS_JSON: &str = include_str!("../../assets/tips.json");
+    serde_json::from_str(TIPS_JSON).unwrap_or_else(|e| {
+        eprintln!("Warning: Failed to load tips.json: {}. Showing no tips.", e);
+        Vec::new()
+    })
+}
+
+/// Startup screen with a progress bar and a gameplay tip
+pub fn draw_loading_screen(progress: f32, tip: &str) {
+    clear_background(BLACK);
+    draw_rectangle(0.0, 0.0, screen_width(), screen_height(), color_u8!(15, 15, 25, 255));
 
-        let footer = "Press [ENTER] to start next round | Press [ESC] for Menu";
-        let footer_w = measure_text(footer, None, 20, 1.0).width;
-        draw_text(footer, (screen_width() - footer_w) / 2.0, screen_height() - 40.0, 20.0, DARKGRAY);
+    let title = "SCRAPYARD PLANET";
+    let title_w = measure_text(title, None, 40, 1.0).width;
+    draw_text(title, (screen_width() - title_w) / 2.0, screen_height() / 2.0 - 60.0, 40.0, WHITE);
+
+    let bar_w = 400.0;
+    let bar_h = 16.0;
+    let bar_x = (screen_width() - bar_w) / 2.0;
+    let bar_y = screen_height() / 2.0 - bar_h / 2.0;
+    draw_rectangle(bar_x, bar_y, bar_w, bar_h, color_u8!(35, 35, 45, 255));
+    draw_rectangle(bar_x, bar_y, bar_w * progress.clamp(0.0, 1.0), bar_h, YELLOW);
+    draw_rectangle_lines(bar_x, bar_y, bar_w, bar_h, 1.0, GRAY);
+
+    let label = format!("Loading... {:.0}%", progress.clamp(0.0, 1.0) * 100.0);
+    let label_w = measure_text(&label, None, 18, 1.0).width;
+    draw_text(&label, (screen_width() - label_w) / 2.0, bar_y + bar_h + 26.0, 18.0, GRAY);
+
+    for (i, line) in wrap_text(tip, 600.0, 20).iter().enumerate() {
+        let line_w = measure_text(line, None, 20, 1.0).width;
+        draw_text(line, (screen_width() - line_w) / 2.0, screen_height() - 90.0 + i as f32 * 24.0, 20.0, LIGHTGRAY);
+    }
+}
+
+/// Greedy word wrap to fit `max_width` at the given font size
+fn wrap_text(text: &str, max_width: f32, font_size: u16) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut current = String::new();
+    for word in text.split_whitespace() {
+        let candidate = if current.is_empty() { word.to_string() } else { format!("{} {}", current, word) };
+        if !current.is_empty() && measure_text(&candidate, None, font_size, 1.0).width > max_width {
+            lines.push(std::mem::replace(&mut current, word.to_string()));
+        } else {
+            current = candidate;
+        }
+    }
+    if !current.is_empty() {
+        lines.push(current);
     }
+    lines
 }
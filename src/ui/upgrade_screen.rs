@@ -0,0 +1,239 @@
+//! Between-round upgrade screen, permanent upgrades, Cockpit shop and crafting menu
+
+use macroquad::prelude::*;
+use crate::state::GameState;
+use crate::ui::renderer::Renderer;
+
+impl Renderer {
+    pub fn draw_upgrade_screen(&self, state: &GameState) {
+        draw_rectangle(0.0, 0.0, screen_width(), screen_height(), color_u8!(15, 20, 30, 255));
+        let title = "SHIP IMPROVEMENTS";
+        let title_w = measure_text(title, None, 48, 1.0).width;
+        draw_text(title, (screen_width() - title_w) / 2.0, 60.0, 48.0, WHITE);
+        
+        let credits_text = format!("AVAILABLE CREDITS: {}", state.resources.credits);
+        let cred_w = measure_text(&credits_text, None, 24, 1.0).width;
+        draw_text(&credits_text, (screen_width() - cred_w) / 2.0, 100.0, 24.0, GREEN);
+
+        if state.run_stats.escape_credits > 0 {
+            let breakdown = format!("Earned: {} escape + {} defense bonus", state.run_stats.escape_credits, state.run_stats.defense_bonus);
+            let breakdown_w = measure_text(&breakdown, None, 18, 1.0).width;
+            draw_text(&breakdown, (screen_width() - breakdown_w) / 2.0, 125.0, 18.0, LIGHTGRAY);
+        }
+
+        let start_y = 150.0;
+        let card_w = 600.0;
+        let card_h = 80.0;
+        let spacing = 20.0;
+        let card_x = (screen_width() - card_w) / 2.0;
+        let max_unlocked_upgrades = state.unlocked_upgrade_count();
+        let mut locked_hover = false;
+
+        for (i, template) in state.upgrade_templates.iter().enumerate() {
+            let y = start_y + i as f32 * (card_h + spacing);
+            if i >= max_unlocked_upgrades {
+                draw_rectangle(card_x, y, card_w, card_h, color_u8!(25, 25, 30, 255));
+                draw_rectangle_lines(card_x, y, card_w, card_h, 2.0, DARKGRAY);
+                draw_text(&template.name, card_x + 15.0, y + 30.0, 24.0, DARKGRAY);
+                draw_text("Requires a repaired Laboratory point", card_x + 15.0, y + 55.0, 16.0, DARKGRAY);
+                draw_lock_icon(card_x + card_w - 50.0, y + 22.0);
+
+                let (mx, my) = mouse_position();
+                locked_hover |= mx >= card_x && mx <= card_x + card_w && my >= y && my <= y + card_h;
+                continue;
+            }
+            if !state.schematic_known(template) {
+                draw_unknown_schematic_card(card_x, y, card_w, card_h);
+                continue;
+            }
+            let current_level = state.upgrades.get_level(&template.id);
+            let is_max = current_level >= template.max_level;
+            let cost = state.upgrades.get_cost(template);
+            let can_afford = state.resources.credits >= cost && !is_max;
+
+            let bg_color = if is_max { color_u8!(40, 50, 40, 255) } else if can_afford { color_u8!(40, 40, 60, 255) } else { color_u8!(30, 30, 35, 255) };
+            draw_rectangle(card_x, y, card_w, card_h, bg_color);
+            draw_rectangle_lines(card_x, y, card_w, card_h, 2.0, if can_afford { YELLOW } else { GRAY });
+
+            draw_text(&format!("{} (Level {}/{})", template.name, current_level, template.max_level), card_x + 15.0, y + 30.0, 24.0, WHITE);
+            draw_text(&template.description, card_x + 15.0, y + 55.0, 16.0, GRAY);
+
+            if is_max {
+                draw_text("MAX LEVEL", card_x + card_w - 120.0, y + 45.0, 20.0, GREEN);
+            } else {
+                let cost_color = if can_afford { WHITE } else { RED };
+                draw_text(&format!("Cost: {} Cr", cost), card_x + card_w - 150.0, y + 35.0, 20.0, cost_color);
+                if can_afford {
+                    draw_text(&format!("[{}] Buy", i + 1), card_x + card_w - 150.0, y + 60.0, 20.0, YELLOW);
+                } else {
+                    draw_text("Insufficient Funds", card_x + card_w - 150.0, y + 60.0, 16.0, RED);
+                }
+            }
+        }
+
+        self.draw_permanent_upgrades(state);
+
+        let footer = "Press [ENTER] to start next round | Press [ESC] for Menu";
+        let footer_w = measure_text(footer, None, 20, 1.0).width;
+        draw_text(footer, (screen_width() - footer_w) / 2.0, screen_height() - 40.0, 20.0, DARKGRAY);
+
+        if locked_hover {
+            let (mx, my) = mouse_position();
+            let tip = "Each repaired Laboratory point unlocks one more improvement";
+            let tip_w = measure_text(tip, None, 16, 1.0).width + 20.0;
+            let tip_x = (mx + 15.0).min(screen_width() - tip_w);
+            draw_rectangle(tip_x, my + 15.0, tip_w, 28.0, color_u8!(20, 20, 30, 240));
+            draw_rectangle_lines(tip_x, my + 15.0, tip_w, 28.0, 1.0, GRAY);
+            draw_text(tip, tip_x + 10.0, my + 34.0, 16.0, LIGHTGRAY);
+        }
+    }
+
+    /// Side panel listing upgrades that persist across runs
+    pub fn draw_permanent_upgrades(&self, state: &GameState) {
+        let panel_w = 300.0;
+        let row_h = 70.0;
+        let panel_x = screen_width() - panel_w - 20.0;
+        let panel_y = 150.0;
+        let panel_h = 50.0 + state.permanent_upgrade_templates.len() as f32 * row_h;
+
+        draw_rectangle(panel_x, panel_y, panel_w, panel_h, color_u8!(25, 25, 35, 255));
+        draw_rectangle_lines(panel_x, panel_y, panel_w, panel_h, 2.0, color_u8!(80, 80, 120, 255));
+        draw_text("PERMANENT", panel_x + 15.0, panel_y + 30.0, 24.0, GOLD);
+        let banked = format!("Banked: {} Cr", state.profile.banked_credits);
+        let banked_w = measure_text(&banked, None, 16, 1.0).width;
+        draw_text(&banked, panel_x + panel_w - banked_w - 15.0, panel_y + 30.0, 16.0, GREEN);
+
+        for (i, template) in state.permanent_upgrade_templates.iter().enumerate() {
+            let y = panel_y + 50.0 + i as f32 * row_h;
+            let level = state.profile.permanent_level(&template.id);
+            let is_max = level >= template.max_level;
+            let cost = state.profile.permanent_cost(template);
+            let can_afford = state.profile.banked_credits >= cost && !is_max;
+
+            draw_text(&format!("{} ({}/{})", template.name, level, template.max_level), panel_x + 15.0, y + 18.0, 20.0, WHITE);
+            let status = if is_max {
+                ("MAX LEVEL".to_string(), GREEN)
+            } else if can_afford {
+                (format!("[Shift+{}] Buy - {} Cr", i + 1, cost), YELLOW)
+            } else {
+                (format!("{} Cr", cost), RED)
+            };
+            draw_text(&status.0, panel_x + 15.0, y + 40.0, 16.0, status.1);
+            draw_line(panel_x + 10.0, y + row_h - 12.0, panel_x + panel_w - 10.0, y + row_h - 12.0, 1.0, DARKGRAY);
+        }
+    }
+
+    /// Storage room crafting overlay: recipe list plus current inventory
+    pub fn draw_crafting_menu(&self, state: &GameState) {
+        draw_rectangle(0.0, 0.0, screen_width(), screen_height(), color_u8!(0, 0, 0, 200));
+
+        let title = "CRAFTING";
+        let title_w = measure_text(title, None, 40, 1.0).width;
+        draw_text(title, (screen_width() - title_w) / 2.0, 110.0, 40.0, WHITE);
+
+        let scrap_text = format!("Scrap: {}", state.resources.scrap);
+        let scrap_w = measure_text(&scrap_text, None, 20, 1.0).width;
+        draw_text(&scrap_text, (screen_width() - scrap_w) / 2.0, 140.0, 20.0, ORANGE);
+
+        let card_w = 400.0;
+        let card_h = 70.0;
+        let spacing = 15.0;
+        let card_x = (screen_width() - card_w) / 2.0;
+        let start_y = 165.0;
+
+        for (i, recipe) in state.recipe_book.recipes.iter().enumerate() {
+            let y = start_y + i as f32 * (card_h + spacing);
+            let is_selected = i == state.crafting_selection;
+            let affordable = state.resources.can_afford(recipe.scrap_cost);
+
+            let bg_color = if is_selected { color_u8!(60, 60, 90, 255) } else { color_u8!(35, 35, 45, 255) };
+            draw_rectangle(card_x, y, card_w, card_h, bg_color);
+            draw_rectangle_lines(card_x, y, card_w, card_h, 2.0, if is_selected { YELLOW } else { GRAY });
+
+            let text_color = if affordable { WHITE } else { GRAY };
+            draw_text(&recipe.output.name(), card_x + 15.0, y + 30.0, 24.0, text_color);
+            let cost = format!("{} Scrap", recipe.scrap_cost);
+            draw_text(&cost, card_x + 15.0, y + 55.0, 18.0, if affordable { ORANGE } else { RED });
+
+            let owned = state.inventory.iter().filter(|item| **item == recipe.output).count();
+            let owned_text = format!("Owned: {}", owned);
+            let owned_w = measure_text(&owned_text, None, 18, 1.0).width;
+            draw_text(&owned_text, card_x + card_w - owned_w - 15.0, y + 40.0, 18.0, LIGHTGRAY);
+        }
+
+        let hint = "Up/Down: Select | Enter: Craft | Esc: Back";
+        let hint_w = measure_text(hint, None, 18, 1.0).width;
+        draw_text(hint, (screen_width() - hint_w) / 2.0, screen_height() - 40.0, 18.0, GRAY);
+    }
+
+    /// Cockpit shop overlay: this round's upgrade offers, priced in scrap
+    pub fn draw_in_run_shop(&self, state: &GameState) {
+        draw_rectangle(0.0, 0.0, screen_width(), screen_height(), color_u8!(0, 0, 0, 200));
+
+        let title = "COCKPIT SHOP";
+        let title_w = measure_text(title, None, 40, 1.0).width;
+        draw_text(title, (screen_width() - title_w) / 2.0, 110.0, 40.0, WHITE);
+
+        let scrap_text = format!("Scrap: {}   Restocks left: {}", state.resources.scrap, state.shop_refreshes_remaining);
+        let scrap_w = measure_text(&scrap_text, None, 20, 1.0).width;
+        draw_text(&scrap_text, (screen_width() - scrap_w) / 2.0, 140.0, 20.0, ORANGE);
+
+        let card_w = 500.0;
+        let card_h = 70.0;
+        let spacing = 15.0;
+        let card_x = (screen_width() - card_w) / 2.0;
+        let start_y = 165.0;
+
+        if state.shop_offers.is_empty() {
+            let empty = "Sold out - check back after the next restock";
+            let empty_w = measure_text(empty, None, 20, 1.0).width;
+            draw_text(empty, (screen_width() - empty_w) / 2.0, start_y + 30.0, 20.0, GRAY);
+        }
+
+        for (i, id) in state.shop_offers.iter().enumerate() {
+            let Some(template) = state.upgrade_templates.iter().find(|t| &t.id == id) else { continue };
+            let y = start_y + i as f32 * (card_h + spacing);
+            let is_selected = i == state.shop_selection;
+            let cost = state.in_run_shop_cost(id);
+            let affordable = cost.is_some_and(|c| state.resources.can_afford(c));
+
+            let bg_color = if is_selected { color_u8!(60, 60, 90, 255) } else { color_u8!(35, 35, 45, 255) };
+            draw_rectangle(card_x, y, card_w, card_h, bg_color);
+            draw_rectangle_lines(card_x, y, card_w, card_h, 2.0, if is_selected { YELLOW } else { GRAY });
+
+            let level = state.upgrades.get_level(id);
+            let text_color = if affordable { WHITE } else { GRAY };
+            draw_text(&format!("{} (Level {}/{})", template.name, level, template.max_level), card_x + 15.0, y + 28.0, 22.0, text_color);
+            draw_text(&template.description, card_x + 15.0, y + 52.0, 14.0, LIGHTGRAY);
+
+            let cost_text = cost.map_or("MAX".to_string(), |c| format!("{} Scrap", c));
+            let cost_w = measure_text(&cost_text, None, 18, 1.0).width;
+            draw_text(&cost_text, card_x + card_w - cost_w - 15.0, y + 28.0, 18.0, if affordable { ORANGE } else { RED });
+        }
+
+        let hint = "Up/Down: Select | Enter: Buy | E / Esc: Back";
+        let hint_w = measure_text(hint, None, 18, 1.0).width;
+        draw_text(hint, (screen_width() - hint_w) / 2.0, screen_height() - 40.0, 18.0, GRAY);
+    }
+}
+
+/// Padlock drawn on locked upgrade cards; (x, y) is the top-left of the shackle
+fn draw_lock_icon(x: f32, y: f32) {
+    draw_circle_lines(x + 12.0, y + 12.0, 8.0, 3.0, GRAY);
+    draw_rectangle(x, y + 12.0, 24.0, 20.0, GRAY);
+    draw_circle(x + 12.0, y + 21.0, 3.0, color_u8!(25, 25, 30, 255));
+}
+
+/// Upgrade card whose schematic hasn't been found: smeared placeholder text bars
+/// stand in for a blurred name and description
+fn draw_unknown_schematic_card(x: f32, y: f32, w: f32, h: f32) {
+    draw_rectangle(x, y, w, h, color_u8!(25, 30, 40, 255));
+    draw_rectangle_lines(x, y, w, h, 2.0, color_u8!(60, 90, 120, 255));
+    for offset in [-2.0, 0.0, 2.0] {
+        draw_rectangle(x + 15.0 + offset, y + 40.0, 260.0, 8.0, color_u8!(70, 100, 130, 60));
+        draw_rectangle(x + 15.0 + offset, y + 56.0, 380.0, 6.0, color_u8!(70, 100, 130, 40));
+    }
+    draw_text("UNKNOWN SCHEMATIC", x + 15.0, y + 28.0, 24.0, color_u8!(120, 170, 220, 255));
+    draw_text("Scavenge scrap piles to find it", x + w - 290.0, y + 72.0, 16.0, DARKGRAY);
+    draw_lock_icon(x + w - 50.0, y + 22.0);
+}
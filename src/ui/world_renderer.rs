@@ -2,8 +2,12 @@ use macroquad::prelude::*;
 use crate::state::{GameState, ViewMode};
 use crate::simulation::constants::*;
 use crate::ship::ship::{ModuleType, ModuleState, Module};
-use crate::ship::interior::{REPAIR_POINT_SIZE, RoomType};
+use crate::ship::interior::RoomType;
 use crate::ui::renderer::Renderer;
+use crate::enemy::entities::{EnemyType, ProjectileType, SpawnerRoom};
+use crate::simulation::gameplay::ModuleStats;
+use crate::enemy::combat::weapon_fire_rate;
+use crate::ship::layout::Layout;
 
 impl Renderer {
     pub fn draw_gameplay(&self, state: &GameState) {
@@ -12,11 +16,20 @@ impl Renderer {
         
         match state.view_mode {
             ViewMode::Exterior => {
+                set_camera(&self.exterior_camera());
                 self.draw_ship_hull(state);
                 self.draw_ship_grid(state);
                 self.draw_enemies(state, shake);
                 self.draw_projectiles(state, shake);
                 self.draw_particles(state, shake);
+                self.draw_damage_numbers(state, shake);
+                set_default_camera();
+
+                self.draw_enemy_indicators(state);
+                self.draw_hovered_module_tooltip(state);
+                if state.settings.show_minimap {
+                    self.draw_minimap(state);
+                }
             }
             ViewMode::Interior => {
                 self.draw_interior(state);
@@ -25,6 +38,12 @@ impl Renderer {
         
         // Draw HUD with stats (always visible)
         self.draw_hud(state);
+        // Screen shake off doubles as reduced motion, which also drops the flashing ring
+        if state.engine_state == crate::state::EngineState::Charging && state.settings.screen_shake {
+            self.draw_escape_countdown_ring(state);
+        }
+        self.draw_flavor_popups(state);
+        self.draw_wave_summary();
         
         // View mode indicator
         let mode_text = match state.view_mode {
@@ -39,295 +58,37 @@ impl Renderer {
         }
     }
     
-    fn draw_hud(&self, state: &GameState) {
-        // HUD background bar at top
-        draw_rectangle(0.0, 0.0, screen_width(), 35.0, color_u8!(0, 0, 0, 180));
-        
-        // Power info
-        // Power info
-        let max_power: i32 = state.interior.rooms.iter()
-            .filter(|r| matches!(r.room_type, crate::ship::interior::RoomType::Module(ModuleType::Core)))
-            .map(|r| r.repair_points.len() as i32 * POWER_PER_CORE_POINT)
-            .sum();
-
-        let power_color = if state.used_power <= state.total_power { GREEN } else { RED };
-        let power_text = format!("Power: {}/{} [{}]", state.used_power, state.total_power, max_power);
-        draw_text(&power_text, 20.0, 24.0, 20.0, power_color);
-        
-        // Scrap
-        let scrap_text = format!("Scrap: {}", state.resources.scrap);
-        draw_text(&scrap_text, 180.0, 24.0, 20.0, ORANGE);
-        
-        // Credits
-        let credits_text = format!("Credits: {}", state.resources.credits);
-        draw_text(&credits_text, 320.0, 24.0, 20.0, YELLOW);
-        
-        // Ship integrity
-        let hp_pct = state.ship_integrity / state.ship_max_integrity;
-        let hp_color = if hp_pct > 0.6 { GREEN } else if hp_pct > 0.3 { YELLOW } else { RED };
-        let hp_text = format!("Hull: {:.0}/{:.0}", state.ship_integrity, state.ship_max_integrity);
-        draw_text(&hp_text, 480.0, 24.0, 20.0, hp_color);
-
-        // Engine Status
-        let (stress_text, stress_color) = if state.engine_stress >= STRESS_THRESHOLD_CRITICAL {
-            ("ENGINE: CASCADE", RED)
-        } else if state.engine_stress >= STRESS_THRESHOLD_UNSTABLE {
-            ("ENGINE: UNSTABLE", ORANGE)
-        } else if state.engine_stress >= STRESS_THRESHOLD_STRAINED {
-            ("ENGINE: STRAINED", YELLOW)
-        } else {
-             if state.engine_stress > 0.0 {
-                 ("ENGINE: WARM", GREEN)
-             } else {
-                 ("ENGINE: STABLE", BLUE)
-             }
-        };
-        // Shake text if critical
-        let (dx, dy) = if state.engine_stress >= STRESS_THRESHOLD_CRITICAL { 
-             (macroquad::rand::gen_range(-2.0, 2.0), macroquad::rand::gen_range(-2.0, 2.0))
-        } else { (0.0, 0.0) };
-        draw_text(stress_text, 680.0 + dx, 24.0 + dy, 20.0, stress_color);
-        
-        // Nanite Alert
-        let alert_x = 900.0;
-        draw_text("Alert:", alert_x, 24.0, 20.0, WHITE);
-        draw_rectangle(alert_x + 60.0, 10.0, 100.0, 14.0, DARKGRAY);
-        let alert_pct = (state.nanite_alert / 50.0).clamp(0.0, 1.0);
-        draw_rectangle(alert_x + 60.0, 10.0, 100.0 * alert_pct, 14.0, RED);
-        
-        // Engine/Escape timer (if charging)
-        if state.engine_state == crate::state::EngineState::Charging {
-            let mins = (state.escape_timer / 60.0).floor() as i32;
-            let secs = (state.escape_timer % 60.0).floor() as i32;
-            let escape_text = format!("ESCAPE: {:02}:{:02}", mins, secs);
-            draw_text(&escape_text, screen_width() - 180.0, 48.0, 20.0, SKYBLUE);
-        }
-    }
+    /// The escaped ship climbing off the top of the screen and fading out, engines
+    /// trailing fire (spawned by GameState::update_victory_animation)
+    pub fn draw_victory_animation(&self, state: &GameState) {
+        let background = color_u8!(10, 20, 30, 255);
+        clear_background(background);
+        let lift = state.victory_lift();
+        let progress = lift / screen_height();
 
-    pub fn draw_interior(&self, state: &GameState) {
-        let interior = &state.interior;
-        
-        // Camera offset to center on player
-        let cam_x = if interior.width < screen_width() {
-            (screen_width() - interior.width) / 2.0
-        } else {
-            (screen_width() / 2.0 - state.player.position.x)
-                .clamp(screen_width() - interior.width, 0.0)
-        };
-        let cam_y = if interior.height < screen_height() {
-            (screen_height() - interior.height) / 2.0
-        } else {
-            (screen_height() / 2.0 - state.player.position.y)
-                .clamp(screen_height() - interior.height, 0.0)
-        };
-        
-        // Background (void)
-        draw_rectangle(0.0, 0.0, screen_width(), screen_height(), color_u8!(10, 10, 15, 255));
-        
-        // Draw all interior elements
-        self.draw_rooms(state, cam_x, cam_y);
-        self.draw_player(state, cam_x, cam_y);
-        self.draw_scrap_piles(state, cam_x, cam_y);
-        self.draw_repair_prompt(state, cam_x, cam_y);
-    }
-    
-    fn draw_rooms(&self, state: &GameState, cam_x: f32, cam_y: f32) {
-        let tile_size = 64.0; // Tile size
-        
-        for room in &state.interior.rooms {
-            let rx = cam_x + room.x;
-            let ry = cam_y + room.y;
-            
-            // Draw floor tiles
-            let floor_tex_name = match room.room_type {
-                RoomType::Module(ModuleType::Core) => "tile_floor_core",
-                RoomType::Module(ModuleType::Weapon) => "tile_floor_weapon",
-                RoomType::Module(ModuleType::Defense) => "tile_floor_defense",
-                RoomType::Module(ModuleType::Engine) => "tile_floor_engine",
-                RoomType::Module(ModuleType::Utility) => "tile_floor_utility",
-                RoomType::Medbay => "tile_floor_medbay",
-                RoomType::Cockpit => "tile_floor_cockpit",
-                RoomType::Storage => "tile_floor_storage",
-                RoomType::Corridor => "tile_floor_corridor",
-                _ => "tile_floor_corridor",
-            };
+        let mut camera = self.exterior_camera();
+        camera.target.y += lift;
+        set_camera(&camera);
+        self.draw_ship_hull(state);
+        self.draw_ship_grid(state);
+        set_camera(&self.exterior_camera());
+        let (w, h) = (screen_width(), screen_height());
+        draw_rectangle(-w, -h, w * 3.0, h * 3.0, Color::new(background.r, background.g, background.b, progress));
+        self.draw_particles(state, Vec2::ZERO);
+        set_default_camera();
 
-            if let Some(tex) = state.assets.get_texture(floor_tex_name) {
-                // Tiling: draw texture across the room
-                let cols = (room.width / tile_size).ceil() as i32;
-                let rows = (room.height / tile_size).ceil() as i32;
-                
-                for r in 0..rows {
-                    for c in 0..cols {
-                       draw_texture(tex, rx + c as f32 * tile_size, ry + r as f32 * tile_size, WHITE);
-                    }
-                }
-            } else {
-                 draw_rectangle(rx, ry, room.width, room.height, room.color());
-            }
-            
-            // Draw walls (top edge) using tile_wall_tech if room above is empty? 
-            // Simplified: Just draw walls on the boundaries if desired, but for top-down, usually walls are just drawn.
-            // Let's draw `tile_wall_tech` along the top edge of the room.
-            if let Some(wall_tex) = state.assets.get_texture("tile_wall_tech") {
-                 let cols = (room.width / tile_size).ceil() as i32;
-                 for c in 0..cols {
-                     // Draw wall "above" the room or at the top of the room?
-                     // Usually walls take up space. Here rooms are packed. 
-                     // Let's overlay at the top edge for visual flair.
-                     draw_texture_ex(wall_tex, rx + c as f32 * tile_size, ry - 10.0, WHITE, 
-                        DrawTextureParams {
-                            dest_size: Some(vec2(tile_size, 20.0)), // Squashed wall
-                            ..Default::default()
-                        }
-                    );
-                 }
-            }
-            
-            // Tutorial highlight
-            let is_target = state.tutorial_state.should_highlight(&state.tutorial_config, room.id);
-            if is_target && !room.is_fully_repaired() {
-                let pulse = ((state.frame_count as f32 * 0.1).sin() * 0.5 + 0.5) * 155.0 + 100.0;
-                draw_rectangle_lines(rx - 2.0, ry - 2.0, room.width + 4.0, room.height + 4.0, 4.0, 
-                    Color::new(1.0, 1.0, 0.0, pulse / 255.0));
-            } else {
-                draw_rectangle_lines(rx, ry, room.width, room.height, 2.0, color_u8!(70, 70, 80, 255));
-            }
-            
-            // Repair points (Props)
-            for (i, point) in room.repair_points.iter().enumerate() {
-                let px = rx + point.x;
-                let py = ry + point.y;
-                let half = REPAIR_POINT_SIZE / 2.0;
-                
-                // Determine prop type based on room
-                let prop_names = match room.room_type {
-                    RoomType::Module(ModuleType::Core) => vec!["prop_generator_coil", "prop_console_desk"],
-                    RoomType::Module(ModuleType::Weapon) => vec!["prop_ammo_loader", "prop_capacitor_bank"],
-                    RoomType::Module(ModuleType::Defense) => vec!["prop_shield_emitter", "prop_console_wall"],
-                    RoomType::Module(ModuleType::Engine) => vec!["prop_engine_valve", "prop_pipe_burst"],
-                    RoomType::Module(ModuleType::Utility) => vec!["prop_server_rack", "prop_console_wall"],
-                    RoomType::Medbay => vec!["prop_med_scanner", "prop_console_wall"], // Removed crypto_pod as it is tall
-                    _ => vec!["prop_console_wall"],
-                };
-                
-                // Pick stable random prop
-                let prop_name = prop_names[(point.id + i) % prop_names.len()];
-                
-                if let Some(tex) = state.assets.get_texture(prop_name) {
-                    let color = if point.repaired { WHITE } else { color_u8!(255, 150, 150, 255) }; // Red tint if broken
-                    let w = tex.width();
-                    let h = tex.height();
-                    
-                    // Center the prop
-                    draw_texture_ex(tex, px - w/2.0, py - h/2.0, color, DrawTextureParams::default());
-                    
-                    if !point.repaired {
-                         // Spark effect or icon
-                         // draw_rectangle_lines(px - w/2.0, py - h/2.0, w, h, 2.0, RED);
-                    }
-                } else {
-                    // Fallback
-                    if point.repaired {
-                        draw_rectangle(px - half, py - half, half * 2.0, half * 2.0, color_u8!(30, 100, 30, 255));
-                    } else {
-                        draw_rectangle(px - half, py - half, half * 2.0, half * 2.0, color_u8!(100, 40, 30, 255));
-                    }
-                }
-            }
-            
-            // Room name
-            let name = room.name();
-            if !name.is_empty() {
-                let text_size = 18.0;
-                let text_w = measure_text(name, None, text_size as u16, 1.0).width;
-                 draw_text(name, rx + (room.width - text_w) / 2.0, ry + 24.0, text_size, WHITE);
-            }
-        }
-    }
-    
-    fn draw_player(&self, state: &GameState, cam_x: f32, cam_y: f32) {
-        let player_screen_x = cam_x + state.player.position.x;
-        let player_screen_y = cam_y + state.player.position.y;
-        
-        draw_circle(player_screen_x, player_screen_y, state.player.size, color_u8!(100, 200, 255, 255));
-        draw_circle_lines(player_screen_x, player_screen_y, state.player.size, 2.0, WHITE);
-        
-        let facing_end = vec2(player_screen_x, player_screen_y) + state.player.facing * state.player.size;
-        draw_line(player_screen_x, player_screen_y, facing_end.x, facing_end.y, 2.0, WHITE);
-        
-        // Gathering progress bar
-        if state.gathering_target.is_some() && state.gathering_timer > 0.0 {
-            let progress = (state.gathering_timer / GATHERING_TIME_SECONDS).clamp(0.0, 1.0);
-            let bar_w = 40.0;
-            let bar_h = 6.0;
-            let px = player_screen_x - bar_w / 2.0;
-            let py = player_screen_y - 30.0;
-            
-            draw_rectangle(px, py, bar_w, bar_h, BLACK);
-            draw_rectangle(px, py, bar_w * progress, bar_h, GREEN);
-        }
-    }
-    
-    fn draw_scrap_piles(&self, state: &GameState, cam_x: f32, cam_y: f32) {
-        for pile in &state.scrap_piles {
-            if !pile.active { continue; }
-            let screen_pos_x = cam_x + pile.position.x;
-            let screen_pos_y = cam_y + pile.position.y;
-            
-            draw_circle(screen_pos_x, screen_pos_y, 8.0, BROWN);
-            draw_circle(screen_pos_x, screen_pos_y, 6.0, DARKBROWN);
-            
-            if pile.position.distance(state.player.position) < INTERACTION_RANGE {
-                draw_circle_lines(screen_pos_x, screen_pos_y, 12.0, 2.0, YELLOW);
-                if state.gathering_target.is_none() {
-                    draw_text("[Hold E] Scavenge", screen_pos_x - 40.0, screen_pos_y - 15.0, 16.0, WHITE);
-                }
-            }
-        }
-    }
-    
-    fn draw_repair_prompt(&self, state: &GameState, cam_x: f32, cam_y: f32) {
-        let interior = &state.interior;
-        let Some(room) = interior.room_at(state.player.position) else { return };
-        let Some(point_idx) = room.repair_point_at(state.player.position) else { return };
-        if room.repair_points[point_idx].repaired { return; }
-        
-        let Some(room_idx) = interior.rooms.iter().position(|r| r.id == room.id) else { return };
-        let Some((scrap_cost, power_cost)) = state.get_repair_cost(room_idx, point_idx) else { return };
-        
-        let player_screen_x = cam_x + state.player.position.x;
-        let player_screen_y = cam_y + state.player.position.y;
-        
-        let is_reactor = power_cost == 0;
-        let can_afford_scrap = state.resources.scrap >= scrap_cost;
-        let can_afford_power = is_reactor || (state.used_power + power_cost <= state.total_power);
-        
-        let cost_text = if is_reactor {
-            format!("{scrap_cost} Scrap")
-        } else {
-            format!("{scrap_cost} Scrap + {power_cost} Power")
-        };
-        
-        let label = if can_afford_scrap && can_afford_power {
-            format!("[E] Repair ({})", cost_text)
-        } else if !can_afford_scrap {
-            format!("Need {scrap_cost} Scrap")
-        } else {
-            format!("Need {power_cost} Power (Repair Reactor)")
-        };
-        
-        let color = if can_afford_scrap && can_afford_power { YELLOW } else { RED };
-        draw_text(&label, player_screen_x - 60.0, player_screen_y - 20.0, 16.0, color);
+        let hint = "[Space] Skip";
+        let size = measure_text(hint, None, 20, 1.0);
+        draw_text(hint, screen_width() - size.width - 20.0, screen_height() - 20.0, 20.0, GRAY);
     }
 
     pub fn draw_ship_hull(&self, state: &GameState) {
-        if let Some(tex) = state.assets.get_texture("ship_hull_scavenger") {
-            let total_width = GRID_WIDTH as f32 * CELL_SIZE;
-            let total_height = GRID_HEIGHT as f32 * CELL_SIZE;
-            let start_x = (screen_width() - total_width) / 2.0;
-            let start_y = (screen_height() - total_height) / 2.0;
+        let total_width = GRID_WIDTH as f32 * CELL_SIZE;
+        let total_height = GRID_HEIGHT as f32 * CELL_SIZE;
+        let start_x = (screen_width() - total_width) / 2.0;
+        let start_y = (screen_height() - total_height) / 2.0;
 
+        if let Some(tex) = state.assets.get_texture("ship_hull_scavenger") {
             // Draw hull slightly larger than grid and centered
             let scale_x = (total_width + 100.0) / tex.width();
             let scale_y = (total_height + 100.0) / tex.height();
@@ -343,6 +104,30 @@ impl Renderer {
                 ..Default::default()
             });
         }
+
+        self.draw_hull_damage_overlays(state, start_x, start_y, total_width, total_height);
+    }
+
+    /// Cracks that spread over the hull border as integrity drops, plus a red alarm
+    /// outline once it is low. Takes the grid rectangle; the hull extends 50px past it.
+    fn draw_hull_damage_overlays(&self, state: &GameState, start_x: f32, start_y: f32, total_width: f32, total_height: f32) {
+        if state.ship_max_integrity <= 0.0 { return; }
+        let integrity = state.ship_integrity / state.ship_max_integrity;
+        let stages = HULL_CRACK_THRESHOLDS.iter().filter(|&&t| integrity < t).count();
+        if stages == 0 { return; }
+
+        let origin = vec2(start_x - 50.0, start_y - 50.0);
+        let size = vec2(total_width + 100.0, total_height + 100.0);
+        let visible = stages * HULL_CRACKS_PER_STAGE * HULL_CRACK_SEGMENTS;
+        for (a, b) in state.hull_crack_positions.iter().take(visible) {
+            let (a, b) = (origin + *a * size, origin + *b * size);
+            draw_line(a.x, a.y, b.x, b.y, 2.0, color_u8!(20, 20, 20, 220));
+        }
+
+        if integrity < HULL_ALARM_THRESHOLD {
+            let pulse = ((get_time() * 6.0).sin() as f32 + 1.0) / 2.0;
+            draw_rectangle_lines(origin.x, origin.y, size.x, size.y, 4.0, Color::new(1.0, 0.1, 0.1, 0.25 + pulse * 0.5));
+        }
     }
 
     pub fn draw_ship_grid(&self, state: &GameState) {
@@ -362,17 +147,113 @@ impl Renderer {
                 if let Some(mod_data) = module {
                     self.draw_module_base(px, py, true);
                     draw_rectangle_lines(px, py, CELL_SIZE, CELL_SIZE, 1.0, COLOR_GRID_LINE);
-                    self.draw_module(px, py, mod_data);
+                    let fire_rate = if mod_data.module_type == ModuleType::Weapon { weapon_fire_rate(state, (x, y)) } else { 0.0 };
+                    self.draw_module(state, px, py, mod_data, fire_rate);
+                    if state.sell_confirm == Some((x, y)) {
+                        let pulse = ((state.frame_count as f32 * 0.3).sin() * 0.5 + 0.5) * 0.3 + 0.3;
+                        draw_rectangle(px, py, CELL_SIZE, CELL_SIZE, Color::new(1.0, 0.1, 0.1, pulse));
+                        draw_rectangle_lines(px, py, CELL_SIZE, CELL_SIZE, 3.0, RED);
+                    }
                 } else {
                     // Draw nothing for empty space
                 }
             }
         }
         
+        self.draw_synergy_links(state, start_x, start_y);
+        draw_drones(state);
+
         // Draw weapon ranges OVER grid
         self.draw_weapon_ranges(state, start_x, start_y);
     }
 
+    /// Tooltip for the hovered module, in screen space on top of the zoomed world
+    fn draw_hovered_module_tooltip(&self, state: &GameState) {
+        if let Some((hx, hy)) = self.hovered_module {
+            if let Some(module) = &state.ship.grid[hx][hy] {
+                let (mx, my) = mouse_position();
+                self.draw_module_tooltip(mx, my, module, state.module_registry.get(module.module_type));
+            }
+        }
+    }
+
+    /// Thin white line between each synergized Weapon/Defense pair
+    fn draw_synergy_links(&self, state: &GameState, start_x: f32, start_y: f32) {
+        let center = |x: usize, y: usize| {
+            vec2(start_x + (x as f32 + 0.5) * CELL_SIZE, start_y + (y as f32 + 0.5) * CELL_SIZE)
+        };
+
+        for &(x, y) in state.synergies.keys() {
+            // Only draw from weapons so each pair gets one line per shared edge
+            let is_weapon = state.ship.grid[x][y].as_ref()
+                .is_some_and(|m| m.module_type == ModuleType::Weapon);
+            if !is_weapon { continue; }
+
+            for (nx, ny) in [(x.wrapping_sub(1), y), (x + 1, y), (x, y.wrapping_sub(1)), (x, y + 1)] {
+                let is_partner = state.synergies.contains_key(&(nx, ny))
+                    && state.ship.grid[nx][ny].as_ref()
+                        .is_some_and(|m| m.module_type == ModuleType::Defense);
+                if is_partner {
+                    let a = center(x, y);
+                    let b = center(nx, ny);
+                    draw_line(a.x, a.y, b.x, b.y, 1.0, WHITE);
+                }
+            }
+        }
+    }
+
+    /// Floating info panel next to the cursor, kept inside the screen
+    pub fn draw_module_tooltip(&self, x: f32, y: f32, module: &Module, stats: &ModuleStats) {
+        let w = 200.0;
+        let is_max_level = module.level >= MODULE_MAX_LEVEL;
+        let h = if is_max_level { 130.0 } else { 150.0 };
+
+        // Prefer below-right of the cursor, flip when it would leave the screen
+        let mut tx = x + 16.0;
+        let mut ty = y + 16.0;
+        if tx + w > screen_width() { tx = x - w - 16.0; }
+        if ty + h > screen_height() { ty = y - h - 16.0; }
+        tx = tx.max(0.0);
+        ty = ty.max(0.0);
+
+        draw_rectangle(tx, ty, w, h, color_u8!(15, 15, 25, 230));
+        draw_rectangle_lines(tx, ty, w, h, 2.0, module_color(module.module_type));
+
+        draw_text(&stats.name, tx + 10.0, ty + 22.0, 20.0, WHITE);
+        // Level as filled pips out of the max
+        let pip = 6.0;
+        let pips_x = tx + w - 10.0 - MODULE_MAX_LEVEL as f32 * (pip + 3.0) + 3.0;
+        for i in 0..MODULE_MAX_LEVEL {
+            let px = pips_x + i as f32 * (pip + 3.0);
+            if i < module.level {
+                draw_rectangle(px, ty + 12.0, pip, pip, YELLOW);
+            } else {
+                draw_rectangle_lines(px, ty + 12.0, pip, pip, 1.0, GRAY);
+            }
+        }
+
+        // Health bar
+        let pct = (module.health / module.max_health).clamp(0.0, 1.0);
+        let bar_w = w - 20.0;
+        draw_rectangle(tx + 10.0, ty + 32.0, bar_w, 8.0, color_u8!(60, 20, 20, 255));
+        draw_rectangle(tx + 10.0, ty + 32.0, bar_w * pct, 8.0, GREEN);
+        let hp_text = if module.state == ModuleState::Destroyed {
+            "DESTROYED".to_string()
+        } else {
+            format!("HP {:.0}/{:.0}", module.health, module.max_health)
+        };
+        draw_text(&hp_text, tx + 10.0, ty + 56.0, 16.0, LIGHTGRAY);
+
+        draw_text(&format!("Range: {:.0}", stats.range), tx + 10.0, ty + 76.0, 16.0, LIGHTGRAY);
+        draw_text(&format!("Damage: {:.1}", stats.damage), tx + 10.0, ty + 96.0, 16.0, LIGHTGRAY);
+        draw_text(&format!("Fire rate: {:.1}/s", stats.fire_rate), tx + 10.0, ty + 116.0, 16.0, LIGHTGRAY);
+
+        if !is_max_level {
+            let cost = module.upgrade_cost(stats.base_cost);
+            draw_text(&format!("Upgrade: {} scrap", cost), tx + 10.0, ty + 138.0, 16.0, ORANGE);
+        }
+    }
+
     fn draw_weapon_ranges(&self, state: &GameState, start_x: f32, start_y: f32) {
         let base_range = state.module_registry.get(ModuleType::Weapon).range;
         
@@ -431,19 +312,46 @@ impl Renderer {
         draw_rectangle(x, y, CELL_SIZE, CELL_SIZE, color);
     }
 
-    pub fn draw_module(&self, x: f32, y: f32, mod_data: &Module) {
-        let color = match mod_data.module_type {
-            ModuleType::Core => RED,
-            ModuleType::Weapon => ORANGE,
-            ModuleType::Defense => BLUE,
-            ModuleType::Utility => GREEN,
-            ModuleType::Engine => PURPLE,
-            ModuleType::Empty => COLOR_MODULE_EMPTY,
-        };
+    pub fn draw_module(&self, state: &GameState, x: f32, y: f32, mod_data: &Module, effective_fire_rate: f32) {
+        let color = module_color(mod_data.module_type);
+        let colorblind = state.settings.colorblind_mode.is_enabled();
 
         let padding = 2.0;
+
+        // Levels 4+ bleed a faint glow past the cell edge
+        let ring_color = desaturate(color, 0.35);
+        if mod_data.level >= 4 && mod_data.state != ModuleState::Destroyed {
+            let layers = if mod_data.level >= 5 { 4 } else { 2 };
+            for i in (1..=layers).rev() {
+                let spread = i as f32 * 2.0;
+                let alpha = 0.25 / i as f32;
+                draw_rectangle(
+                    x - spread,
+                    y - spread,
+                    CELL_SIZE + spread * 2.0,
+                    CELL_SIZE + spread * 2.0,
+                    Color::new(ring_color.r, ring_color.g, ring_color.b, alpha),
+                );
+            }
+        }
+
         draw_rectangle(x + padding, y + padding, CELL_SIZE - padding * 2.0, CELL_SIZE - padding * 2.0, color);
 
+        // One inset ring per level from 2 up, capped at two
+        let ring_inner = Color::new(ring_color.r * 0.8, ring_color.g * 0.8, ring_color.b * 0.8, 1.0);
+        for ring in 0..(mod_data.level.saturating_sub(1)).min(2) {
+            let inset = padding + 4.0 + ring as f32 * 4.0;
+            draw_rectangle_lines(x + inset, y + inset, CELL_SIZE - inset * 2.0, CELL_SIZE - inset * 2.0, 1.0, ring_inner);
+        }
+
+        if mod_data.module_type == ModuleType::Weapon {
+            self.draw_turret(state, x, y, mod_data.turret_angle);
+        }
+
+        if colorblind {
+            draw_module_icon(x + CELL_SIZE / 2.0, y + CELL_SIZE / 2.0, CELL_SIZE * 0.2, mod_data.module_type);
+        }
+
         match mod_data.state {
             ModuleState::Destroyed => {
                 draw_line(x, y, x + CELL_SIZE, y + CELL_SIZE, 2.0, BLACK);
@@ -456,24 +364,134 @@ impl Renderer {
                 draw_rectangle_lines(x + padding, y + padding, CELL_SIZE - padding * 2.0, CELL_SIZE - padding * 2.0, 2.0, WHITE);
             }
         }
+
+        if mod_data.is_overcharged(state.time_survived) {
+            let pulse = (get_time() * 8.0).sin() as f32 * 0.5 + 0.5;
+            draw_rectangle_lines(x, y, CELL_SIZE, CELL_SIZE, 2.0 + pulse * 2.0, Color::new(1.0, 1.0, 0.4, 0.5 + pulse * 0.5));
+        }
+
+        if mod_data.hull_breached {
+            // Jagged crack across the cell
+            let crack = color_u8!(20, 10, 5, 230);
+            let s = CELL_SIZE;
+            draw_line(x + s * 0.15, y + s * 0.1, x + s * 0.45, y + s * 0.4, 2.0, crack);
+            draw_line(x + s * 0.45, y + s * 0.4, x + s * 0.35, y + s * 0.6, 2.0, crack);
+            draw_line(x + s * 0.35, y + s * 0.6, x + s * 0.7, y + s * 0.9, 2.0, crack);
+            draw_line(x + s * 0.45, y + s * 0.4, x + s * 0.85, y + s * 0.3, 1.5, crack);
+        }
+
+        // Weapon cooldown: fills left to right, green once ready to fire
+        if mod_data.module_type == ModuleType::Weapon && mod_data.state == ModuleState::Active {
+            let readiness = if effective_fire_rate > 0.001 {
+                (1.0 - mod_data.cooldown * effective_fire_rate).clamp(0.0, 1.0)
+            } else {
+                0.0
+            };
+            let bar_color = if readiness >= 1.0 { GREEN } else { YELLOW };
+            draw_rectangle(x, y, CELL_SIZE, 4.0, color_u8!(20, 20, 20, 200));
+            draw_rectangle(x, y, CELL_SIZE * readiness, 4.0, bar_color);
+        }
+
+        // Health 2px below the padded fill; grey while offline since the module isn't at risk
+        if mod_data.state != ModuleState::Destroyed && mod_data.max_health > 0.0 {
+            let pct = (mod_data.health / mod_data.max_health).clamp(0.0, 1.0);
+            let bar_color = if mod_data.state == ModuleState::Offline { GRAY } else { health_color(pct) };
+            let bar_y = y + CELL_SIZE - padding + 2.0;
+            draw_rectangle(x, bar_y, CELL_SIZE, 4.0, color_u8!(20, 20, 20, 200));
+            draw_rectangle(x, bar_y, CELL_SIZE * pct, 4.0, bar_color);
+        }
+    }
+
+    /// Weapon turret pointing at `angle` (radians, 0 = right)
+    fn draw_turret(&self, state: &GameState, x: f32, y: f32, angle: f32) {
+        let center = vec2(x + CELL_SIZE / 2.0, y + CELL_SIZE / 2.0);
+        match (state.assets.get_texture("weapon_turret_base"), state.assets.get_texture("weapon_pulse_turret")) {
+            (Some(base), Some(barrel)) => {
+                let size = vec2(CELL_SIZE, CELL_SIZE);
+                draw_texture_ex(base, x, y, WHITE, DrawTextureParams {
+                    dest_size: Some(size),
+                    ..Default::default()
+                });
+                // Sprites face up, so rotate a quarter turn further
+                draw_texture_ex(barrel, x, y, WHITE, DrawTextureParams {
+                    dest_size: Some(size),
+                    rotation: angle + std::f32::consts::FRAC_PI_2,
+                    ..Default::default()
+                });
+            }
+            _ => {
+                draw_poly(center.x, center.y, 3, CELL_SIZE * 0.3, angle.to_degrees(), color_u8!(230, 230, 230, 220));
+            }
+        }
+    }
+
+    /// Edge arrows pointing at enemies that are still off-screen
+    pub fn draw_enemy_indicators(&self, state: &GameState) {
+        let margin = 20.0;
+        let half = vec2(screen_width(), screen_height()) / 2.0;
+        let center = half;
+        let flash = (get_time() * 6.0).sin() > 0.0;
+
+        for enemy in &state.enemies {
+            let pos = self.world_to_screen(enemy.position);
+            if pos.x >= 0.0 && pos.x <= screen_width() && pos.y >= 0.0 && pos.y <= screen_height() {
+                continue;
+            }
+
+            // Where the line from the screen center to the enemy crosses the (inset) border
+            let dir = pos - center;
+            let tx = if dir.x != 0.0 { (half.x - margin) / dir.x.abs() } else { f32::MAX };
+            let ty = if dir.y != 0.0 { (half.y - margin) / dir.y.abs() } else { f32::MAX };
+            let tip = center + dir * tx.min(ty);
+
+            let is_boss = enemy.enemy_type == EnemyType::Boss;
+            let size = 10.0 * enemy_size_scale(&enemy.enemy_type) * if is_boss { 1.5 } else { 1.0 };
+            let mut color = enemy_color(&enemy.enemy_type);
+            if is_boss && !flash {
+                color.a = 0.35;
+            }
+
+            let forward = dir.normalize_or_zero();
+            let side = vec2(-forward.y, forward.x);
+            let back = tip - forward * size * 1.6;
+            draw_triangle(tip, back + side * size, back - side * size, color);
+            draw_triangle_lines(tip, back + side * size, back - side * size, 1.0, BLACK);
+        }
     }
 
     pub fn draw_enemies(&self, state: &GameState, shake: Vec2) {
+        if state.cockpit_fully_repaired && state.view_mode == ViewMode::Exterior {
+            draw_enemy_path_previews(state, shake);
+        }
+        for spawner in &state.spawners {
+            draw_spawner(spawner, shake);
+        }
+        let colorblind = state.settings.colorblind_mode.is_enabled();
         for enemy in &state.enemies {
             let tex_name = match enemy.enemy_type {
                 crate::enemy::entities::EnemyType::Nanodrone => "enemy_nanodrone",
                 crate::enemy::entities::EnemyType::Nanoguard => "enemy_nanoguard",
                 crate::enemy::entities::EnemyType::Leech => "enemy_leech",
                 crate::enemy::entities::EnemyType::SiegeConstruct => "enemy_siege_construct",
+                crate::enemy::entities::EnemyType::Carrier => "enemy_carrier",
                 crate::enemy::entities::EnemyType::Boss => "enemy_boss",
             };
 
             let ex = enemy.position.x + shake.x;
             let ey = enemy.position.y + shake.y;
 
-            if let Some(tex) = state.assets.get_texture(tex_name) {
-                let w = tex.width();
-                let h = tex.height();
+            // Prefer the current animation frame, then the static sprite
+            let animation_frame = state.assets.get_animation(tex_name)
+                .and_then(|anim| anim.frame_at(enemy.animation_timer));
+
+            if enemy.enemy_type == EnemyType::Carrier {
+                draw_carrier(ex, ey, enemy.drops_remaining, enemy.ability_timer);
+            } else if let Some((tex, params)) = animation_frame
+                .map(|t| (t, DrawTextureParams::default()))
+                .or_else(|| state.assets.create_sprite(tex_name))
+            {
+                let size = params.source.map_or(tex.size(), |r| r.size());
+                let (w, h) = (size.x, size.y);
                 
                 // Rotation towards ship center if applicable, or just 0 for top-down sprites?
                 // Most sprites face UP or RIGHT by default.
@@ -486,22 +504,42 @@ impl Renderer {
                      0.0
                 };
                 
+                // Size differences make enemy types distinguishable without color
+                let scale = if colorblind { enemy_size_scale(&enemy.enemy_type) } else { 1.0 };
+                let (w, h) = (w * scale, h * scale);
+
                 // Draw sprite centered
                 draw_texture_ex(tex, ex - w / 2.0, ey - h / 2.0, WHITE, DrawTextureParams {
+                    dest_size: Some(vec2(w, h)),
+                    source: params.source,
                     rotation,
                     pivot: None, // pivot at center by default for rotation? No, pivot is absolute.
                     ..Default::default()
                 });
             } else {
                 // Fallback
-                let color = match enemy.enemy_type {
-                    crate::enemy::entities::EnemyType::Nanodrone => GREEN,
-                    crate::enemy::entities::EnemyType::Nanoguard => YELLOW,
-                    crate::enemy::entities::EnemyType::Leech => PURPLE,
-                    crate::enemy::entities::EnemyType::SiegeConstruct => DARKGRAY,
-                    crate::enemy::entities::EnemyType::Boss => RED,
-                };
-                 draw_circle(ex, ey, 8.0, color);
+                let radius = if colorblind { 8.0 * enemy_size_scale(&enemy.enemy_type) } else { 8.0 };
+                let cracked = enemy.enemy_type == EnemyType::SiegeConstruct && !enemy.is_armored();
+                let color = if cracked { LIGHTGRAY } else { enemy_color(&enemy.enemy_type) };
+                draw_circle(ex, ey, radius, color);
+            }
+
+            if enemy.enemy_type == EnemyType::SiegeConstruct && !enemy.is_armored() {
+                draw_armor_cracks(ex, ey);
+            }
+
+            if enemy.elite {
+                draw_circle_lines(ex, ey, 18.0, 2.0, GOLD);
+                let star_w = measure_text("*", None, 24, 1.0).width;
+                draw_text("*", ex - star_w / 2.0, ey - 30.0, 24.0, GOLD);
+            }
+            if enemy.is_slowed() {
+                draw_circle_lines(ex, ey, 14.0, 2.0, BLUE);
+            }
+            if enemy.is_stunned() {
+                // Six-pointed star above the health bar
+                draw_poly(ex, ey - 24.0, 3, 6.0, -90.0, YELLOW);
+                draw_poly(ex, ey - 24.0, 3, 6.0, 90.0, YELLOW);
             }
 
             if enemy.health < enemy.max_health {
@@ -518,19 +556,18 @@ impl Renderer {
         for proj in &state.projectiles {
             let px = proj.position.x + shake.x;
             let py = proj.position.y + shake.y;
-            draw_line(
-                px,
-                py,
-                px - proj.velocity.normalize().x * 10.0,
-                py - proj.velocity.normalize().y * 10.0,
-                2.0,
-                YELLOW,
-            );
+            match proj.kind {
+                ProjectileType::Ballistic => draw_circle(px, py, 2.5, YELLOW),
+                ProjectileType::Beam { length, .. } => {
+                    let end = vec2(px, py) + proj.velocity * length;
+                    draw_line(px, py, end.x, end.y, 4.0, color_u8!(0, 255, 255, 255));
+                }
+            }
         }
     }
 
     pub fn draw_particles(&self, state: &GameState, shake: Vec2) {
-        for particle in &state.particles {
+        for particle in state.particle_pool.slots.iter() {
             if particle.active {
                 let alpha = (particle.lifetime / particle.max_lifetime).clamp(0.0, 1.0);
                 let color = Color::new(particle.color.r, particle.color.g, particle.color.b, particle.color.a * alpha);
@@ -538,4 +575,197 @@ impl Renderer {
             }
         }
     }
+
+    pub fn draw_damage_numbers(&self, state: &GameState, shake: Vec2) {
+        for number in &state.damage_numbers {
+            let progress = 1.0 - number.timer / number.max_timer;
+            let alpha = (number.timer / number.max_timer).clamp(0.0, 1.0);
+            let (size, base) = if number.lethal { (26.0, RED) } else { (18.0, WHITE) };
+            let color = Color::new(base.r, base.g, base.b, alpha);
+
+            let text = format!("{:.0}", number.amount);
+            let text_w = measure_text(&text, None, size as u16, 1.0).width;
+            let x = number.position.x - text_w / 2.0 + shake.x;
+            let y = number.position.y - 20.0 - 30.0 * progress + shake.y;
+            draw_text(&text, x, y, size, color);
+        }
+    }
+
+    /// Scaled-down overview of the ship grid and nearby enemies (bottom-right corner)
+    pub fn draw_minimap(&self, state: &GameState) {
+        let map_w = 150.0;
+        let map_h = 112.0;
+        let map_x = screen_width() - map_w - 10.0;
+        let map_y = screen_height() - map_h - 40.0;
+
+        draw_rectangle(map_x, map_y, map_w, map_h, color_u8!(0, 0, 0, 160));
+
+        let scale_x = map_w / GRID_WIDTH as f32;
+        let scale_y = map_h / GRID_HEIGHT as f32;
+
+        for x in 0..GRID_WIDTH {
+            for y in 0..GRID_HEIGHT {
+                if let Some(module) = &state.ship.grid[x][y] {
+                    let cx = map_x + x as f32 * scale_x + (scale_x - 4.0) / 2.0;
+                    let cy = map_y + y as f32 * scale_y + (scale_y - 4.0) / 2.0;
+                    draw_rectangle(cx, cy, 4.0, 4.0, module_color(module.module_type));
+                }
+            }
+        }
+
+        // Enemies are in screen space, so map them relative to the grid origin
+        let total_width = GRID_WIDTH as f32 * CELL_SIZE;
+        let total_height = GRID_HEIGHT as f32 * CELL_SIZE;
+        let start_x = (screen_width() - total_width) / 2.0;
+        let start_y = (screen_height() - total_height) / 2.0;
+
+        for enemy in &state.enemies {
+            let ex = map_x + (enemy.position.x - start_x) / total_width * map_w;
+            let ey = map_y + (enemy.position.y - start_y) / total_height * map_h;
+            if ex < map_x || ex > map_x + map_w - 2.0 || ey < map_y || ey > map_y + map_h - 2.0 {
+                continue;
+            }
+            draw_rectangle(ex, ey, 2.0, 2.0, enemy_color(&enemy.enemy_type));
+        }
+
+        draw_rectangle_lines(map_x, map_y, map_w, map_h, 1.0, WHITE);
+    }
+}
+
+/// Drone bay drones circling the hull
+fn draw_drones(state: &GameState) {
+    for module in state.ship.grid.iter().flatten().flatten() {
+        if module.module_type != ModuleType::DroneBay { continue; }
+        for pos in &module.drone_positions {
+            draw_circle(pos.x, pos.y, 5.0, color_u8!(0, 230, 255, 255));
+            draw_circle_lines(pos.x, pos.y, 5.0, 1.0, WHITE);
+        }
+    }
+}
+
+fn module_color(module_type: ModuleType) -> Color {
+    match module_type {
+        ModuleType::Core => RED,
+        ModuleType::Weapon => ORANGE,
+        ModuleType::Defense => BLUE,
+        ModuleType::Utility => GREEN,
+        ModuleType::Engine => PURPLE,
+        ModuleType::DroneBay => SKYBLUE,
+        ModuleType::Empty => COLOR_MODULE_EMPTY,
+    }
+}
+
+/// Green above 60% health, yellow above 30%, red below
+pub(crate) fn health_color(pct: f32) -> Color {
+    if pct > 0.6 { GREEN } else if pct > 0.3 { YELLOW } else { RED }
+}
+
+/// Blend a color toward its own grey by `amount` (0 = unchanged, 1 = fully grey)
+fn desaturate(color: Color, amount: f32) -> Color {
+    let grey = color.r * 0.3 + color.g * 0.59 + color.b * 0.11;
+    Color::new(
+        color.r + (grey - color.r) * amount,
+        color.g + (grey - color.g) * amount,
+        color.b + (grey - color.b) * amount,
+        color.a,
+    )
+}
+
+/// Ghost lines showing where each enemy heads over the next few seconds; the Boss
+/// gets a bigger arrow. Enemies latched on or fleeing are left out.
+fn draw_enemy_path_previews(state: &GameState, shake: Vec2) {
+    let core = state.ship.find_core().map_or(vec2(screen_width(), screen_height()) / 2.0, |(x, y)| Layout::grid_to_screen_center(x, y));
+    let color = color_u8!(255, 255, 0, 60);
+    for enemy in &state.enemies {
+        if enemy.attached_to.is_some() || enemy.is_retreating { continue; }
+        let start = enemy.position + shake;
+        let dir = (core - enemy.position).normalize_or_zero();
+        let reach = (enemy.speed * COCKPIT_PATH_PREVIEW_SECONDS).min(enemy.position.distance(core));
+        let end = start + dir * reach;
+        if enemy.enemy_type == EnemyType::Boss {
+            draw_line(start.x, start.y, end.x, end.y, 4.0, color);
+            let side = vec2(-dir.y, dir.x) * 12.0;
+            draw_triangle(end + dir * 18.0, end + side, end - side, color);
+        } else {
+            draw_line(start.x, start.y, end.x, end.y, 1.5, color);
+        }
+    }
+}
+
+/// Pulsing red square with a health bar above it
+fn draw_spawner(spawner: &SpawnerRoom, shake: Vec2) {
+    let center = spawner.position + shake;
+    let half = SPAWNER_SIZE / 2.0;
+    let pulse = (get_time() * 4.0).sin() as f32 * 0.5 + 0.5;
+    draw_rectangle(center.x - half, center.y - half, SPAWNER_SIZE, SPAWNER_SIZE, Color::new(0.6 + 0.4 * pulse, 0.05, 0.05, 1.0));
+    draw_rectangle_lines(center.x - half, center.y - half, SPAWNER_SIZE, SPAWNER_SIZE, 2.0, Color::new(1.0, 0.3, 0.3, 0.5 + 0.5 * pulse));
+
+    let pct = (spawner.health / SPAWNER_HP).clamp(0.0, 1.0);
+    let bar_y = center.y - half - 8.0;
+    draw_rectangle(center.x - half, bar_y, SPAWNER_SIZE, 4.0, RED);
+    draw_rectangle(center.x - half, bar_y, SPAWNER_SIZE * pct, 4.0, GREEN);
+}
+
+/// Carrier hull: a large disc with a cross marking and one pip per remaining drop.
+/// The next pip to launch pulses faster as the drop timer fills.
+fn draw_carrier(x: f32, y: f32, drops_remaining: u32, drop_timer: f32) {
+    let radius = 22.0;
+    draw_circle(x, y, radius, color_u8!(120, 70, 20, 255));
+    draw_circle_lines(x, y, radius, 2.0, ORANGE);
+    draw_line(x - radius * 0.7, y, x + radius * 0.7, y, 3.0, color_u8!(255, 200, 120, 255));
+    draw_line(x, y - radius * 0.7, x, y + radius * 0.7, 3.0, color_u8!(255, 200, 120, 255));
+
+    let charge = (drop_timer / CARRIER_DROP_INTERVAL).clamp(0.0, 1.0);
+    for i in 0..drops_remaining {
+        let angle = i as f32 / CARRIER_DROP_COUNT as f32 * std::f32::consts::TAU - std::f32::consts::FRAC_PI_2;
+        let (px, py) = (x + angle.cos() * radius * 0.55, y + angle.sin() * radius * 0.55);
+        let pulse = if i + 1 == drops_remaining { 0.5 + 0.5 * (get_time() as f32 * (4.0 + charge * 12.0)).sin() } else { 1.0 };
+        draw_circle(px, py, 2.5, Color::new(0.3, 1.0, 0.3, 0.4 + 0.6 * pulse));
+    }
+}
+
+/// Jagged lines across a Siege Construct whose armor has broken
+fn draw_armor_cracks(x: f32, y: f32) {
+    let color = color_u8!(30, 30, 30, 255);
+    draw_line(x - 7.0, y - 6.0, x - 1.0, y, 1.5, color);
+    draw_line(x - 1.0, y, x - 4.0, y + 7.0, 1.5, color);
+    draw_line(x - 1.0, y, x + 6.0, y - 2.0, 1.5, color);
+    draw_line(x + 6.0, y - 2.0, x + 8.0, y + 5.0, 1.5, color);
+}
+
+pub(crate) fn enemy_color(enemy_type: &EnemyType) -> Color {
+    match enemy_type {
+        EnemyType::Nanodrone => GREEN,
+        EnemyType::Nanoguard => YELLOW,
+        EnemyType::Leech => PURPLE,
+        EnemyType::SiegeConstruct => DARKGRAY,
+        EnemyType::Carrier => ORANGE,
+        EnemyType::Boss => RED,
+    }
+}
+
+/// Shape drawn inside a module when color-blind mode is on
+fn draw_module_icon(cx: f32, cy: f32, size: f32, module_type: ModuleType) {
+    let color = color_u8!(255, 255, 255, 220);
+    match module_type {
+        ModuleType::Core => {
+            draw_line(cx - size, cy, cx + size, cy, 3.0, color);
+            draw_line(cx, cy - size, cx, cy + size, 3.0, color);
+        }
+        ModuleType::Weapon => draw_poly(cx, cy, 3, size, -90.0, color),
+        ModuleType::Defense => draw_circle(cx, cy, size, color),
+        ModuleType::Engine => draw_rectangle(cx - size, cy - size, size * 2.0, size * 2.0, color),
+        ModuleType::Utility => draw_poly(cx, cy, 4, size, 0.0, color), // Diamond
+        ModuleType::DroneBay => draw_circle_lines(cx, cy, size, 3.0, color),
+        _ => {}
+    }
+}
+
+/// Relative size of each enemy type in color-blind mode
+fn enemy_size_scale(enemy_type: &EnemyType) -> f32 {
+    match enemy_type {
+        EnemyType::Boss => 1.5,
+        EnemyType::Nanoguard | EnemyType::Carrier => 1.2,
+        _ => 1.0,
+    }
 }